@@ -0,0 +1,182 @@
+/// Batches execute multiple inserts, updates and deletes as a single statement
+/// Which statements can be added depends on the kind of the batch, counter updates can only be
+/// added to a counter batch and the other statements only to a logged or unlogged batch.
+/// Mixing them results in a compile error instead of an error from the database.
+use crate::query_transform::{
+    DeleteMultiple, DeleteUnique, Insert, QueryOptions, Qv, Update, UpdateCounter,
+};
+use crate::Error;
+use scylla::batch::BatchType;
+use scylla::frame::value::{SerializedValues, ValueList};
+use scylla::query::Query;
+use scylla::CachingSession;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+/// The kind of batch, this determines which statements can be added
+pub trait BatchKind {
+    fn batch_type() -> BatchType;
+}
+
+/// Logged batches are applied atomically
+#[derive(Debug, Clone, Copy)]
+pub struct Logged;
+
+/// Unlogged batches skip the batch log, they are only atomic within a single partition
+#[derive(Debug, Clone, Copy)]
+pub struct Unlogged;
+
+/// Counter batches only contain counter updates
+#[derive(Debug, Clone, Copy)]
+pub struct Counter;
+
+impl BatchKind for Logged {
+    fn batch_type() -> BatchType {
+        BatchType::Logged
+    }
+}
+
+impl BatchKind for Unlogged {
+    fn batch_type() -> BatchType {
+        BatchType::Unlogged
+    }
+}
+
+impl BatchKind for Counter {
+    fn batch_type() -> BatchType {
+        BatchType::Counter
+    }
+}
+
+pub type LoggedBatch = Batch<Logged>;
+pub type UnloggedBatch = Batch<Unlogged>;
+pub type CounterBatch = Batch<Counter>;
+
+/// A statement that can be added to a batch of kind K
+pub trait BatchStatement<K: BatchKind> {
+    type Query: AsRef<str>;
+    type Values: ValueList;
+
+    fn qv(&self) -> &Qv<Self::Query, Self::Values>;
+}
+
+macro_rules! batch_statement {
+    ($ident: ident, $($kind: ident),+) => {
+        $(
+            impl<R: AsRef<str>, V: ValueList> BatchStatement<$kind> for $ident<R, V> {
+                type Query = R;
+                type Values = V;
+
+                fn qv(&self) -> &Qv<R, V> {
+                    &self.qv
+                }
+            }
+        )+
+    };
+}
+batch_statement!(Insert, Logged, Unlogged);
+batch_statement!(Update, Logged, Unlogged);
+batch_statement!(DeleteUnique, Logged, Unlogged);
+batch_statement!(DeleteMultiple, Logged, Unlogged);
+batch_statement!(UpdateCounter, Counter);
+
+/// Collects statements which are executed in a single round trip
+/// The values are serialized when a statement is appended
+pub struct Batch<K: BatchKind> {
+    queries: Vec<String>,
+    values: Vec<SerializedValues>,
+    options: QueryOptions,
+    kind: PhantomData<K>,
+}
+
+impl<K: BatchKind> Default for Batch<K> {
+    fn default() -> Self {
+        Batch {
+            queries: vec![],
+            values: vec![],
+            options: QueryOptions::default(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K: BatchKind> Clone for Batch<K> {
+    fn clone(&self) -> Self {
+        Batch {
+            queries: self.queries.clone(),
+            values: self.values.clone(),
+            options: self.options.clone(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K: BatchKind> Debug for Batch<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Batch")
+            .field("queries", &self.queries)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+impl<K: BatchKind> Batch<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the options that are used when executing the batch
+    pub fn with_options(mut self, options: QueryOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Adds a statement to the batch, e.g. an Insert or Update returned by the generated code
+    pub fn append(&mut self, statement: &impl BatchStatement<K>) -> Result<&mut Self, Error> {
+        let qv = statement.qv();
+        let values = qv.values.serialized()?.into_owned();
+
+        self.queries.push(qv.query.as_ref().to_string());
+        self.values.push(values);
+
+        Ok(self)
+    }
+
+    /// The amount of statements in the batch
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// Prepares the statements and executes them as a single batch
+    /// The statements are prepared through the cache of the session, just like single statements,
+    /// so executing the same batch again doesn't prepare the statements again
+    /// The timeout and retry policy of the options apply to the execution of the batch
+    pub async fn execute(&self, session: &CachingSession) -> Result<(), Error> {
+        tracing::debug!("Executing batch of {} statements", self.len());
+
+        let mut batch = self.options.batch(K::batch_type());
+
+        for query in &self.queries {
+            let query: Query = query.as_str().into();
+
+            batch.append_statement(session.add_prepared_statement(&query).await?);
+        }
+
+        let batch = &batch;
+
+        self.options
+            .retry(false, || async move {
+                Ok(self
+                    .options
+                    .with_timeout(session.session.batch(batch, &self.values))
+                    .await?)
+            })
+            .await?;
+
+        Ok(())
+    }
+}
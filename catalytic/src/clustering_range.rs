@@ -0,0 +1,120 @@
+//! Ranges over the clustering columns within a partition, used by the generated select_partition_range
+//! and delete_range. A bound of a range restricts a prefix of the clustering columns, e.g. '(d, a) >= (?, ?)'
+use scylla::frame::value::{SerializeValuesError, SerializedValues};
+use std::ops::{Bound, RangeBounds};
+
+/// A prefix of the clustering columns together with its values
+/// This is implemented by the generated ClusteringPrefix enums
+pub trait ClusteringColumns {
+    /// The names of the columns in the prefix, in the order of the clustering key
+    fn columns(&self) -> &'static [&'static str];
+
+    /// Adds the values of the prefix, in the same order as the columns
+    fn add_values(&self, values: &mut SerializedValues) -> Result<(), SerializeValuesError>;
+}
+
+/// Appends the restrictions of the range to a query that restricts the partition key
+/// The values of the bounds are added after the values that are already serialized
+pub fn append_range<P: ClusteringColumns>(
+    query: &str,
+    range: &impl RangeBounds<P>,
+    values: &mut SerializedValues,
+) -> Result<String, SerializeValuesError> {
+    let mut query = query.to_string();
+
+    for restriction in [
+        restriction(range.start_bound(), ">=", ">", values)?,
+        restriction(range.end_bound(), "<=", "<", values)?,
+    ]
+    .iter()
+    .flatten()
+    {
+        query.push_str(" and ");
+        query.push_str(restriction);
+    }
+
+    Ok(query)
+}
+
+fn restriction<P: ClusteringColumns>(
+    bound: Bound<&P>,
+    included: &str,
+    excluded: &str,
+    values: &mut SerializedValues,
+) -> Result<Option<String>, SerializeValuesError> {
+    let (prefix, operator) = match bound {
+        Bound::Included(prefix) => (prefix, included),
+        Bound::Excluded(prefix) => (prefix, excluded),
+        Bound::Unbounded => return Ok(None),
+    };
+    let columns = prefix.columns();
+
+    prefix.add_values(values)?;
+
+    // Always use the multi column notation, single and multi column slices can not be mixed
+    Ok(Some(format!(
+        "({}) {} ({})",
+        columns.join(", "),
+        operator,
+        vec!["?"; columns.len()].join(", ")
+    )))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    enum Prefix {
+        B(i32),
+        C(i32, i32),
+    }
+
+    impl ClusteringColumns for Prefix {
+        fn columns(&self) -> &'static [&'static str] {
+            match self {
+                Prefix::B(..) => &["b"],
+                Prefix::C(..) => &["b", "c"],
+            }
+        }
+
+        fn add_values(&self, values: &mut SerializedValues) -> Result<(), SerializeValuesError> {
+            match self {
+                Prefix::B(b) => values.add_value(b),
+                Prefix::C(b, c) => {
+                    values.add_value(b)?;
+                    values.add_value(c)
+                }
+            }
+        }
+    }
+
+    fn append(range: impl RangeBounds<Prefix>) -> (String, i16) {
+        let mut values = SerializedValues::new();
+        let query = append_range("select * from t where a = ?", &range, &mut values).unwrap();
+
+        (query, values.len())
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(("select * from t where a = ?".to_string(), 0), append(..));
+        assert_eq!(
+            (
+                "select * from t where a = ? and (b) >= (?) and (b, c) < (?, ?)".to_string(),
+                3
+            ),
+            append(Prefix::B(1)..Prefix::C(2, 3))
+        );
+        assert_eq!(
+            (
+                "select * from t where a = ? and (b, c) <= (?, ?)".to_string(),
+                2
+            ),
+            append(..=Prefix::C(2, 3))
+        );
+        assert_eq!(
+            ("select * from t where a = ? and (b) > (?)".to_string(), 1),
+            append((Bound::Excluded(Prefix::B(1)), Bound::Unbounded))
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Marker types for the CQL types of columns, used by the query! macro to check at compile time
+//! that the arguments exactly match the types of the columns they are bound to.
+//! Ascii and varchar columns are checked as text.
+use crate::map_entries::MapEntries;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::marker::PhantomData;
+
+/// The path of this module, used by the generated code
+pub const CQL_TYPE_MODULE: &str = "catalytic::cql_type";
+
+/// Implemented for the Rust types that can be bound to a column with CQL type C
+/// JSON mapped types implement this for Text through the Json derive
+pub trait CqlTypeOf<C> {}
+
+/// Doesn't do anything at runtime, it only fails to compile if T can not be bound to C
+pub fn assert_cql_type<C, T: CqlTypeOf<C> + ?Sized>(_: &T) {}
+
+pub struct TinyInt;
+pub struct SmallInt;
+pub struct Int;
+pub struct BigInt;
+pub struct Text;
+pub struct Boolean;
+pub struct Time;
+pub struct Timestamp;
+pub struct Float;
+pub struct Double;
+pub struct Uuid;
+pub struct Counter;
+pub struct List<C>(PhantomData<C>);
+pub struct Set<C>(PhantomData<C>);
+pub struct Map<K, V>(PhantomData<(K, V)>);
+/// The struct of the user defined type implements CqlTypeOf for this marker
+pub struct UserDefinedType<U>(PhantomData<U>);
+/// The value of a 'column in ?' restriction
+pub struct In<C>(PhantomData<C>);
+
+macro_rules! cql_type_of {
+    ($marker: ident, $($ty: ty),+) => {
+        $(
+            impl CqlTypeOf<$marker> for $ty {}
+        )+
+    };
+}
+
+cql_type_of!(TinyInt, i8);
+cql_type_of!(SmallInt, i16);
+cql_type_of!(Int, i32);
+cql_type_of!(BigInt, i64);
+cql_type_of!(Text, String, str);
+cql_type_of!(Boolean, bool);
+cql_type_of!(Time, scylla::frame::value::Time);
+cql_type_of!(Timestamp, scylla::frame::value::Timestamp);
+cql_type_of!(Float, f32);
+cql_type_of!(Double, f64);
+cql_type_of!(Uuid, uuid::Uuid);
+cql_type_of!(Counter, scylla::frame::value::Counter);
+
+/// Null is a valid value for every column
+impl<C, T: CqlTypeOf<C>> CqlTypeOf<C> for Option<T> {}
+
+impl<C, T: CqlTypeOf<C> + ?Sized> CqlTypeOf<C> for &T {}
+
+impl<C, T: CqlTypeOf<C>> CqlTypeOf<List<C>> for Vec<T> {}
+impl<C, T: CqlTypeOf<C>> CqlTypeOf<List<C>> for [T] {}
+
+impl<C, T: CqlTypeOf<C>> CqlTypeOf<Set<C>> for Vec<T> {}
+impl<C, T: CqlTypeOf<C>> CqlTypeOf<Set<C>> for [T] {}
+impl<C, T: CqlTypeOf<C>, S> CqlTypeOf<Set<C>> for HashSet<T, S> {}
+impl<C, T: CqlTypeOf<C>> CqlTypeOf<Set<C>> for BTreeSet<T> {}
+
+impl<CK, CV, K: CqlTypeOf<CK>, V: CqlTypeOf<CV>, S> CqlTypeOf<Map<CK, CV>> for HashMap<K, V, S> {}
+impl<CK, CV, K: CqlTypeOf<CK>, V: CqlTypeOf<CV>> CqlTypeOf<Map<CK, CV>> for BTreeMap<K, V> {}
+impl<CK, CV, K: CqlTypeOf<CK>, V: CqlTypeOf<CV>> CqlTypeOf<Map<CK, CV>> for MapEntries<K, V> {}
+
+impl<C, T: CqlTypeOf<C>> CqlTypeOf<In<C>> for Vec<T> {}
+impl<C, T: CqlTypeOf<C>> CqlTypeOf<In<C>> for [T] {}
+
+/// Tuple columns are checked with a tuple of markers
+macro_rules! tuple_cql_type_of {
+    ($(($c: ident, $t: ident)),+) => {
+        impl<$($c, $t: CqlTypeOf<$c>),+> CqlTypeOf<($($c,)+)> for ($($t,)+) {}
+    };
+}
+
+tuple_cql_type_of!((C1, T1));
+tuple_cql_type_of!((C1, T1), (C2, T2));
+tuple_cql_type_of!((C1, T1), (C2, T2), (C3, T3));
+tuple_cql_type_of!((C1, T1), (C2, T2), (C3, T3), (C4, T4));
+tuple_cql_type_of!((C1, T1), (C2, T2), (C3, T3), (C4, T4), (C5, T5));
+tuple_cql_type_of!((C1, T1), (C2, T2), (C3, T3), (C4, T4), (C5, T5), (C6, T6));
+tuple_cql_type_of!(
+    (C1, T1),
+    (C2, T2),
+    (C3, T3),
+    (C4, T4),
+    (C5, T5),
+    (C6, T6),
+    (C7, T7)
+);
+tuple_cql_type_of!(
+    (C1, T1),
+    (C2, T2),
+    (C3, T3),
+    (C4, T4),
+    (C5, T5),
+    (C6, T6),
+    (C7, T7),
+    (C8, T8)
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn assignable() {
+        assert_cql_type::<Int, _>(&1);
+        assert_cql_type::<Int, _>(&Some(1));
+        assert_cql_type::<Int, _>(&&1);
+        assert_cql_type::<Text, _>("text");
+        assert_cql_type::<Text, _>(&"text".to_string());
+        assert_cql_type::<List<Int>, _>(&vec![1]);
+        assert_cql_type::<Set<Text>, _>(&HashSet::<String>::new());
+        assert_cql_type::<Map<Text, List<Double>>, _>(&HashMap::<String, Vec<f64>>::new());
+        assert_cql_type::<In<BigInt>, _>(&[1i64, 2][..]);
+        assert_cql_type::<(Int, Text), _>(&(1, "a".to_string()));
+        assert_cql_type::<Int, _>(&None::<i32>);
+    }
+}
@@ -1,10 +1,14 @@
 use std::env;
+use std::path::PathBuf;
 
 /// Mandatory: the keyspace to use when validating queries and mapping table names to Rust structures.
 /// Note: this crate can add rows! Make sure this is a test keyspace or development environment
 pub const TEST_DB_KEYSPACE_KEY: &str = "TEST_DB_KEYSPACE_KEY";
 /// Defaults to 127.0.0.1:9042
 pub const SCYLLA_URI: &str = "SCYLLA_URI";
+/// Optional: path to a schema snapshot (see `SchemaSnapshot`), relative to the root of the crate that is compiled.
+/// When set, queries are validated against the snapshot and are not executed, so no database is needed
+pub const OFFLINE_SCHEMA_SNAPSHOT: &str = "OFFLINE_SCHEMA_SNAPSHOT";
 
 pub fn keyspace() -> String {
     env::var(TEST_DB_KEYSPACE_KEY).unwrap_or_else(|_| {
@@ -18,3 +22,13 @@ pub fn keyspace() -> String {
 pub fn database_url() -> String {
     env::var(SCYLLA_URI).unwrap_or_else(|_| "127.0.0.1:9042".to_string())
 }
+
+pub fn offline_schema_snapshot() -> Option<PathBuf> {
+    let path = env::var(OFFLINE_SCHEMA_SNAPSHOT).ok()?;
+
+    // Proc macro's run inside the compiler, which has another current dir than the crate that is compiled
+    match env::var("CARGO_MANIFEST_DIR") {
+        Ok(manifest_dir) => Some(PathBuf::from(manifest_dir).join(path)),
+        Err(_) => Some(PathBuf::from(path)),
+    }
+}
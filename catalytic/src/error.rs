@@ -0,0 +1,101 @@
+//! The error that is returned by all operations of the generated code and the query types
+use crate::page_cursor::PageCursorError;
+use crate::query_transform::UniqueQueryRowTransformError;
+use scylla::cql_to_rust::FromRowError;
+use scylla::frame::value::SerializeValuesError;
+use scylla::transport::errors::{DbError, QueryError};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    #[error("Query error: {0}")]
+    QueryError(#[from] QueryError),
+    #[error("Serialize values error: {0}")]
+    SerializeValuesError(#[from] SerializeValuesError),
+    #[error("From row error: {0}")]
+    FromRowError(#[from] FromRowError),
+    #[error("{0}")]
+    UniqueQueryRowTransformError(#[from] UniqueQueryRowTransformError),
+    #[error("{0}")]
+    PageCursorError(#[from] PageCursorError),
+}
+
+impl Error {
+    /// The request timed out, either on the client or on the coordinator
+    pub fn is_timeout(&self) -> bool {
+        matches!(
+            self,
+            Error::QueryError(QueryError::TimeoutError)
+                | Error::QueryError(QueryError::DbError(DbError::ReadTimeout { .. }, _))
+                | Error::QueryError(QueryError::DbError(DbError::WriteTimeout { .. }, _))
+        )
+    }
+
+    /// Not enough replicas were alive to satisfy the consistency level
+    pub fn is_unavailable(&self) -> bool {
+        matches!(
+            self,
+            Error::QueryError(QueryError::DbError(DbError::Unavailable { .. }, _))
+        )
+    }
+
+    /// A unique row was expected, but the row doesn't exist
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Error::UniqueQueryRowTransformError(UniqueQueryRowTransformError::NoRows)
+        )
+    }
+
+    /// The error is transient, executing the same statement again can succeed
+    /// Note that a write that timed out may have been applied, only retry idempotent statements
+    pub fn is_retryable(&self) -> bool {
+        self.is_timeout()
+            || self.is_unavailable()
+            || matches!(
+                self,
+                Error::QueryError(QueryError::IoError(_))
+                    | Error::QueryError(QueryError::DbError(DbError::Overloaded, _))
+                    | Error::QueryError(QueryError::DbError(DbError::IsBootstrapping, _))
+            )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use scylla::frame::types::Consistency;
+
+    #[test]
+    fn classification() {
+        let timeout = Error::from(QueryError::TimeoutError);
+
+        assert!(timeout.is_timeout());
+        assert!(timeout.is_retryable());
+        assert!(!timeout.is_unavailable());
+
+        let unavailable = Error::from(QueryError::DbError(
+            DbError::Unavailable {
+                consistency: Consistency::Quorum,
+                required: 2,
+                alive: 1,
+            },
+            "Cannot achieve consistency level".to_string(),
+        ));
+
+        assert!(unavailable.is_unavailable());
+        assert!(unavailable.is_retryable());
+        assert!(!unavailable.is_timeout());
+
+        let not_found = Error::from(UniqueQueryRowTransformError::NoRows);
+
+        assert!(not_found.is_not_found());
+        assert!(!not_found.is_retryable());
+
+        let syntax = Error::from(QueryError::DbError(
+            DbError::SyntaxError,
+            "line 1:0 no viable alternative".to_string(),
+        ));
+
+        assert!(!syntax.is_retryable());
+    }
+}
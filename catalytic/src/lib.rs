@@ -1,12 +1,24 @@
 use scylla::Bytes;
 
+pub mod batch;
 pub mod capitalizing;
+pub mod clustering_range;
+pub mod cql_type;
 pub mod env_property_reader;
+mod error;
+pub mod map_entries;
 pub mod materialized_view;
+pub mod page_cursor;
 pub mod query_metadata;
 pub mod query_transform;
+pub mod retry_policy;
 pub mod runtime;
+pub mod schema_provider;
+pub mod secondary_index;
 mod sort;
 pub mod table_metadata;
+pub mod user_defined_type;
+
+pub use error::Error;
 
 pub type Cursor = Option<Bytes>;
@@ -0,0 +1,118 @@
+//! Maps of which the key type can not be the key of a Rust map (e.g. map<float, int>)
+//! The entries are kept in a vec, in the order the database returns them
+use scylla::cql_to_rust::{FromCqlVal, FromCqlValError};
+use scylla::frame::response::result::CqlValue;
+use scylla::frame::value::{Value, ValueTooBig};
+use std::convert::TryFrom;
+
+/// The entries of a map, serialized and deserialized as a CQL map
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MapEntries<K, V>(pub Vec<(K, V)>);
+
+impl<K: PartialEq, V> MapEntries<K, V> {
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Replaces the value if the key is already present, just like a Rust map
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => Some(std::mem::replace(v, value)),
+            None => {
+                self.0.push((key, value));
+
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.0.iter().position(|(k, _)| k == key)?;
+
+        Some(self.0.remove(index).1)
+    }
+}
+
+impl<K, V> From<Vec<(K, V)>> for MapEntries<K, V> {
+    fn from(entries: Vec<(K, V)>) -> Self {
+        MapEntries(entries)
+    }
+}
+
+impl<K: FromCqlVal<CqlValue>, V: FromCqlVal<CqlValue>> FromCqlVal<CqlValue> for MapEntries<K, V> {
+    fn from_cql(cql_val: CqlValue) -> Result<Self, FromCqlValError> {
+        match cql_val {
+            CqlValue::Map(entries) => entries
+                .into_iter()
+                .map(|(k, v)| Ok((K::from_cql(k)?, V::from_cql(v)?)))
+                .collect::<Result<_, _>>()
+                .map(MapEntries),
+            _ => Err(FromCqlValError::BadCqlType),
+        }
+    }
+}
+
+impl<K: Value, V: Value> Value for MapEntries<K, V> {
+    fn serialize(&self, buf: &mut Vec<u8>) -> Result<(), ValueTooBig> {
+        let start = buf.len();
+
+        // Placeholder for the length
+        buf.extend_from_slice(&[0; 4]);
+        buf.extend_from_slice(
+            &i32::try_from(self.0.len())
+                .map_err(|_| ValueTooBig)?
+                .to_be_bytes(),
+        );
+
+        for (k, v) in &self.0 {
+            k.serialize(buf)?;
+            v.serialize(buf)?;
+        }
+
+        let len = i32::try_from(buf.len() - start - 4).map_err(|_| ValueTooBig)?;
+
+        buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn entries() {
+        let mut entries = MapEntries(vec![(1.5f32, 1), (2.5, 2)]);
+
+        assert_eq!(Some(1), entries.insert(1.5, 3));
+        assert_eq!(None, entries.insert(3.5, 4));
+        assert_eq!(Some(&3), entries.get(&1.5));
+        assert_eq!(Some(2), entries.remove(&2.5));
+        assert_eq!(vec![(1.5, 3), (3.5, 4)], entries.0);
+    }
+
+    #[test]
+    fn cql_map() {
+        let mut map = HashMap::new();
+
+        map.insert(1, "a".to_string());
+
+        let entries = MapEntries(vec![(1, "a".to_string())]);
+        let (mut expected, mut serialized) = (vec![], vec![]);
+
+        map.serialize(&mut expected).unwrap();
+        entries.serialize(&mut serialized).unwrap();
+
+        assert_eq!(expected, serialized);
+        assert_eq!(
+            entries,
+            MapEntries::from_cql(CqlValue::Map(vec![(
+                CqlValue::Int(1),
+                CqlValue::Text("a".to_string())
+            )]))
+            .unwrap()
+        );
+    }
+}
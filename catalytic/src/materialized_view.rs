@@ -1,11 +1,11 @@
 use crate::capitalizing::table_name_to_struct_name;
 use crate::env_property_reader::keyspace;
-use crate::query_metadata::query_columns;
 use crate::runtime::query_collect_to_vec;
+use crate::schema_provider::{DatabaseSchema, SchemaProvider};
 use std::collections::HashSet;
 
 /// Information about a materialized view, as queried from the database
-#[derive(scylla::FromRow)]
+#[derive(scylla::FromRow, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct MaterializedViewFromDb {
     /// The table name of the materialized view
     pub table_name: String,
@@ -40,23 +40,22 @@ pub fn query_materialized_views() -> Vec<MaterializedViewFromDb> {
 
 /// Queries a specific materialized view, and gives back information about the materialized view
 pub fn query_materialized_view(table_name: &str) -> Option<MaterializedView> {
-    let query = format!(
-        "{}  and view_name = '{}'",
-        query_for_materialized_view(),
-        table_name
-    );
-    let mut rows: Vec<MaterializedViewFromDb> = query_collect_to_vec(query, &[]);
-
-    if rows.is_empty() {
-        return None;
-    }
-
-    assert_eq!(1, rows.len());
-
-    let mv = rows.remove(0);
+    materialized_view(&DatabaseSchema, table_name)
+}
 
-    let query_column_names = |table_name| {
-        query_columns(table_name)
+/// Gives back information about a materialized view, read from the given schema
+pub fn materialized_view(
+    schema: &dyn SchemaProvider,
+    table_name: &str,
+) -> Option<MaterializedView> {
+    let mv = schema
+        .materialized_views()
+        .into_iter()
+        .find(|mv| mv.table_name == table_name)?;
+
+    let query_column_names = |table_name: &str| {
+        schema
+            .columns(table_name)
             .into_iter()
             .map(|r| r.column_name)
             .collect::<HashSet<_>>()
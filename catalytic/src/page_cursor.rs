@@ -0,0 +1,224 @@
+//! Opaque cursors to continue a paged query, which can be handed out to clients (e.g. in an HTTP API)
+//! A cursor contains the paging state together with a hash of the query and its values, so a cursor
+//! of one query can not be used to page through another query
+use scylla::frame::value::SerializedValues;
+use scylla::Bytes;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+
+const VERSION: u8 = 1;
+const HASH_LEN: usize = 8;
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// This error is returned when a cursor can not be decoded or belongs to another query
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PageCursorError {
+    #[error("The cursor is not a valid page cursor")]
+    InvalidCursor,
+    #[error("The cursor belongs to another query")]
+    OtherQuery,
+}
+
+/// The cursor of the next page of a query, encoded as URL-safe base64 without padding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCursor {
+    hash: u64,
+    paging_state: Bytes,
+}
+
+/// A page of rows together with the cursor of the next page
+/// The next cursor is None when this was the last page
+pub struct Page<T> {
+    pub entities: Vec<T>,
+    pub next_cursor: Option<PageCursor>,
+}
+
+impl PageCursor {
+    pub(crate) fn new(hash: u64, paging_state: Bytes) -> PageCursor {
+        PageCursor { hash, paging_state }
+    }
+
+    /// Decodes a cursor, use SelectMultiple::decode_cursor to also check that it belongs to a query
+    pub fn decode(encoded: &str) -> Result<PageCursor, PageCursorError> {
+        let bytes = decode_base64(encoded).ok_or(PageCursorError::InvalidCursor)?;
+
+        if bytes.len() <= 1 + HASH_LEN || bytes[0] != VERSION {
+            return Err(PageCursorError::InvalidCursor);
+        }
+
+        let mut hash = [0; HASH_LEN];
+
+        hash.copy_from_slice(&bytes[1..=HASH_LEN]);
+
+        Ok(PageCursor {
+            hash: u64::from_be_bytes(hash),
+            paging_state: Bytes::copy_from_slice(&bytes[1 + HASH_LEN..]),
+        })
+    }
+
+    pub fn encode(&self) -> String {
+        let mut bytes = Vec::with_capacity(1 + HASH_LEN + self.paging_state.len());
+
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.hash.to_be_bytes());
+        bytes.extend_from_slice(&self.paging_state);
+
+        encode_base64(&bytes)
+    }
+
+    /// Checks that the cursor was created for the query with the given hash
+    pub(crate) fn validate(&self, hash: u64) -> Result<(), PageCursorError> {
+        if self.hash == hash {
+            Ok(())
+        } else {
+            Err(PageCursorError::OtherQuery)
+        }
+    }
+
+    /// Returns the paging state if the cursor was created for the query with the given hash
+    pub(crate) fn paging_state(self, hash: u64) -> Result<Bytes, PageCursorError> {
+        self.validate(hash)?;
+
+        Ok(self.paging_state)
+    }
+}
+
+impl Display for PageCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl Serialize for PageCursor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.encode())
+    }
+}
+
+impl<'de> Deserialize<'de> for PageCursor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+
+        PageCursor::decode(&encoded).map_err(D::Error::custom)
+    }
+}
+
+/// Hashes the query together with the values, FNV-1a is used because it's stable across
+/// releases and processes, so cursors stay valid after a restart
+pub(crate) fn query_hash(query: &str, values: &SerializedValues) -> u64 {
+    let mut serialized = vec![];
+
+    values.write_to_request(&mut serialized);
+
+    query
+        .as_bytes()
+        .iter()
+        .chain(&[0])
+        .chain(&serialized)
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() * 4 + 2) / 3);
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..=chunk.len() {
+            encoded.push(ALPHABET[((n >> (18 - 6 * i)) & 63) as usize] as char);
+        }
+    }
+
+    encoded
+}
+
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 3 / 4);
+
+    for chunk in encoded.as_bytes().chunks(4) {
+        // A single character can not encode a whole byte
+        if chunk.len() == 1 {
+            return None;
+        }
+
+        let mut n = 0;
+
+        for (i, c) in chunk.iter().enumerate() {
+            let value = ALPHABET.iter().position(|a| a == c)? as u32;
+
+            n |= value << (18 - 6 * i);
+        }
+
+        for i in 0..chunk.len() - 1 {
+            bytes.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn base64() {
+        for (bytes, encoded) in [
+            (&b""[..], ""),
+            (&b"f"[..], "Zg"),
+            (&b"fo"[..], "Zm8"),
+            (&b"foo"[..], "Zm9v"),
+            (&b"foob"[..], "Zm9vYg"),
+            (&[0xfb, 0xff][..], "-_8"),
+        ]
+        .iter()
+        {
+            assert_eq!(*encoded, encode_base64(bytes));
+            assert_eq!(Some(bytes.to_vec()), decode_base64(encoded));
+        }
+
+        assert_eq!(None, decode_base64("Zm9vY"));
+        assert_eq!(None, decode_base64("Zm+v"));
+    }
+
+    #[test]
+    fn cursor() {
+        let mut values = SerializedValues::new();
+
+        values.add_value(&1).unwrap();
+
+        let hash = query_hash("select * from t where a = ?", &values);
+        let cursor = PageCursor::new(hash, Bytes::from_static(&[1, 2, 3]));
+        let decoded = PageCursor::decode(&cursor.encode()).unwrap();
+
+        assert_eq!(cursor, decoded);
+        assert_eq!(
+            Ok(Bytes::from_static(&[1, 2, 3])),
+            decoded.paging_state(hash)
+        );
+
+        let mut other_values = SerializedValues::new();
+
+        other_values.add_value(&2).unwrap();
+
+        let other_hash = query_hash("select * from t where a = ?", &other_values);
+
+        assert_ne!(hash, other_hash);
+        assert_eq!(
+            Err(PageCursorError::OtherQuery),
+            cursor.paging_state(other_hash)
+        );
+        assert_eq!(
+            Err(PageCursorError::InvalidCursor),
+            PageCursor::decode("AQ")
+        );
+    }
+}
@@ -20,6 +20,10 @@ pub struct QueryMetadata {
     pub limited: bool,
     /// The TTL of the query if provided
     pub ttl: Option<Ttl>,
+    /// The condition if the query is a lightweight transaction
+    pub lwt: Option<Lwt>,
+    /// Only true if the query ends with 'allow filtering'
+    pub allow_filtering: bool,
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -28,6 +32,15 @@ pub enum Ttl {
     Fixed(i32),
 }
 
+/// The 'if' clause of a lightweight transaction
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Lwt {
+    IfNotExists,
+    IfExists,
+    /// Conditions on the columns of the row, e.g. 'if a = ?'
+    IfCondition,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct ParameterizedColumnType {
     pub column_type: ColumnType,
@@ -38,6 +51,8 @@ pub struct ParameterizedColumnType {
 pub enum ParameterizedValue {
     ExtractedColumn(ColumnInQuery),
     UsingTtl,
+    /// The write timestamp in microseconds
+    UsingTimestamp,
     Limit,
 }
 
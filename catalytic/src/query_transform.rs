@@ -1,22 +1,39 @@
 /// This mod contains structs which are returned from the generated code
 /// The structs are quite specific in what they can do. This means you don't have generic 'execute'
 /// methods, but specific methods, like 'update', 'delete' etc
+use crate::page_cursor::{query_hash, Page, PageCursor};
+use crate::retry_policy::{retry, RetryPolicy};
 use crate::Cursor;
+use crate::Error;
+use futures_util::stream::FuturesUnordered;
+pub use futures_util::Stream;
 use futures_util::{StreamExt, TryStreamExt};
-use scylla::cql_to_rust::FromRowError;
+use scylla::batch::{Batch as ScyllaBatch, BatchType};
+use scylla::cql_to_rust::{FromCqlVal, FromRowError};
+use scylla::frame::response::result::{CqlValue, Row};
+use scylla::frame::types::{Consistency, SerialConsistency};
 use scylla::frame::value::SerializedValues;
 use scylla::frame::value::{SerializeValuesError, ValueList};
 use scylla::query::Query;
 use scylla::transport::errors::QueryError;
-use scylla::transport::iterator::TypedRowIterator;
+use scylla::transport::iterator::{RowIterator, TypedRowIterator};
 use scylla::{CachingSession, FromRow, QueryResult};
 use std::fmt::{Debug, Formatter};
+use std::future::Future;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
 
-pub type ScyllaQueryResult = Result<QueryResult, QueryError>;
+pub type ScyllaQueryResult = Result<QueryResult, Error>;
 pub type CountType = i64;
 pub type TtlType = i32;
+/// The Murmur3 token of a partition key
+pub type TokenType = i64;
+/// A write timestamp in microseconds since the Unix epoch
+pub type TimestampType = i64;
 
 /// The Count struct is returned when a count query is executed
 #[derive(scylla::FromRow, Debug, Clone, Copy, PartialEq)]
@@ -35,19 +52,11 @@ pub enum UniqueQueryRowTransformError {
     FromRowError(FromRowError),
 }
 
-/// This error can be thrown when a row is queried, but an error occurred
-#[derive(Debug, Clone)]
-pub enum SingleSelectQueryErrorTransform {
-    UniqueQueryRowTransformError(UniqueQueryRowTransformError),
-    QueryError(QueryError),
-}
+/// This error can be thrown when a row is queried, equals Error
+pub type SingleSelectQueryErrorTransform = Error;
 
-/// This error can be thrown when multiple rows were queried, but an error occurred
-#[derive(Debug, Clone)]
-pub enum MultipleSelectQueryErrorTransform {
-    FromRowError(FromRowError),
-    QueryError(QueryError),
-}
+/// This error can be thrown when multiple rows were queried, equals Error
+pub type MultipleSelectQueryErrorTransform = Error;
 
 /// This is returned when a query successfully completed, were the query could have retrieved
 /// an arbitrary amount of entities
@@ -72,36 +81,6 @@ impl<T> Deref for QueryEntityVecResult<T> {
     }
 }
 
-impl From<FromRowError> for MultipleSelectQueryErrorTransform {
-    fn from(u: FromRowError) -> Self {
-        MultipleSelectQueryErrorTransform::FromRowError(u)
-    }
-}
-
-impl From<QueryError> for MultipleSelectQueryErrorTransform {
-    fn from(u: QueryError) -> Self {
-        MultipleSelectQueryErrorTransform::QueryError(u)
-    }
-}
-
-impl From<UniqueQueryRowTransformError> for SingleSelectQueryErrorTransform {
-    fn from(u: UniqueQueryRowTransformError) -> Self {
-        SingleSelectQueryErrorTransform::UniqueQueryRowTransformError(u)
-    }
-}
-
-impl From<QueryError> for SingleSelectQueryErrorTransform {
-    fn from(u: QueryError) -> Self {
-        SingleSelectQueryErrorTransform::QueryError(u)
-    }
-}
-
-impl From<SerializeValuesError> for SingleSelectQueryErrorTransform {
-    fn from(u: SerializeValuesError) -> Self {
-        SingleSelectQueryErrorTransform::QueryError(u.into())
-    }
-}
-
 /// This is the result of a successfully queried unique row where the unique row is optional
 pub struct QueryResultUniqueRow<T> {
     pub entity: Option<T>,
@@ -199,6 +178,268 @@ impl<T: FromRow> QueryResultUniqueRowExpect<T> {
     }
 }
 
+/// This is the result of an 'insert ... if not exists'
+/// The result contains the '[applied]' column, followed by the columns of the existing row
+pub struct QueryResultLwt<T> {
+    /// False if the condition of the transaction was not met
+    pub applied: bool,
+    /// The existing row, only filled when the transaction was not applied and the row exists
+    pub existing: Option<T>,
+    /// The rows variable will be always empty here
+    /// They are moved and transformed into the applied and existing variables
+    pub query_result: QueryResult,
+}
+
+impl<T: FromRow> QueryResultLwt<T> {
+    fn from_query_result(
+        query_result: QueryResult,
+    ) -> Result<QueryResultLwt<T>, UniqueQueryRowTransformError> {
+        let conditional = QueryResultConditional::from_query_result(query_result)?;
+        let existing = match conditional.existing {
+            Some(row) => {
+                Some(T::from_row(row).map_err(UniqueQueryRowTransformError::FromRowError)?)
+            }
+            None => None,
+        };
+
+        Ok(QueryResultLwt {
+            applied: conditional.applied,
+            existing,
+            query_result: conditional.query_result,
+        })
+    }
+}
+
+/// This is the result of an update or delete with an 'if' clause
+/// Which columns follow the '[applied]' column depends on the database: Cassandra only returns the
+/// columns of the condition, ScyllaDB returns all columns, so the existing row is not typed
+pub struct QueryResultConditional {
+    /// False if the condition of the transaction was not met
+    pub applied: bool,
+    /// The columns after '[applied]', only filled when the transaction was not applied and the row exists
+    pub existing: Option<Row>,
+    /// The rows variable will be always empty here
+    /// They are moved and transformed into the applied and existing variables
+    pub query_result: QueryResult,
+}
+
+impl QueryResultConditional {
+    fn from_query_result(
+        mut query_result: QueryResult,
+    ) -> Result<QueryResultConditional, UniqueQueryRowTransformError> {
+        let mut rows = None;
+
+        std::mem::swap(&mut query_result.rows, &mut rows);
+
+        let mut rows = rows.unwrap_or_default();
+
+        if rows.len() > 1 {
+            return Err(UniqueQueryRowTransformError::MoreThanOneRow);
+        }
+
+        let mut columns = match rows.pop() {
+            Some(row) if !row.columns.is_empty() => row.columns,
+            _ => return Err(UniqueQueryRowTransformError::NoRows),
+        };
+        let applied = bool::from_cql(columns.remove(0)).map_err(|err| {
+            UniqueQueryRowTransformError::FromRowError(FromRowError::BadCqlVal { err, column: 0 })
+        })?;
+        // When the row doesn't exist, all the columns are null
+        let existing = if !applied && columns.iter().any(Option::is_some) {
+            Some(Row { columns })
+        } else {
+            None
+        };
+
+        Ok(QueryResultConditional {
+            applied,
+            existing,
+            query_result,
+        })
+    }
+}
+
+/// A row together with the writetime and ttl of columns, selected with 'writetime(column)' and 'ttl(column)'
+/// The metadata is a tuple with a value for every selected function, in the order of the query
+/// A value is None if the cell is null or, in case of a ttl, if the cell doesn't expire
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithMetadata<T, M> {
+    pub entity: T,
+    pub metadata: M,
+}
+
+impl<T, M> Deref for WithMetadata<T, M> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entity
+    }
+}
+
+impl<T, M> DerefMut for WithMetadata<T, M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.entity
+    }
+}
+
+/// The selected writetime and ttl values, these are the last columns of the row
+pub trait CellMetadata: FromRow {
+    /// The amount of selected values
+    const COLUMNS: usize;
+}
+
+macro_rules! cell_metadata {
+    ($count: expr; $($ty: ident),+) => {
+        impl<$($ty: FromCqlVal<Option<CqlValue>>),+> CellMetadata for ($($ty,)+) {
+            const COLUMNS: usize = $count;
+        }
+    };
+}
+cell_metadata!(1; A);
+cell_metadata!(2; A, B);
+cell_metadata!(3; A, B, C);
+cell_metadata!(4; A, B, C, D);
+cell_metadata!(5; A, B, C, D, E);
+cell_metadata!(6; A, B, C, D, E, F);
+cell_metadata!(7; A, B, C, D, E, F, G);
+cell_metadata!(8; A, B, C, D, E, F, G, H);
+
+impl<T: FromRow, M: CellMetadata> FromRow for WithMetadata<T, M> {
+    fn from_row(mut row: Row) -> Result<Self, FromRowError> {
+        let len = row.columns.len();
+
+        if len < M::COLUMNS {
+            return Err(FromRowError::WrongRowSize {
+                expected: M::COLUMNS,
+                actual: len,
+            });
+        }
+
+        let metadata = row.columns.split_off(len - M::COLUMNS);
+
+        Ok(WithMetadata {
+            entity: T::from_row(row)?,
+            metadata: M::from_row(Row { columns: metadata })?,
+        })
+    }
+}
+
+/// Options for a single statement, options which are not set fall back to the defaults of the session
+/// The options can be set on every query type with the 'with_options' method, e.g.:
+/// pk.update_name_qv(&name)?.with_options(options).update(&session)
+#[derive(Debug, Clone, Default)]
+pub struct QueryOptions {
+    pub consistency: Option<Consistency>,
+    /// The consistency of the paxos phase of a lightweight transaction
+    pub serial_consistency: Option<SerialConsistency>,
+    /// Client side timeout of a request, every page gets this timeout when the pages are collected in
+    /// memory, but a stream of rows only applies it to the first page
+    pub timeout: Option<Duration>,
+    /// Idempotent statements can safely be retried, selects are always retried as idempotent
+    pub idempotent: bool,
+    pub tracing: bool,
+    /// The write timestamp in microseconds, instead of the timestamp the coordinator picks
+    pub timestamp: Option<i64>,
+    /// Retries requests that failed with a transient error, no request is retried if no policy is set
+    pub retry_policy: Option<Arc<dyn RetryPolicy>>,
+}
+
+impl QueryOptions {
+    pub fn consistency(mut self, consistency: Consistency) -> Self {
+        self.consistency = Some(consistency);
+        self
+    }
+
+    pub fn serial_consistency(mut self, serial_consistency: SerialConsistency) -> Self {
+        self.serial_consistency = Some(serial_consistency);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+        self
+    }
+
+    pub fn tracing(mut self, tracing: bool) -> Self {
+        self.tracing = tracing;
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: impl RetryPolicy + 'static) -> Self {
+        self.retry_policy = Some(Arc::new(retry_policy));
+        self
+    }
+
+    /// Creates the query that will be executed
+    fn query(&self, query: &str) -> Query {
+        let mut query: Query = query.into();
+
+        if let Some(consistency) = self.consistency {
+            query.set_consistency(consistency);
+        }
+
+        query.set_serial_consistency(self.serial_consistency);
+        query.set_is_idempotent(self.idempotent);
+        query.set_tracing(self.tracing);
+        query.set_timestamp(self.timestamp);
+
+        query
+    }
+
+    /// Creates the batch that will be executed, the options apply to the batch as a whole
+    pub(crate) fn batch(&self, batch_type: BatchType) -> ScyllaBatch {
+        let mut batch = ScyllaBatch::new(batch_type);
+
+        if let Some(consistency) = self.consistency {
+            batch.set_consistency(consistency);
+        }
+
+        batch.set_serial_consistency(self.serial_consistency);
+        batch.set_is_idempotent(self.idempotent);
+        batch.set_tracing(self.tracing);
+        batch.set_timestamp(self.timestamp);
+
+        batch
+    }
+
+    pub(crate) async fn with_timeout<T>(
+        &self,
+        request: impl Future<Output = Result<T, QueryError>>,
+    ) -> Result<T, QueryError> {
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, request)
+                .await
+                .map_err(|_| QueryError::TimeoutError)?,
+            None => request.await,
+        }
+    }
+
+    /// Executes the request again according to the retry policy, the timeout applies to every attempt
+    /// A read can't change anything, so it is idempotent even if the options don't say so
+    pub(crate) async fn retry<T, F: Future<Output = Result<T, Error>>>(
+        &self,
+        read: bool,
+        request: impl FnMut() -> F,
+    ) -> Result<T, Error> {
+        retry(
+            self.retry_policy.as_deref(),
+            self.idempotent || read,
+            request,
+        )
+        .await
+    }
+}
+
 pub struct Qv<R: AsRef<str> = &'static str, V: ValueList = SerializedValues> {
     pub query: R,
     pub values: V,
@@ -222,76 +463,158 @@ impl<R: AsRef<str>, V: ValueList> Debug for Qv<R, V> {
 }
 
 impl<R: AsRef<str>, V: ValueList> Qv<R, V> {
-    async fn execute(&self, session: &CachingSession) -> ScyllaQueryResult {
+    async fn execute(&self, session: &CachingSession, options: &QueryOptions) -> ScyllaQueryResult {
+        options
+            .retry(false, || self.execute_once(session, options))
+            .await
+    }
+
+    /// Same as execute, but the query is always retried as an idempotent query
+    async fn select(&self, session: &CachingSession, options: &QueryOptions) -> ScyllaQueryResult {
+        options
+            .retry(true, || self.execute_once(session, options))
+            .await
+    }
+
+    async fn execute_once(
+        &self,
+        session: &CachingSession,
+        options: &QueryOptions,
+    ) -> ScyllaQueryResult {
         let as_ref = self.query.as_ref();
 
         tracing::debug!("Executing: {}", as_ref);
 
-        session.execute(as_ref, &self.values).await
+        Ok(options
+            .with_timeout(session.execute(options.query(as_ref), &self.values))
+            .await?)
     }
 
+    /// A failure while fetching a page retries the whole query, rows of earlier pages are discarded
     async fn execute_all_in_memory<T: FromRow, N>(
         &self,
         session: &CachingSession,
+        options: &QueryOptions,
         page_size: i32,
         transform: impl Fn(T) -> N + Copy,
-    ) -> Result<QueryEntityVec<N>, MultipleSelectQueryErrorTransform> {
+    ) -> Result<QueryEntityVec<N>, Error> {
+        options
+            .retry(true, || {
+                self.execute_all_in_memory_once(session, options, page_size, transform)
+            })
+            .await
+    }
+
+    async fn execute_all_in_memory_once<T: FromRow, N>(
+        &self,
+        session: &CachingSession,
+        options: &QueryOptions,
+        page_size: i32,
+        transform: impl Fn(T) -> N + Copy,
+    ) -> Result<QueryEntityVec<N>, Error> {
         let as_ref = self.query.as_ref();
 
         tracing::debug!("Executing with page size: {}: {}", page_size, as_ref);
 
-        let mut query: Query = as_ref.into();
+        let mut query = options.query(as_ref);
 
         query.set_page_size(page_size);
 
-        let rows = session
-            .execute_iter(query, &self.values)
-            .await?
-            .map(|c| {
-                let row = c.map(T::from_row);
-                let transformed = row.map(|r| r.map(transform));
-
-                match transformed {
-                    Ok(ok) => match ok {
-                        Ok(row) => Ok(row),
-                        Err(err) => Err(MultipleSelectQueryErrorTransform::FromRowError(err)),
-                    },
-                    Err(err) => Err(MultipleSelectQueryErrorTransform::QueryError(err)),
-                }
-            })
-            .try_collect::<Vec<_>>()
+        let mut rows = options
+            .with_timeout(session.execute_iter(query, &self.values))
             .await?;
+        let mut entities = vec![];
+
+        // Only the first row of a page waits for the page to be fetched, so this is a timeout per page
+        while let Some(row) = options
+            .with_timeout(async { rows.next().await.transpose() })
+            .await?
+        {
+            entities.push(transform(T::from_row(row)?));
+        }
 
-        Ok(QueryEntityVec { entities: rows })
+        Ok(QueryEntityVec { entities })
     }
 
     async fn execute_iter<T: FromRow>(
         &self,
         session: &CachingSession,
+        options: &QueryOptions,
+        page_size: Option<i32>,
+    ) -> Result<TypedRowIterator<T>, Error> {
+        Ok(self
+            .execute_iter_rows(session, options, page_size)
+            .await?
+            .into_typed())
+    }
+
+    async fn execute_iter_rows(
+        &self,
+        session: &CachingSession,
+        options: &QueryOptions,
         page_size: Option<i32>,
-    ) -> Result<TypedRowIterator<T>, QueryError> {
+    ) -> Result<RowIterator, Error> {
         let as_ref = self.query.as_ref();
 
         tracing::debug!("Executing with page size: {:#?}: {}", page_size, as_ref);
 
-        let mut query: Query = as_ref.into();
+        let mut query = options.query(as_ref);
 
         if let Some(p) = page_size {
             query.set_page_size(p);
         }
 
-        let result = session.execute_iter(query, &self.values).await?;
+        let query = &query;
 
-        Ok(result.into_typed())
+        options
+            .retry(true, move || async move {
+                Ok(options
+                    .with_timeout(session.execute_iter(query.clone(), &self.values))
+                    .await?)
+            })
+            .await
     }
 
     async fn execute_iter_paged<T: FromRow, N>(
         &self,
         session: &CachingSession,
+        options: &QueryOptions,
         page_size: Option<i32>,
         paging_state: Cursor,
         transform: impl Fn(T) -> N + Copy,
-    ) -> Result<QueryEntityVecResult<N>, MultipleSelectQueryErrorTransform> {
+    ) -> Result<QueryEntityVecResult<N>, Error> {
+        let mut result = self
+            .execute_page(session, options, page_size, paging_state)
+            .await?;
+        let rows = self.transform(&mut result, transform)?;
+
+        Ok(QueryEntityVecResult {
+            entities: rows,
+            query_result: result,
+        })
+    }
+
+    async fn execute_page(
+        &self,
+        session: &CachingSession,
+        options: &QueryOptions,
+        page_size: Option<i32>,
+        paging_state: Cursor,
+    ) -> ScyllaQueryResult {
+        options
+            .retry(true, || {
+                self.execute_page_once(session, options, page_size, paging_state.clone())
+            })
+            .await
+    }
+
+    async fn execute_page_once(
+        &self,
+        session: &CachingSession,
+        options: &QueryOptions,
+        page_size: Option<i32>,
+        paging_state: Cursor,
+    ) -> ScyllaQueryResult {
         let as_ref = self.query.as_ref();
 
         tracing::debug!(
@@ -301,21 +624,15 @@ impl<R: AsRef<str>, V: ValueList> Qv<R, V> {
             as_ref,
         );
 
-        let mut query: Query = as_ref.into();
+        let mut query = options.query(as_ref);
 
         if let Some(p) = page_size {
             query.set_page_size(p);
         }
 
-        let mut result = session
-            .execute_paged(query, &self.values, paging_state)
-            .await?;
-        let rows = self.transform(&mut result, transform)?;
-
-        Ok(QueryEntityVecResult {
-            entities: rows,
-            query_result: result,
-        })
+        Ok(options
+            .with_timeout(session.execute_paged(query, &self.values, paging_state))
+            .await?)
     }
 
     fn transform<T: FromRow, N>(
@@ -341,14 +658,24 @@ macro_rules! simple_qv_holder {
         #[derive(Debug)]
         pub struct $ident<R: AsRef<str> = &'static str, V: ValueList = SerializedValues> {
             pub qv: Qv<R, V>,
+            pub options: QueryOptions,
         }
         impl<R: AsRef<str>, V: ValueList> $ident<R, V> {
             pub fn new(qv: Qv<R, V>) -> Self {
-                Self { qv }
+                Self {
+                    qv,
+                    options: QueryOptions::default(),
+                }
+            }
+
+            /// Replaces the options that are used when executing the statement
+            pub fn with_options(mut self, options: QueryOptions) -> Self {
+                self.options = options;
+                self
             }
 
             pub async fn $method(&self, session: &CachingSession) -> ScyllaQueryResult {
-                self.qv.execute(session).await
+                self.qv.execute(session, &self.options).await
             }
         }
 
@@ -362,7 +689,7 @@ macro_rules! simple_qv_holder {
 
         impl<R: AsRef<str> + Clone, V: ValueList + Clone> Clone for $ident<R, V> {
             fn clone(&self) -> Self {
-                $ident::new(self.qv.clone())
+                $ident::new(self.qv.clone()).with_options(self.options.clone())
             }
         }
     };
@@ -371,24 +698,42 @@ simple_qv_holder!(DeleteMultiple, delete_multiple);
 simple_qv_holder!(DeleteUnique, delete_unique);
 simple_qv_holder!(Insert, insert);
 simple_qv_holder!(Update, update);
+simple_qv_holder!(UpdateCounter, update);
 simple_qv_holder!(Truncate, truncate);
 
+/// The rows are decoded as 'P' and converted to 'T', 'query_as!' uses this to map the columns of
+/// the projection to the fields of a struct by name
 macro_rules! read_transform {
     ($ ident : ident) => {
         #[derive(Debug)]
-        pub struct $ident<T: FromRow, R: AsRef<str> = &'static str, V: ValueList = SerializedValues>
-        {
+        pub struct $ident<
+            T: From<P>,
+            R: AsRef<str> = &'static str,
+            V: ValueList = SerializedValues,
+            P: FromRow = T,
+        > {
             pub qv: Qv<R, V>,
-            p: PhantomData<T>,
+            pub options: QueryOptions,
+            p: PhantomData<fn(P) -> T>,
         }
 
-        impl<T: FromRow, R: AsRef<str>, V: ValueList> $ident<T, R, V> {
-            pub fn new(qv: Qv<R, V>) -> $ident<T, R, V> {
-                $ident { qv, p: PhantomData }
+        impl<T: From<P>, R: AsRef<str>, V: ValueList, P: FromRow> $ident<T, R, V, P> {
+            pub fn new(qv: Qv<R, V>) -> $ident<T, R, V, P> {
+                $ident {
+                    qv,
+                    options: QueryOptions::default(),
+                    p: PhantomData,
+                }
+            }
+
+            /// Replaces the options that are used when executing the query
+            pub fn with_options(mut self, options: QueryOptions) -> Self {
+                self.options = options;
+                self
             }
         }
 
-        impl<T: FromRow, R: AsRef<str>, V: ValueList> Deref for $ident<T, R, V> {
+        impl<T: From<P>, R: AsRef<str>, V: ValueList, P: FromRow> Deref for $ident<T, R, V, P> {
             type Target = Qv<R, V>;
 
             fn deref(&self) -> &Self::Target {
@@ -396,9 +741,11 @@ macro_rules! read_transform {
             }
         }
 
-        impl<T: FromRow, R: AsRef<str> + Clone, V: ValueList + Clone> Clone for $ident<T, R, V> {
+        impl<T: From<P>, R: AsRef<str> + Clone, V: ValueList + Clone, P: FromRow> Clone
+            for $ident<T, R, V, P>
+        {
             fn clone(&self) -> Self {
-                $ident::new(self.qv.clone())
+                $ident::new(self.qv.clone()).with_options(self.options.clone())
             }
         }
     };
@@ -406,30 +753,88 @@ macro_rules! read_transform {
 read_transform!(SelectMultiple);
 read_transform!(SelectUnique);
 read_transform!(SelectUniqueExpect);
+read_transform!(LightweightTransaction);
+
+/// An update or delete with an 'if' clause
+#[derive(Debug)]
+pub struct ConditionalMutation<R: AsRef<str> = &'static str, V: ValueList = SerializedValues> {
+    pub qv: Qv<R, V>,
+    pub options: QueryOptions,
+}
 
-impl<T: FromRow, R: AsRef<str>, V: ValueList> SelectUnique<T, R, V> {
-    pub fn expect(self) -> SelectUniqueExpect<T, R, V> {
-        SelectUniqueExpect::new(self.qv)
+impl<R: AsRef<str>, V: ValueList> ConditionalMutation<R, V> {
+    pub fn new(qv: Qv<R, V>) -> Self {
+        Self {
+            qv,
+            options: QueryOptions::default(),
+        }
     }
 
-    pub async fn select(
-        &self,
-        session: &CachingSession,
-    ) -> Result<QueryResultUniqueRow<T>, SingleSelectQueryErrorTransform> {
-        let result = self.qv.execute(session).await?;
-        let result = QueryResultUniqueRow::from_query_result(result)?;
+    /// Replaces the options that are used when executing the statement
+    pub fn with_options(mut self, options: QueryOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Executes the update or delete, the result tells if it was applied
+    pub async fn execute(&self, session: &CachingSession) -> Result<QueryResultConditional, Error> {
+        let result = self.qv.execute(session, &self.options).await?;
+        let result = QueryResultConditional::from_query_result(result)?;
 
         Ok(result)
     }
 }
 
-impl<T: FromRow, R: AsRef<str>, V: ValueList> SelectUniqueExpect<T, R, V> {
+impl<R: AsRef<str>, V: ValueList> Deref for ConditionalMutation<R, V> {
+    type Target = Qv<R, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.qv
+    }
+}
+
+impl<R: AsRef<str> + Clone, V: ValueList + Clone> Clone for ConditionalMutation<R, V> {
+    fn clone(&self) -> Self {
+        ConditionalMutation::new(self.qv.clone()).with_options(self.options.clone())
+    }
+}
+
+impl<T: From<P>, R: AsRef<str>, V: ValueList, P: FromRow> SelectUnique<T, R, V, P> {
+    pub fn expect(self) -> SelectUniqueExpect<T, R, V, P> {
+        SelectUniqueExpect::new(self.qv).with_options(self.options)
+    }
+
+    pub async fn select(&self, session: &CachingSession) -> Result<QueryResultUniqueRow<T>, Error> {
+        let result = self.qv.select(session, &self.options).await?;
+        let result = QueryResultUniqueRow::<P>::from_query_result(result)?;
+
+        Ok(QueryResultUniqueRow {
+            entity: result.entity.map(T::from),
+            query_result: result.query_result,
+        })
+    }
+}
+
+impl<T: From<P>, R: AsRef<str>, V: ValueList, P: FromRow> SelectUniqueExpect<T, R, V, P> {
     pub async fn select(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRowExpect<T>, SingleSelectQueryErrorTransform> {
-        let result = self.qv.execute(session).await?;
-        let result = QueryResultUniqueRowExpect::from_query_result(result)?;
+    ) -> Result<QueryResultUniqueRowExpect<T>, Error> {
+        let result = self.qv.select(session, &self.options).await?;
+        let result = QueryResultUniqueRowExpect::<P>::from_query_result(result)?;
+
+        Ok(QueryResultUniqueRowExpect {
+            entity: T::from(result.entity),
+            query_result: result.query_result,
+        })
+    }
+}
+
+impl<T: FromRow, R: AsRef<str>, V: ValueList> LightweightTransaction<T, R, V> {
+    /// Executes the 'insert ... if not exists'
+    pub async fn execute(&self, session: &CachingSession) -> Result<QueryResultLwt<T>, Error> {
+        let result = self.qv.execute(session, &self.options).await?;
+        let result = QueryResultLwt::from_query_result(result)?;
 
         Ok(result)
     }
@@ -439,7 +844,7 @@ impl<R: AsRef<str>, V: ValueList> SelectUniqueExpect<Count, R, V> {
     pub async fn select_count(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRowExpect<CountType>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRowExpect<CountType>, Error> {
         let count: QueryResultUniqueRowExpect<Count> = self.select(session).await?;
         Ok(QueryResultUniqueRowExpect {
             entity: count.entity.count,
@@ -449,20 +854,25 @@ impl<R: AsRef<str>, V: ValueList> SelectUniqueExpect<Count, R, V> {
 }
 
 impl<T: FromRow, R: AsRef<str>, V: ValueList> SelectMultiple<T, R, V> {
+    /// Only available when the rows are not converted, use select_stream otherwise
     pub async fn select(
         &self,
         session: &CachingSession,
         page_size: Option<i32>,
-    ) -> Result<TypedRowIterator<T>, QueryError> {
-        self.qv.execute_iter(session, page_size).await
+    ) -> Result<TypedRowIterator<T>, Error> {
+        self.qv
+            .execute_iter(session, &self.options, page_size)
+            .await
     }
+}
 
+impl<T: From<P>, R: AsRef<str>, V: ValueList, P: FromRow> SelectMultiple<T, R, V, P> {
     pub async fn select_paged(
         &self,
         session: &CachingSession,
         page_size: Option<i32>,
         paging_state: Cursor,
-    ) -> Result<QueryEntityVecResult<T>, MultipleSelectQueryErrorTransform> {
+    ) -> Result<QueryEntityVecResult<T>, Error> {
         self.select_paged_transform(session, page_size, paging_state, |v| v)
             .await
     }
@@ -473,9 +883,15 @@ impl<T: FromRow, R: AsRef<str>, V: ValueList> SelectMultiple<T, R, V> {
         page_size: Option<i32>,
         paging_state: Cursor,
         transform: impl Fn(T) -> N + Copy,
-    ) -> Result<QueryEntityVecResult<N>, MultipleSelectQueryErrorTransform> {
+    ) -> Result<QueryEntityVecResult<N>, Error> {
         self.qv
-            .execute_iter_paged(session, page_size, paging_state, transform)
+            .execute_iter_paged(
+                session,
+                &self.options,
+                page_size,
+                paging_state,
+                move |p: P| transform(T::from(p)),
+            )
             .await
     }
 
@@ -483,7 +899,7 @@ impl<T: FromRow, R: AsRef<str>, V: ValueList> SelectMultiple<T, R, V> {
         &self,
         session: &CachingSession,
         page_size: i32,
-    ) -> Result<QueryEntityVec<T>, MultipleSelectQueryErrorTransform> {
+    ) -> Result<QueryEntityVec<T>, Error> {
         self.select_all_in_memory_transform(session, page_size, |v| v)
             .await
     }
@@ -493,9 +909,412 @@ impl<T: FromRow, R: AsRef<str>, V: ValueList> SelectMultiple<T, R, V> {
         session: &CachingSession,
         page_size: i32,
         transform: impl Fn(T) -> N + Copy,
-    ) -> Result<QueryEntityVec<N>, MultipleSelectQueryErrorTransform> {
+    ) -> Result<QueryEntityVec<N>, Error> {
         self.qv
-            .execute_all_in_memory(session, page_size, transform)
+            .execute_all_in_memory(session, &self.options, page_size, move |p: P| {
+                transform(T::from(p))
+            })
             .await
     }
+
+    /// Selects a page of rows, pass the cursor of the previous page to select the next page
+    /// The cursor must belong to this query, with the same values
+    pub async fn select_page(
+        &self,
+        session: &CachingSession,
+        page_size: i32,
+        cursor: Option<PageCursor>,
+    ) -> Result<Page<T>, Error> {
+        let hash = self.cursor_hash()?;
+        let paging_state = match cursor {
+            Some(cursor) => Some(cursor.paging_state(hash)?),
+            None => None,
+        };
+        let page = self
+            .select_paged(session, Some(page_size), paging_state)
+            .await?;
+
+        Ok(Page {
+            next_cursor: page
+                .query_result
+                .paging_state
+                .map(|paging_state| PageCursor::new(hash, paging_state)),
+            entities: page.entities,
+        })
+    }
+
+    /// Decodes a cursor and checks that it belongs to this query, with the same values
+    pub fn decode_cursor(&self, encoded: &str) -> Result<PageCursor, Error> {
+        let cursor = PageCursor::decode(encoded)?;
+
+        cursor.validate(self.cursor_hash()?)?;
+
+        Ok(cursor)
+    }
+
+    fn cursor_hash(&self) -> Result<u64, SerializeValuesError> {
+        Ok(query_hash(
+            self.qv.query.as_ref(),
+            &self.qv.values.serialized()?,
+        ))
+    }
+
+    /// Returns a stream of the rows, a page is fetched when the rows of the previous page are consumed
+    /// Pass the paging state of a previous stream to resume it
+    pub fn select_stream<'a>(
+        &self,
+        session: &'a CachingSession,
+        page_size: Option<i32>,
+        paging_state: Cursor,
+    ) -> Result<SelectStream<'a, T, T, fn(T) -> T, P>, Error> {
+        self.select_stream_transform(
+            session,
+            page_size,
+            paging_state,
+            std::convert::identity as fn(T) -> T,
+        )
+    }
+
+    /// Same as select_stream, but the transform is applied to every row when it is yielded
+    pub fn select_stream_transform<'a, N, F: FnMut(T) -> N>(
+        &self,
+        session: &'a CachingSession,
+        page_size: Option<i32>,
+        paging_state: Cursor,
+        transform: F,
+    ) -> Result<SelectStream<'a, T, N, F, P>, Error> {
+        let qv = Qv {
+            query: self.qv.query.as_ref().to_string(),
+            values: self.qv.values.serialized()?.into_owned(),
+        };
+
+        Ok(SelectStream {
+            session,
+            qv: Arc::new(qv),
+            options: self.options.clone(),
+            page_size,
+            paging_state,
+            rows: vec![].into_iter(),
+            page: None,
+            exhausted: false,
+            transform,
+            p: PhantomData,
+        })
+    }
+
+    /// Scans the whole token ring with a query per split, of which at most 'concurrency' are executed at the same time
+    /// The query must end with the restriction 'token(pk) > ? and token(pk) <= ?', the bounds of a split
+    /// are bound after the values of the query
+    /// The rows of the splits are merged in the order in which they arrive
+    pub fn scan_parallel<'a>(
+        self,
+        session: &'a CachingSession,
+        splits: usize,
+        concurrency: usize,
+    ) -> Result<impl Stream<Item = Result<T, Error>> + 'a, Error>
+    where
+        T: 'a,
+        P: 'a,
+    {
+        let query = self.qv.query.as_ref().to_string();
+        let values = self.qv.values.serialized()?.into_owned();
+        let options = self.options;
+        let splits = token_ranges(splits).into_iter().map(move |(start, end)| {
+            let query = query.clone();
+            let mut values = values.clone();
+            let options = options.clone();
+
+            Box::pin(
+                futures_util::stream::once(async move {
+                    tracing::debug!("Scanning token range ({}, {}]", start, end);
+
+                    values.add_value(&start)?;
+                    values.add_value(&end)?;
+
+                    let rows = Qv { query, values }
+                        .execute_iter_rows(session, &options, None)
+                        .await?;
+
+                    Ok::<_, Error>(
+                        rows.map(|row| -> Result<T, Error> { Ok(T::from(P::from_row(row?)?)) }),
+                    )
+                })
+                .try_flatten(),
+            )
+        });
+
+        Ok(merge(splits, concurrency))
+    }
+}
+
+/// A stream of the rows of a query, the next page is fetched when all rows of the current page are yielded
+/// Rows are converted and transformed lazily, when they are yielded
+/// Use the methods of StreamExt, like take and chunks, or next_chunk to keep access to the paging state
+/// The rows are decoded as 'P' and converted to 'T' before they are transformed
+pub struct SelectStream<'a, T, N = T, F = fn(T) -> N, P = T> {
+    session: &'a CachingSession,
+    qv: Arc<Qv<String, SerializedValues>>,
+    options: QueryOptions,
+    page_size: Option<i32>,
+    paging_state: Cursor,
+    rows: std::vec::IntoIter<Row>,
+    page: Option<Pin<Box<dyn Future<Output = ScyllaQueryResult> + Send + 'a>>>,
+    exhausted: bool,
+    transform: F,
+    p: PhantomData<fn(P) -> (T, N)>,
+}
+
+impl<T, N, F, P> SelectStream<'_, T, N, F, P> {
+    /// The paging state of the next page that will be fetched
+    /// If no rows are buffered, the stream can be resumed from this paging state without skipping or repeating rows
+    pub fn paging_state(&self) -> Cursor {
+        self.paging_state.clone()
+    }
+
+    /// The amount of rows of the current page that are not yielded yet
+    pub fn buffered(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// True if the last page has been fetched and all its rows are yielded
+    pub fn is_finished(&self) -> bool {
+        self.exhausted && self.rows.as_slice().is_empty()
+    }
+}
+
+impl<T: From<P>, N, F: FnMut(T) -> N + Unpin, P: FromRow> SelectStream<'_, T, N, F, P> {
+    /// Collects the next 'amount' rows, less rows are returned when the stream is finished
+    pub async fn next_chunk(&mut self, amount: usize) -> Result<Vec<N>, Error> {
+        let mut chunk = Vec::with_capacity(amount);
+
+        while chunk.len() < amount {
+            match self.next().await {
+                Some(row) => chunk.push(row?),
+                None => break,
+            }
+        }
+
+        Ok(chunk)
+    }
+}
+
+impl<T: From<P>, N, F: FnMut(T) -> N + Unpin, P: FromRow> Stream for SelectStream<'_, T, N, F, P> {
+    type Item = Result<N, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(row) = this.rows.next() {
+                let row = P::from_row(row).map(|p| (this.transform)(T::from(p)));
+
+                return Poll::Ready(Some(row.map_err(Into::into)));
+            }
+
+            if this.exhausted {
+                return Poll::Ready(None);
+            }
+
+            if this.page.is_none() {
+                let session = this.session;
+                let qv = this.qv.clone();
+                let options = this.options.clone();
+                let page_size = this.page_size;
+                let paging_state = this.paging_state.clone();
+
+                this.page = Some(Box::pin(async move {
+                    qv.execute_page(session, &options, page_size, paging_state)
+                        .await
+                }));
+            }
+
+            let page = this.page.as_mut().unwrap();
+            let result = match page.as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            this.page = None;
+
+            match result {
+                Ok(mut result) => {
+                    this.paging_state = result.paging_state.take();
+                    this.exhausted = this.paging_state.is_none();
+                    this.rows = result.rows.take().unwrap_or_default().into_iter();
+                }
+                Err(err) => {
+                    // The failed page can be fetched again by resuming from the paging state
+                    this.exhausted = true;
+
+                    return Poll::Ready(Some(Err(err.into())));
+                }
+            }
+        }
+    }
+}
+
+/// Splits the Murmur3 token ring in ranges of (nearly) equal size
+/// The start of a range is exclusive and the end is inclusive, like 'token(pk) > ? and token(pk) <= ?'
+/// The minimum token is never assigned to a partition, so the ranges cover all partitions
+pub fn token_ranges(splits: usize) -> Vec<(TokenType, TokenType)> {
+    let splits = splits.max(1) as i128;
+    let min = TokenType::MIN as i128;
+    let width = TokenType::MAX as i128 - min;
+    let bound = |split: i128| (min + width * split / splits) as TokenType;
+
+    (0..splits).map(|s| (bound(s), bound(s + 1))).collect()
+}
+
+/// Merges the streams, at most 'concurrency' streams are polled at the same time
+/// The next stream is started when one of the polled streams is exhausted
+fn merge<S: Stream + Unpin>(
+    mut streams: impl Iterator<Item = S>,
+    concurrency: usize,
+) -> impl Stream<Item = S::Item> {
+    let polled = streams
+        .by_ref()
+        .take(concurrency.max(1))
+        .map(StreamExt::into_future)
+        .collect::<FuturesUnordered<_>>();
+
+    futures_util::stream::unfold((streams, polled), |(mut streams, mut polled)| async move {
+        while let Some((item, stream)) = polled.next().await {
+            match item {
+                Some(item) => {
+                    polled.push(stream.into_future());
+
+                    return Some((item, (streams, polled)));
+                }
+                None => {
+                    if let Some(stream) = streams.next() {
+                        polled.push(stream.into_future());
+                    }
+                }
+            }
+        }
+
+        None
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::query_transform::{merge, token_ranges, QueryOptions, TokenType, WithMetadata};
+    use crate::retry_policy::ExponentialBackoff;
+    use crate::runtime::block_on;
+    use crate::Error;
+    use futures_util::StreamExt;
+    use scylla::cql_to_rust::FromRowError;
+    use scylla::frame::response::result::{CqlValue, Row};
+    use scylla::frame::types::{Consistency, SerialConsistency};
+    use scylla::transport::errors::QueryError;
+    use scylla::FromRow;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn token_ranges_cover_the_ring() {
+        assert_eq!(vec![(TokenType::MIN, TokenType::MAX)], token_ranges(0));
+
+        let ranges = token_ranges(3);
+
+        assert_eq!(3, ranges.len());
+        assert_eq!(TokenType::MIN, ranges[0].0);
+        assert_eq!(TokenType::MAX, ranges[2].1);
+
+        for window in ranges.windows(2) {
+            assert_eq!(window[0].1, window[1].0);
+            assert!(window[0].0 < window[0].1);
+        }
+    }
+
+    #[test]
+    fn merge_streams() {
+        let streams = (0..4).map(|s| futures_util::stream::iter(s * 10..s * 10 + 3));
+        let mut merged = block_on(merge(streams, 2).collect::<Vec<_>>());
+
+        merged.sort_unstable();
+
+        assert_eq!(vec![0, 1, 2, 10, 11, 12, 20, 21, 22, 30, 31, 32], merged);
+    }
+
+    #[test]
+    fn with_metadata() {
+        let row = Row {
+            columns: vec![
+                Some(CqlValue::Int(1)),
+                Some(CqlValue::BigInt(1634567890123456)),
+                None,
+            ],
+        };
+        let row = WithMetadata::<(i32,), (Option<i64>, Option<i32>)>::from_row(row).unwrap();
+
+        assert_eq!(1, row.entity.0);
+        assert_eq!((Some(1634567890123456), None), row.metadata);
+
+        let row = Row {
+            columns: vec![None],
+        };
+
+        assert!(matches!(
+            WithMetadata::<(i32,), (Option<i64>, Option<i32>)>::from_row(row),
+            Err(FromRowError::WrongRowSize {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn query_options() {
+        let query = QueryOptions::default().query("select * from t");
+
+        assert_eq!(None, query.get_serial_consistency());
+        assert!(!query.get_is_idempotent());
+        assert!(!query.get_tracing());
+        assert_eq!(None, query.get_timestamp());
+
+        let query = QueryOptions::default()
+            .consistency(Consistency::Quorum)
+            .serial_consistency(SerialConsistency::LocalSerial)
+            .idempotent(true)
+            .tracing(true)
+            .timestamp(1)
+            .query("select * from t");
+
+        assert_eq!(Consistency::Quorum, query.get_consistency());
+        assert_eq!(
+            Some(SerialConsistency::LocalSerial),
+            query.get_serial_consistency()
+        );
+        assert!(query.get_is_idempotent());
+        assert!(query.get_tracing());
+        assert_eq!(Some(1), query.get_timestamp());
+    }
+
+    #[test]
+    fn reads_are_retried() {
+        let options = QueryOptions::default().retry_policy(ExponentialBackoff::default());
+        let attempts = &AtomicU32::new(0);
+        let timeout_once = move || async move {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(Error::from(QueryError::TimeoutError)),
+                _ => Ok(()),
+            }
+        };
+
+        block_on(async {
+            // A select is retried without marking it as idempotent
+            options.retry(true, timeout_once).await.unwrap();
+
+            assert_eq!(2, attempts.swap(0, Ordering::SeqCst));
+
+            // A write is not
+            assert!(options
+                .retry(false, timeout_once)
+                .await
+                .unwrap_err()
+                .is_timeout());
+            assert_eq!(1, attempts.load(Ordering::SeqCst));
+        });
+    }
 }
@@ -0,0 +1,262 @@
+//! Retries of requests that failed with a transient error (see Error::is_retryable)
+//! A policy is set per statement with QueryOptions::retry_policy, e.g.:
+//! pk.select_unique_qv()?.with_options(QueryOptions::default().retry_policy(ExponentialBackoff::default()))
+//! Selects are always idempotent, other statements must be marked with QueryOptions::idempotent
+use crate::Error;
+use std::collections::hash_map::RandomState;
+use std::fmt::Debug;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Decides if a failed request is executed again
+pub trait RetryPolicy: Debug + Send + Sync {
+    /// Returns the delay before the next attempt, or None to return the error
+    /// The attempt is the number of the attempt that failed, starting at 1
+    fn retry_delay(&self, error: &Error, attempt: u32, idempotent: bool) -> Option<Duration>;
+}
+
+/// Never retries a request, this is the behavior when no policy is set
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NoRetry;
+
+impl RetryPolicy for NoRetry {
+    fn retry_delay(&self, _: &Error, _: u32, _: bool) -> Option<Duration> {
+        None
+    }
+}
+
+/// Retries retryable errors, the delay doubles after every attempt until the max delay is reached
+/// With jitter a random delay between zero and the calculated delay is used, so clients that failed
+/// at the same time don't retry at the same time
+/// Non-idempotent statements are not retried by default, a write that timed out may have been applied
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialBackoff {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        ExponentialBackoff {
+            max_retries: 3,
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(2),
+            jitter: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl ExponentialBackoff {
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// The delay after the given attempt, without jitter
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32 << attempt.saturating_sub(1).min(31);
+
+        self.base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn retry_delay(&self, error: &Error, attempt: u32, idempotent: bool) -> Option<Duration> {
+        if attempt > self.max_retries
+            || !error.is_retryable()
+            || !(idempotent || self.retry_non_idempotent)
+        {
+            return None;
+        }
+
+        let delay = self.delay(attempt);
+
+        if self.jitter {
+            Some(delay.mul_f64(random_fraction()))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+/// Executes the request until it succeeds or the policy doesn't retry the error
+/// The request is a function, because every attempt needs a new future
+pub async fn retry<T, F: Future<Output = Result<T, Error>>>(
+    policy: Option<&dyn RetryPolicy>,
+    idempotent: bool,
+    mut request: impl FnMut() -> F,
+) -> Result<T, Error> {
+    let mut attempt = 0;
+
+    loop {
+        attempt += 1;
+
+        let error = match request().await {
+            Ok(ok) => return Ok(ok),
+            Err(err) => err,
+        };
+
+        match policy.and_then(|p| p.retry_delay(&error, attempt, idempotent)) {
+            Some(delay) => {
+                tracing::debug!(
+                    "Retrying attempt {} in {:?} after: {}",
+                    attempt,
+                    delay,
+                    error
+                );
+
+                tokio::time::sleep(delay).await;
+            }
+            None => return Err(error),
+        }
+    }
+}
+
+/// A random number in [0, 1), the hasher of RandomState is randomly seeded
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::query_transform::UniqueQueryRowTransformError;
+    use crate::runtime::block_on;
+    use scylla::transport::errors::QueryError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Fails with a timeout until 'timeouts' attempts are made
+    struct FakeExecutor {
+        timeouts: u32,
+        attempts: AtomicU32,
+    }
+
+    impl FakeExecutor {
+        fn new(timeouts: u32) -> FakeExecutor {
+            FakeExecutor {
+                timeouts,
+                attempts: AtomicU32::new(0),
+            }
+        }
+
+        async fn execute(&self) -> Result<u32, Error> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+
+            if attempt <= self.timeouts {
+                Err(QueryError::TimeoutError.into())
+            } else {
+                Ok(attempt)
+            }
+        }
+
+        fn attempts(&self) -> u32 {
+            self.attempts.load(Ordering::SeqCst)
+        }
+    }
+
+    fn policy() -> ExponentialBackoff {
+        ExponentialBackoff::default().base_delay(Duration::from_millis(1))
+    }
+
+    #[test]
+    fn backoff() {
+        let policy = ExponentialBackoff::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300))
+            .max_retries(10)
+            .jitter(false);
+        let timeout = Error::from(QueryError::TimeoutError);
+
+        assert_eq!(
+            vec![100, 200, 300, 300],
+            (1..=4)
+                .map(|a| policy.retry_delay(&timeout, a, true).unwrap().as_millis())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Duration::from_millis(300), policy.delay(u32::MAX));
+
+        for attempt in 1..=10 {
+            let delay = policy
+                .jitter(true)
+                .retry_delay(&timeout, attempt, true)
+                .unwrap();
+
+            assert!(delay <= policy.delay(attempt));
+        }
+
+        assert_eq!(None, policy.retry_delay(&timeout, 11, true));
+        assert_eq!(None, policy.retry_delay(&timeout, 1, false));
+        assert!(policy
+            .retry_non_idempotent(true)
+            .retry_delay(&timeout, 1, false)
+            .is_some());
+        assert_eq!(
+            None,
+            policy.retry_delay(&UniqueQueryRowTransformError::NoRows.into(), 1, true)
+        );
+    }
+
+    #[test]
+    fn retries() {
+        let policy = policy();
+        let policy = Some(&policy as &dyn RetryPolicy);
+
+        block_on(async {
+            // Recovers from the injected timeouts
+            let executor = FakeExecutor::new(2);
+
+            assert_eq!(3, retry(policy, true, || executor.execute()).await.unwrap());
+
+            // Gives up after the max retries
+            let executor = FakeExecutor::new(10);
+
+            assert!(retry(policy, true, || executor.execute())
+                .await
+                .unwrap_err()
+                .is_timeout());
+            assert_eq!(4, executor.attempts());
+
+            // Non-idempotent statements are not retried by default
+            let executor = FakeExecutor::new(1);
+
+            assert!(retry(policy, false, || executor.execute()).await.is_err());
+            assert_eq!(1, executor.attempts());
+
+            // Without a policy nothing is retried
+            let executor = FakeExecutor::new(1);
+
+            assert!(retry(None, true, || executor.execute()).await.is_err());
+            assert_eq!(1, executor.attempts());
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use crate::env_property_reader::{keyspace, offline_schema_snapshot};
+use crate::materialized_view::{query_materialized_views, MaterializedViewFromDb};
+use crate::query_metadata::query_columns;
+use crate::runtime::query_collect_to_vec;
+use crate::secondary_index::{query_secondary_indexes, SecondaryIndex};
+use crate::table_metadata::{ColumnInTable, TableName};
+use crate::user_defined_type::{query_user_defined_types, UserDefinedType};
+use once_cell::sync::Lazy;
+
+mod cql_file;
+mod snapshot;
+
+pub use cql_file::CqlFileSchema;
+pub use snapshot::{SchemaSnapshot, TableSnapshot};
+
+/// Only read once, since the snapshot is needed for every query that is checked at compile time
+static OFFLINE_SCHEMA: Lazy<Option<SchemaSnapshot>> =
+    Lazy::new(|| offline_schema_snapshot().map(SchemaSnapshot::from_file));
+
+/// The source of the table, column and materialized view metadata
+/// Everything that maps tables to Rust structs only talks to this trait, so the metadata can
+/// either come from a running database or from a local file
+pub trait SchemaProvider {
+    /// All the tables in the keyspace, materialized views excluded
+    fn table_names(&self) -> Vec<TableName>;
+
+    /// The columns of a table or materialized view, sorted like `sort_columns` sorts them
+    fn columns(&self, table: &str) -> Vec<ColumnInTable>;
+
+    /// All the materialized views in the keyspace
+    fn materialized_views(&self) -> Vec<MaterializedViewFromDb>;
+
+    /// All the user defined types in the keyspace
+    fn user_defined_types(&self) -> Vec<UserDefinedType>;
+
+    /// All the secondary indexes in the keyspace
+    fn secondary_indexes(&self) -> Vec<SecondaryIndex>;
+
+    /// Describes where the schema is read from, used in error messages
+    fn location(&self) -> String {
+        "the schema".to_string()
+    }
+}
+
+/// The schema to validate queries against
+/// This is the schema snapshot if env property 'OFFLINE_SCHEMA_SNAPSHOT' is set, else the database
+pub fn schema_from_env() -> &'static dyn SchemaProvider {
+    match &*OFFLINE_SCHEMA {
+        Some(snapshot) => snapshot,
+        None => &DatabaseSchema,
+    }
+}
+
+/// Only true if queries are validated against a schema snapshot instead of the database
+pub fn is_offline() -> bool {
+    OFFLINE_SCHEMA.is_some()
+}
+
+/// Reads the schema from the database found at the 'SCYLLA_URI' env property
+pub struct DatabaseSchema;
+
+impl SchemaProvider for DatabaseSchema {
+    fn table_names(&self) -> Vec<TableName> {
+        let query = format!(
+            "select table_name from system_schema.tables where keyspace_name = '{}'",
+            keyspace()
+        );
+
+        query_collect_to_vec(query, &[])
+    }
+
+    fn columns(&self, table: &str) -> Vec<ColumnInTable> {
+        query_columns(table)
+    }
+
+    fn materialized_views(&self) -> Vec<MaterializedViewFromDb> {
+        query_materialized_views()
+    }
+
+    fn user_defined_types(&self) -> Vec<UserDefinedType> {
+        query_user_defined_types()
+    }
+
+    fn secondary_indexes(&self) -> Vec<SecondaryIndex> {
+        query_secondary_indexes()
+    }
+
+    fn location(&self) -> String {
+        format!("keyspace '{}'", keyspace())
+    }
+}
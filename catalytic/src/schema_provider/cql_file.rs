@@ -0,0 +1,862 @@
+use crate::materialized_view::MaterializedViewFromDb;
+use crate::schema_provider::SchemaProvider;
+use crate::secondary_index::SecondaryIndex;
+use crate::sort::sort_columns;
+use crate::table_metadata::{ColumnInTable, ColumnKind, TableName};
+use crate::user_defined_type::UserDefinedType;
+use std::path::Path;
+
+/// Reads the schema from 'create table', 'create materialized view', 'create type' and
+/// 'create index' statements, like a schema.cql file that is checked in next to the build.rs file
+/// All other statements (keyspaces, functions, etc) are ignored. Keyspace prefixes are
+/// ignored as well, so the file should describe a single keyspace
+#[derive(Debug, Clone, PartialEq)]
+pub struct CqlFileSchema {
+    tables: Vec<CqlTable>,
+    materialized_views: Vec<CqlMaterializedView>,
+    user_defined_types: Vec<UserDefinedType>,
+    secondary_indexes: Vec<SecondaryIndex>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CqlTable {
+    table_name: String,
+    /// Sorted the same way as the database columns are sorted
+    columns: Vec<ColumnInTable>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CqlMaterializedView {
+    table: CqlTable,
+    base_table_name: String,
+}
+
+impl CqlFileSchema {
+    /// Reads and parses a file with CQL statements
+    pub fn from_file(path: impl AsRef<Path>) -> CqlFileSchema {
+        let path = path.as_ref();
+        let cql = std::fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Failed to read schema file {:?}: {}", path, e));
+
+        CqlFileSchema::from_cql(&cql)
+    }
+
+    /// Parses CQL statements, separated by ';'
+    pub fn from_cql(cql: &str) -> CqlFileSchema {
+        let mut tables = vec![];
+        let mut views = vec![];
+        let mut user_defined_types = vec![];
+        let mut secondary_indexes = vec![];
+
+        for statement in tokenize(cql).split(|t| t == &Token::Symbol(';')) {
+            let mut parser = Parser {
+                tokens: statement,
+                index: 0,
+            };
+
+            match parser.statement() {
+                Some(Statement::Table(table)) => tables.push(table),
+                Some(Statement::MaterializedView(view)) => views.push(view),
+                Some(Statement::UserDefinedType(udt)) => user_defined_types.push(udt),
+                Some(Statement::SecondaryIndex(index)) => secondary_indexes.push(index),
+                None => {} // Not relevant for the mapping
+            }
+        }
+
+        let mut materialized_views = views
+            .into_iter()
+            .map(|v| v.resolve(&tables))
+            .collect::<Vec<_>>();
+
+        // The database returns the tables ordered by name
+        tables.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+        materialized_views.sort_by(|a, b| a.table.table_name.cmp(&b.table.table_name));
+        user_defined_types.sort_by(|a, b| a.type_name.cmp(&b.type_name));
+        secondary_indexes.sort_by(|a, b| a.index_name.cmp(&b.index_name));
+
+        CqlFileSchema {
+            tables,
+            materialized_views,
+            user_defined_types,
+            secondary_indexes,
+        }
+    }
+}
+
+impl SchemaProvider for CqlFileSchema {
+    fn table_names(&self) -> Vec<TableName> {
+        self.tables
+            .iter()
+            .map(|t| TableName {
+                table_name: t.table_name.clone(),
+            })
+            .collect()
+    }
+
+    fn columns(&self, table: &str) -> Vec<ColumnInTable> {
+        let table = table.to_lowercase();
+
+        self.tables
+            .iter()
+            .chain(self.materialized_views.iter().map(|v| &v.table))
+            .find(|t| t.table_name == table)
+            .map(|t| t.columns.clone())
+            .unwrap_or_default()
+    }
+
+    fn materialized_views(&self) -> Vec<MaterializedViewFromDb> {
+        self.materialized_views
+            .iter()
+            .map(|v| MaterializedViewFromDb {
+                table_name: v.table.table_name.clone(),
+                base_table_name: v.base_table_name.clone(),
+            })
+            .collect()
+    }
+
+    fn user_defined_types(&self) -> Vec<UserDefinedType> {
+        self.user_defined_types.clone()
+    }
+
+    fn secondary_indexes(&self) -> Vec<SecondaryIndex> {
+        self.secondary_indexes.clone()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Unquoted identifiers, keywords and numbers, lowercased just like the database does
+    Word(String),
+    /// An identifier between double quotes, the case is preserved
+    Quoted(String),
+    /// A string literal between single quotes, the content is never relevant
+    Literal,
+    Symbol(char),
+}
+
+fn tokenize(cql: &str) -> Vec<Token> {
+    let chars = cql.chars().collect::<Vec<_>>();
+    let starts_with = |index: usize, pattern: &str| {
+        pattern
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| chars.get(index + offset) == Some(&c))
+    };
+    let mut tokens = vec![];
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+
+        if c.is_whitespace() {
+            index += 1;
+        } else if starts_with(index, "--") || starts_with(index, "//") {
+            while index < chars.len() && chars[index] != '\n' {
+                index += 1;
+            }
+        } else if starts_with(index, "/*") {
+            index += 2;
+
+            while index < chars.len() && !starts_with(index, "*/") {
+                index += 1;
+            }
+
+            index += 2;
+        } else if c == '"' || c == '\'' {
+            let (content, end) = quoted(&chars, index);
+
+            tokens.push(if c == '"' {
+                Token::Quoted(content)
+            } else {
+                Token::Literal
+            });
+
+            index = end;
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = index;
+
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                index += 1;
+            }
+
+            let word = chars[start..index].iter().collect::<String>();
+
+            tokens.push(Token::Word(word.to_lowercase()));
+        } else {
+            tokens.push(Token::Symbol(c));
+
+            index += 1;
+        }
+    }
+
+    tokens
+}
+
+/// Reads the quoted value that starts at the given index
+/// Returns the unescaped content and the index after the closing quote
+fn quoted(chars: &[char], start: usize) -> (String, usize) {
+    let quote = chars[start];
+    let mut content = String::new();
+    let mut index = start + 1;
+
+    loop {
+        match chars.get(index) {
+            None => panic!("Missing closing {} in schema", quote),
+            Some(c) if *c == quote => {
+                // A doubled quote is an escaped quote
+                if chars.get(index + 1) == Some(&quote) {
+                    content.push(quote);
+                    index += 2;
+                } else {
+                    return (content, index + 1);
+                }
+            }
+            Some(c) => {
+                content.push(*c);
+                index += 1;
+            }
+        }
+    }
+}
+
+enum Statement {
+    Table(CqlTable),
+    MaterializedView(UnresolvedMaterializedView),
+    UserDefinedType(UserDefinedType),
+    SecondaryIndex(SecondaryIndex),
+}
+
+/// A materialized view of which the column types are not yet known, since these are
+/// defined by the base table
+struct UnresolvedMaterializedView {
+    table_name: String,
+    base_table_name: String,
+    /// None when all columns are selected
+    selection: Option<Vec<String>>,
+    partition_key: Vec<String>,
+    clustering: Vec<String>,
+}
+
+impl UnresolvedMaterializedView {
+    fn resolve(self, tables: &[CqlTable]) -> CqlMaterializedView {
+        let base_table = tables
+            .iter()
+            .find(|t| t.table_name == self.base_table_name)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Base table '{}' of materialized view '{}' is not defined",
+                    self.base_table_name, self.table_name
+                )
+            });
+        let mut column_names = match self.selection {
+            None => base_table
+                .columns
+                .iter()
+                .map(|c| c.column_name.clone())
+                .collect(),
+            Some(selection) => selection,
+        };
+
+        // The primary key columns are always part of the materialized view
+        for pk in self.partition_key.iter().chain(self.clustering.iter()) {
+            if !column_names.contains(pk) {
+                column_names.push(pk.clone());
+            }
+        }
+
+        let columns = column_names
+            .into_iter()
+            .map(|column_name| {
+                let data_type = base_table
+                    .columns
+                    .iter()
+                    .find(|c| c.column_name == column_name)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Column '{}' of materialized view '{}' is not defined in base table '{}'",
+                            column_name, self.table_name, self.base_table_name
+                        )
+                    })
+                    .data_type
+                    .clone();
+
+                (column_name, data_type)
+            })
+            .collect();
+
+        CqlMaterializedView {
+            table: CqlTable {
+                columns: create_columns(
+                    &self.table_name,
+                    columns,
+                    &self.partition_key,
+                    &self.clustering,
+                ),
+                table_name: self.table_name,
+            },
+            base_table_name: self.base_table_name,
+        }
+    }
+}
+
+/// Creates the columns like they would have been queried from the database
+fn create_columns(
+    table_name: &str,
+    mut columns: Vec<(String, String)>,
+    partition_key: &[String],
+    clustering: &[String],
+) -> Vec<ColumnInTable> {
+    assert!(
+        !partition_key.is_empty(),
+        "Table '{}' has no primary key",
+        table_name
+    );
+
+    for pk in partition_key.iter().chain(clustering.iter()) {
+        assert!(
+            columns.iter().any(|(c, _)| c == pk),
+            "Primary key column '{}' is not defined in table '{}'",
+            pk,
+            table_name
+        );
+    }
+
+    // The database returns the columns ordered by name, sorting the columns keeps that order
+    // for the regular columns
+    columns.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut columns = columns
+        .into_iter()
+        .map(|(column_name, data_type)| {
+            let position = |keys: &[String]| keys.iter().position(|k| k == &column_name);
+            let (kind, position) = if let Some(p) = position(partition_key) {
+                (ColumnKind::PartitionKey, p as i32)
+            } else if let Some(p) = position(clustering) {
+                (ColumnKind::Clustering, p as i32)
+            } else {
+                (ColumnKind::Regular, -1)
+            };
+
+            ColumnInTable {
+                column_name,
+                kind: kind.to_string(),
+                position,
+                data_type,
+            }
+        })
+        .collect();
+
+    sort_columns(&mut columns);
+
+    columns
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    index: usize,
+}
+
+impl Parser<'_> {
+    fn statement(&mut self) -> Option<Statement> {
+        if !self.eat_word("create") {
+            return None;
+        }
+
+        if self.eat_word("table") || self.eat_word("columnfamily") {
+            Some(Statement::Table(self.table()))
+        } else if self.eat_word("materialized") {
+            self.expect_word("view");
+
+            Some(Statement::MaterializedView(self.materialized_view()))
+        } else if self.eat_word("type") {
+            Some(Statement::UserDefinedType(self.user_defined_type()))
+        } else if self.eat_word("index") {
+            Some(Statement::SecondaryIndex(self.secondary_index()))
+        } else if self.eat_word("custom") {
+            self.expect_word("index");
+
+            Some(Statement::SecondaryIndex(self.secondary_index()))
+        } else {
+            None
+        }
+    }
+
+    fn table(&mut self) -> CqlTable {
+        self.if_not_exists();
+
+        let table_name = self.qualified_name();
+        let mut columns = vec![];
+        let mut partition_key = vec![];
+        let mut clustering = vec![];
+
+        self.expect_symbol('(');
+
+        loop {
+            if self.eat_word("primary") {
+                self.expect_word("key");
+
+                let (p, c) = self.primary_key();
+
+                partition_key = p;
+                clustering = c;
+            } else {
+                let column_name = self.identifier();
+                let data_type = self.data_type(false);
+
+                assert!(
+                    !self.eat_word("static"),
+                    "Static column '{}' in table '{}' is not supported",
+                    column_name,
+                    table_name
+                );
+
+                if self.eat_word("primary") {
+                    self.expect_word("key");
+
+                    partition_key = vec![column_name.clone()];
+                }
+
+                columns.push((column_name, data_type));
+            }
+
+            if !self.eat_symbol(',') {
+                break;
+            }
+        }
+
+        self.expect_symbol(')');
+
+        // Everything after the column definitions (like 'with' options) is irrelevant
+
+        CqlTable {
+            columns: create_columns(&table_name, columns, &partition_key, &clustering),
+            table_name,
+        }
+    }
+
+    fn materialized_view(&mut self) -> UnresolvedMaterializedView {
+        self.if_not_exists();
+
+        let table_name = self.qualified_name();
+
+        self.expect_word("as");
+        self.expect_word("select");
+
+        let selection = if self.eat_symbol('*') {
+            None
+        } else {
+            let mut selection = vec![self.identifier()];
+
+            while self.eat_symbol(',') {
+                selection.push(self.identifier());
+            }
+
+            Some(selection)
+        };
+
+        self.expect_word("from");
+
+        let base_table_name = self.qualified_name();
+
+        // Skip the where clause, it only contains 'is not null' restrictions
+        while !(self.eat_word("primary") && self.eat_word("key")) {
+            self.next();
+        }
+
+        let (partition_key, clustering) = self.primary_key();
+
+        UnresolvedMaterializedView {
+            table_name,
+            base_table_name,
+            selection,
+            partition_key,
+            clustering,
+        }
+    }
+
+    fn user_defined_type(&mut self) -> UserDefinedType {
+        self.if_not_exists();
+
+        let type_name = self.qualified_name();
+        let mut field_names = vec![];
+        let mut field_types = vec![];
+
+        self.expect_symbol('(');
+
+        loop {
+            field_names.push(self.identifier());
+            field_types.push(self.data_type(false));
+
+            if !self.eat_symbol(',') {
+                break;
+            }
+        }
+
+        self.expect_symbol(')');
+
+        UserDefinedType {
+            type_name,
+            field_names,
+            field_types,
+        }
+    }
+
+    /// Parses '[name] on table (target)', the target is formatted like the database stores it
+    fn secondary_index(&mut self) -> SecondaryIndex {
+        self.if_not_exists();
+
+        let index_name = if self.eat_word("on") {
+            None
+        } else {
+            let index_name = self.identifier();
+
+            self.expect_word("on");
+
+            Some(index_name)
+        };
+        let table_name = self.qualified_name();
+
+        self.expect_symbol('(');
+
+        let mut target = String::new();
+        let mut depth = 0;
+
+        // Collection indexes like 'keys(m)' and local indexes like '((a), b)' are kept as written
+        while depth > 0 || !self.eat_symbol(')') {
+            match self.next() {
+                Token::Word(w) => target.push_str(w),
+                Token::Quoted(q) if q.to_lowercase() == *q => target.push_str(q),
+                Token::Quoted(q) => target.push_str(&format!("\"{}\"", q)),
+                Token::Literal => target.push_str("''"),
+                Token::Symbol(c) => {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+
+                    target.push(*c);
+                }
+            }
+        }
+
+        // Everything after the target (like 'using' options) is irrelevant
+
+        // The database names an index without a name after the table and the (last) column
+        let index_name = index_name.unwrap_or_else(|| {
+            let column = target
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .filter(|s| !s.is_empty())
+                .last()
+                .unwrap_or_default();
+
+            format!("{}_{}_idx", table_name, column)
+        });
+
+        SecondaryIndex {
+            index_name,
+            table_name,
+            target,
+        }
+    }
+
+    /// Parses '((a, b), c, d)' or '(a, c, d)', without the 'primary key' keywords
+    /// Returns the partition key columns and the clustering columns
+    fn primary_key(&mut self) -> (Vec<String>, Vec<String>) {
+        self.expect_symbol('(');
+
+        let partition_key = if self.eat_symbol('(') {
+            let mut partition_key = vec![self.identifier()];
+
+            while self.eat_symbol(',') {
+                partition_key.push(self.identifier());
+            }
+
+            self.expect_symbol(')');
+
+            partition_key
+        } else {
+            vec![self.identifier()]
+        };
+        let mut clustering = vec![];
+
+        while self.eat_symbol(',') {
+            clustering.push(self.identifier());
+        }
+
+        self.expect_symbol(')');
+
+        (partition_key, clustering)
+    }
+
+    /// Parses a data type and formats it the same way as the database formats it
+    /// Tuples are always frozen, which the database adds to the type name
+    fn data_type(&mut self, parent_is_frozen: bool) -> String {
+        let name = match self.qualified_name().as_str() {
+            // Alias
+            "varchar" => "text".to_string(),
+            n => n.to_string(),
+        };
+
+        if !self.eat_symbol('<') {
+            return name;
+        }
+
+        let is_frozen = name == "frozen";
+        let mut arguments = vec![self.data_type(is_frozen)];
+
+        while self.eat_symbol(',') {
+            arguments.push(self.data_type(is_frozen));
+        }
+
+        self.expect_symbol('>');
+
+        let data_type = format!("{}<{}>", name, arguments.join(", "));
+
+        if name == "tuple" && !parent_is_frozen {
+            format!("frozen<{}>", data_type)
+        } else {
+            data_type
+        }
+    }
+
+    fn if_not_exists(&mut self) {
+        if self.eat_word("if") {
+            self.expect_word("not");
+            self.expect_word("exists");
+        }
+    }
+
+    /// Parses 'keyspace.name' or 'name' and returns the name
+    fn qualified_name(&mut self) -> String {
+        let mut name = self.identifier();
+
+        while self.eat_symbol('.') {
+            name = self.identifier();
+        }
+
+        name
+    }
+
+    fn identifier(&mut self) -> String {
+        match self.next() {
+            Token::Word(w) | Token::Quoted(w) => w.clone(),
+            t => panic!("Expected an identifier in schema, but found {:?}", t),
+        }
+    }
+
+    fn next(&mut self) -> &Token {
+        let token = self
+            .tokens
+            .get(self.index)
+            .unwrap_or_else(|| panic!("Unexpected end of statement in schema"));
+
+        self.index += 1;
+
+        token
+    }
+
+    fn eat(&mut self, token: Token) -> bool {
+        if self.tokens.get(self.index) == Some(&token) {
+            self.index += 1;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        self.eat(Token::Word(word.to_string()))
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        self.eat(Token::Symbol(symbol))
+    }
+
+    fn expect_word(&mut self, word: &str) {
+        assert!(
+            self.eat_word(word),
+            "Expected '{}' in schema, but found {:?}",
+            word,
+            self.tokens.get(self.index)
+        );
+    }
+
+    fn expect_symbol(&mut self, symbol: char) {
+        assert!(
+            self.eat_symbol(symbol),
+            "Expected '{}' in schema, but found {:?}",
+            symbol,
+            self.tokens.get(self.index)
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::query_metadata::query_columns;
+    use crate::runtime::{create_test_tables, ANOTHER_TEST_TABLE, TEST_TABLE};
+
+    const SCHEMA: &str = "
+        -- The same tables as the example project
+        create table if not exists person(name text, age int, email text, primary key((name), age));
+        CREATE MATERIALIZED VIEW IF NOT EXISTS person_by_email AS
+            SELECT *
+            FROM person
+            WHERE name IS NOT NULL AND age IS NOT NULL AND email IS NOT NULL
+            PRIMARY KEY ((email), name, age);
+        create table test_keyspace.Child(
+            birthday int primary key,
+            /* Mapped to json */
+            json varchar,
+            \"Quoted\" map<text,frozen<list<int>>>,
+            pair tuple<int, text>
+        ) with comment = 'it''s a child';
+        create keyspace ignored with replication = { 'class': 'SimpleStrategy', 'replication_factor': 1 };
+    ";
+
+    fn column(name: &str, kind: ColumnKind, position: i32, data_type: &str) -> ColumnInTable {
+        ColumnInTable {
+            column_name: name.to_string(),
+            kind: kind.to_string(),
+            position,
+            data_type: data_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_schema() {
+        let schema = CqlFileSchema::from_cql(SCHEMA);
+        let table_names = schema
+            .table_names()
+            .into_iter()
+            .map(|t| t.table_name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["child", "person"], table_names);
+        assert_eq!(
+            vec![
+                column("birthday", ColumnKind::PartitionKey, 0, "int"),
+                column(
+                    "Quoted",
+                    ColumnKind::Regular,
+                    1_000_000,
+                    "map<text, frozen<list<int>>>"
+                ),
+                column("json", ColumnKind::Regular, 1_000_000, "text"),
+                column(
+                    "pair",
+                    ColumnKind::Regular,
+                    1_000_000,
+                    "frozen<tuple<int, text>>"
+                ),
+            ],
+            schema.columns("child")
+        );
+        assert_eq!(
+            vec![
+                column("email", ColumnKind::PartitionKey, 0, "text"),
+                column("name", ColumnKind::Clustering, 1_000, "text"),
+                column("age", ColumnKind::Clustering, 1_001, "int"),
+            ],
+            schema.columns("person_by_email")
+        );
+
+        let views = schema.materialized_views();
+
+        assert_eq!(1, views.len());
+        assert_eq!("person_by_email", views[0].table_name);
+        assert_eq!("person", views[0].base_table_name);
+        assert!(schema.columns("idontexist").is_empty());
+    }
+
+    #[test]
+    fn parse_user_defined_types() {
+        let schema = CqlFileSchema::from_cql(
+            "create type if not exists test_keyspace.phone(number varchar);
+            create type address(street text, \"Number\" int, phones list<frozen<phone>>);
+            create table person(name text primary key, address frozen<address>);",
+        );
+
+        assert_eq!(
+            vec![
+                UserDefinedType {
+                    type_name: "address".to_string(),
+                    field_names: vec![
+                        "street".to_string(),
+                        "Number".to_string(),
+                        "phones".to_string()
+                    ],
+                    field_types: vec![
+                        "text".to_string(),
+                        "int".to_string(),
+                        "list<frozen<phone>>".to_string()
+                    ],
+                },
+                UserDefinedType {
+                    type_name: "phone".to_string(),
+                    field_names: vec!["number".to_string()],
+                    field_types: vec!["text".to_string()],
+                },
+            ],
+            schema.user_defined_types()
+        );
+        assert_eq!(
+            vec![
+                column("name", ColumnKind::PartitionKey, 0, "text"),
+                column("address", ColumnKind::Regular, 1_000_000, "frozen<address>"),
+            ],
+            schema.columns("person")
+        );
+    }
+
+    #[test]
+    fn parse_secondary_indexes() {
+        let schema = CqlFileSchema::from_cql(
+            "create table person(name text primary key, email text, \"Age\" int, tags set<text>);
+            create index on person(email);
+            create index if not exists by_age on ks.person (\"Age\");
+            create custom index on person(keys(tags)) using 'StorageAttachedIndex';",
+        );
+        let index = |index_name: &str, target: &str| SecondaryIndex {
+            index_name: index_name.to_string(),
+            table_name: "person".to_string(),
+            target: target.to_string(),
+        };
+
+        assert_eq!(
+            vec![
+                index("by_age", "\"Age\""),
+                index("person_email_idx", "email"),
+                index("person_tags_idx", "keys(tags)"),
+            ],
+            schema.secondary_indexes()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_base_table() {
+        CqlFileSchema::from_cql(
+            "create materialized view v as select * from t where a is not null primary key (a)",
+        );
+    }
+
+    #[test]
+    fn same_as_database() {
+        create_test_tables();
+
+        // The same statements as the ones in create_test_tables
+        let schema = CqlFileSchema::from_cql(&format!(
+            "create table if not exists {} (a int, b int, c int, d int, e int, primary key((b, c), d, a));
+            create table if not exists {}  (a int, b text, c text, d int, primary key((a), b, c))",
+            TEST_TABLE, ANOTHER_TEST_TABLE
+        ));
+
+        for table in [TEST_TABLE, ANOTHER_TEST_TABLE] {
+            assert_eq!(query_columns(table), schema.columns(table));
+        }
+    }
+}
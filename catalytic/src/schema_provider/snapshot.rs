@@ -0,0 +1,148 @@
+use crate::materialized_view::MaterializedViewFromDb;
+use crate::schema_provider::SchemaProvider;
+use crate::secondary_index::SecondaryIndex;
+use crate::table_metadata::{ColumnInTable, TableName};
+use crate::user_defined_type::UserDefinedType;
+use std::path::Path;
+
+/// A serialized copy of a schema, which can be checked in so queries can be validated without a
+/// database, see env property 'OFFLINE_SCHEMA_SNAPSHOT'
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SchemaSnapshot {
+    /// The tables and materialized views with their sorted columns
+    pub tables: Vec<TableSnapshot>,
+    pub materialized_views: Vec<MaterializedViewFromDb>,
+    /// Missing in snapshots which are written before user defined types were supported
+    #[serde(default)]
+    pub user_defined_types: Vec<UserDefinedType>,
+    /// Missing in snapshots which are written before secondary indexes were supported
+    #[serde(default)]
+    pub secondary_indexes: Vec<SecondaryIndex>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TableSnapshot {
+    pub table_name: String,
+    pub columns: Vec<ColumnInTable>,
+}
+
+impl SchemaSnapshot {
+    /// Takes a snapshot of the given schema, most likely `DatabaseSchema`
+    pub fn from_schema(schema: &dyn SchemaProvider) -> SchemaSnapshot {
+        let materialized_views = schema.materialized_views();
+        let tables = schema
+            .table_names()
+            .into_iter()
+            .map(|t| t.table_name)
+            .chain(materialized_views.iter().map(|mv| mv.table_name.clone()))
+            .map(|table_name| TableSnapshot {
+                columns: schema.columns(&table_name),
+                table_name,
+            })
+            .collect();
+
+        SchemaSnapshot {
+            tables,
+            materialized_views,
+            user_defined_types: schema.user_defined_types(),
+            secondary_indexes: schema.secondary_indexes(),
+        }
+    }
+
+    /// Reads a snapshot which is written by `write`
+    pub fn from_file(path: impl AsRef<Path>) -> SchemaSnapshot {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Failed to read schema snapshot {:?}: {}", path, e));
+
+        serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Invalid schema snapshot {:?}: {}", path, e))
+    }
+
+    /// Writes the snapshot as json, the output is stable so it can be checked in
+    pub fn write(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).unwrap();
+
+        std::fs::write(path, json + "\n")
+            .unwrap_or_else(|e| panic!("Failed to write schema snapshot {:?}: {}", path, e));
+    }
+}
+
+impl SchemaProvider for SchemaSnapshot {
+    fn table_names(&self) -> Vec<TableName> {
+        self.tables
+            .iter()
+            .filter(|t| {
+                !self
+                    .materialized_views
+                    .iter()
+                    .any(|mv| mv.table_name == t.table_name)
+            })
+            .map(|t| TableName {
+                table_name: t.table_name.clone(),
+            })
+            .collect()
+    }
+
+    fn columns(&self, table: &str) -> Vec<ColumnInTable> {
+        let table = table.to_lowercase();
+
+        self.tables
+            .iter()
+            .find(|t| t.table_name == table)
+            .map(|t| t.columns.clone())
+            .unwrap_or_default()
+    }
+
+    fn materialized_views(&self) -> Vec<MaterializedViewFromDb> {
+        self.materialized_views.clone()
+    }
+
+    fn user_defined_types(&self) -> Vec<UserDefinedType> {
+        self.user_defined_types.clone()
+    }
+
+    fn secondary_indexes(&self) -> Vec<SecondaryIndex> {
+        self.secondary_indexes.clone()
+    }
+
+    fn location(&self) -> String {
+        "the schema snapshot".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema_provider::CqlFileSchema;
+
+    #[test]
+    fn write_and_read() {
+        let schema = CqlFileSchema::from_cql(
+            "create type address(street text, number int);
+            create table person(name text, age int, email text, primary key((name), age));
+            create materialized view person_by_email as
+                select * from person
+                where name is not null and age is not null and email is not null
+                primary key ((email), name, age);
+            create index on person(email);",
+        );
+        let snapshot = SchemaSnapshot::from_schema(&schema);
+        let path = std::env::temp_dir().join("catalytic_schema_snapshot_test.json");
+
+        snapshot.write(&path);
+
+        let read = SchemaSnapshot::from_file(&path);
+
+        assert_eq!(snapshot, read);
+        assert_eq!(schema.table_names(), read.table_names());
+        assert_eq!(schema.materialized_views(), read.materialized_views());
+        assert_eq!(schema.user_defined_types(), read.user_defined_types());
+        assert_eq!(schema.secondary_indexes(), read.secondary_indexes());
+
+        for table in ["person", "person_by_email"] {
+            assert_eq!(schema.columns(table), read.columns(table));
+        }
+    }
+}
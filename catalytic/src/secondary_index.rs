@@ -0,0 +1,90 @@
+use crate::env_property_reader::keyspace;
+use crate::runtime::query_collect_to_vec;
+use crate::schema_provider::SchemaProvider;
+use std::collections::HashMap;
+
+/// A secondary index on a table
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SecondaryIndex {
+    pub index_name: String,
+    /// The table that is indexed
+    pub table_name: String,
+    /// What is indexed, as stored in the 'target' option of the index, e.g. 'email',
+    /// 'values(tags)' or the primary key of a local index
+    pub target: String,
+}
+
+impl SecondaryIndex {
+    /// The indexed column, if the index is a global index on the value of a column
+    /// Indexes on the keys, values or entries of a collection and local indexes are not supported
+    pub fn column_name(&self) -> Option<&str> {
+        let target = self.target.as_str();
+
+        if target.len() > 1 && target.starts_with('"') && target.ends_with('"') {
+            return Some(&target[1..target.len() - 1]);
+        }
+
+        if !target.is_empty() && target.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            Some(target)
+        } else {
+            None
+        }
+    }
+}
+
+/// The row of system_schema.indexes, the target is one of the options
+#[derive(scylla::FromRow)]
+struct SecondaryIndexFromDb {
+    index_name: String,
+    table_name: String,
+    options: HashMap<String, String>,
+}
+
+/// Queries all the secondary indexes from the database
+pub fn query_secondary_indexes() -> Vec<SecondaryIndex> {
+    let query = format!(
+        "select index_name, table_name, options from system_schema.indexes where keyspace_name = '{}'",
+        keyspace()
+    );
+
+    query_collect_to_vec::<SecondaryIndexFromDb>(query, &[])
+        .into_iter()
+        .map(|i| SecondaryIndex {
+            target: i.options.get("target").cloned().unwrap_or_default(),
+            index_name: i.index_name,
+            table_name: i.table_name,
+        })
+        .collect()
+}
+
+/// The columns of the table that can be restricted with '=' because of a secondary index
+pub fn indexed_columns(schema: &dyn SchemaProvider, table_name: &str) -> Vec<String> {
+    schema
+        .secondary_indexes()
+        .iter()
+        .filter(|i| i.table_name == table_name)
+        .filter_map(|i| i.column_name().map(str::to_string))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn column_name() {
+        let index = |target: &str| SecondaryIndex {
+            index_name: "idx".to_string(),
+            table_name: "t".to_string(),
+            target: target.to_string(),
+        };
+
+        assert_eq!(Some("email"), index("email").column_name());
+        assert_eq!(Some("Email"), index("\"Email\"").column_name());
+        assert_eq!(None, index("values(tags)").column_name());
+        assert_eq!(
+            None,
+            index("{\"pk\":[\"a\"],\"ck\":[\"email\"]}").column_name()
+        );
+    }
+}
@@ -1,3 +1,6 @@
+use crate::cql_type::CQL_TYPE_MODULE;
+use crate::user_defined_type::user_defined_type_path;
+
 /// The type of the column
 #[derive(PartialEq, Debug)]
 pub enum ColumnKind {
@@ -31,7 +34,15 @@ impl ToString for ColumnKind {
 }
 
 /// Meta data about a column for a table
-#[derive(Debug, scylla::ValueList, scylla::FromRow)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    scylla::ValueList,
+    scylla::FromRow,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 pub struct ColumnInTable {
     /// Name of the column
     pub column_name: String,
@@ -56,7 +67,7 @@ impl ColumnInTable {
 }
 
 /// Well this holds the table name, but a struct is needed for the FromRow trait
-#[derive(scylla::FromRow, Debug)]
+#[derive(scylla::FromRow, Debug, Clone, PartialEq)]
 pub struct TableName {
     pub table_name: String,
 }
@@ -78,14 +89,43 @@ pub enum ColumnType {
     Double,
     Uuid,
     Counter,
+    List(Box<ColumnType>),
+    Set(Box<ColumnType>),
+    Map(Box<ColumnType>, Box<ColumnType>),
+    Tuple(Vec<ColumnType>),
+    /// The name of a user defined type, a struct is generated for it
+    UserDefinedType(String),
     Custom(String),
 }
 
 impl ColumnType {
+    /// Parses a type like it is stored in the system_schema tables, e.g. map<text, frozen<list<int>>>
+    /// Frozen types are mapped to the same type as their non-frozen variant
     pub fn new<T: ToString>(from: T) -> Self {
         let s = from.to_string();
+        let s = s.trim();
+
+        if let Some((name, arguments)) = split_type_arguments(s) {
+            let mut arguments = arguments.into_iter().map(ColumnType::new);
+            let mut next = || {
+                Box::new(
+                    arguments
+                        .next()
+                        .unwrap_or_else(|| panic!("Missing type argument for {}", s)),
+                )
+            };
+
+            return match name {
+                "frozen" => *next(),
+                "list" => ColumnType::List(next()),
+                "set" => ColumnType::Set(next()),
+                "map" => ColumnType::Map(next(), next()),
+                "tuple" => ColumnType::Tuple(arguments.collect()),
+                _ => ColumnType::Custom(s.to_string()),
+            };
+        }
 
-        match s.as_str() {
+        match s {
             "tinyint" => ColumnType::TinyInt,
             "smallint" => ColumnType::SmallInt,
             "int" => ColumnType::Int,
@@ -100,11 +140,30 @@ impl ColumnType {
             "double" => ColumnType::Double,
             "uuid" => ColumnType::Uuid,
             "counter" => ColumnType::Counter,
-            _ => ColumnType::Custom(s),
+            // Native types that are not supported yet
+            "blob" | "date" | "decimal" | "duration" | "inet" | "timeuuid" | "varint" => {
+                ColumnType::Custom(s.to_string())
+            }
+            // Custom types are quoted class names, so every other identifier is a user defined type
+            _ if is_identifier(s.trim_matches('"')) => {
+                ColumnType::UserDefinedType(s.trim_matches('"').to_string())
+            }
+            _ => ColumnType::Custom(s.to_string()),
         }
     }
 
     pub fn to_ty(&self) -> String {
+        self.to_ty_nested(false)
+    }
+
+    /// The type when used as element of a set or as key of a map
+    pub fn to_ty_hashable(&self) -> String {
+        self.to_ty_nested(true)
+    }
+
+    /// Values in a set and keys of a map need to be hashable
+    /// If such a value is a collection itself, the ordered variant is used since that one is hashable
+    fn to_ty_nested(&self, hashable: bool) -> String {
         let result = match self {
             ColumnType::TinyInt => "i8",
             ColumnType::SmallInt => "i16",
@@ -118,9 +177,306 @@ impl ColumnType {
             ColumnType::Float => "f32",
             ColumnType::Double => "f64",
             ColumnType::Uuid => "uuid::Uuid",
+            ColumnType::List(t) => {
+                return format!("std::vec::Vec<{}>", t.to_ty_nested(hashable));
+            }
+            // A set of e.g. floats can not be a Rust set, use a vec instead
+            ColumnType::Set(t) if !t.is_hashable() => {
+                return format!("std::vec::Vec<{}>", t.to_ty_nested(hashable));
+            }
+            ColumnType::Set(t) if hashable => {
+                return format!("std::collections::BTreeSet<{}>", t.to_ty_nested(true));
+            }
+            ColumnType::Set(t) => {
+                return format!("std::collections::HashSet<{}>", t.to_ty_nested(true));
+            }
+            // A map with e.g. float keys can not be a Rust map, its entries are kept in a vec instead
+            ColumnType::Map(k, v) if !k.is_hashable() => {
+                return format!(
+                    "catalytic::map_entries::MapEntries<{}, {}>",
+                    k.to_ty_nested(hashable),
+                    v.to_ty_nested(hashable)
+                );
+            }
+            ColumnType::Map(k, v) => {
+                return if hashable {
+                    format!(
+                        "std::collections::BTreeMap<{}, {}>",
+                        k.to_ty_nested(true),
+                        v.to_ty_nested(true)
+                    )
+                } else {
+                    format!(
+                        "std::collections::HashMap<{}, {}>",
+                        k.to_ty_nested(true),
+                        v.to_ty_nested(false)
+                    )
+                };
+            }
+            ColumnType::Tuple(types) => {
+                let types = types
+                    .iter()
+                    .map(|t| t.to_ty_nested(hashable))
+                    .collect::<Vec<_>>();
+
+                // A tuple with a single element needs a trailing comma
+                return if types.len() == 1 {
+                    format!("({},)", types[0])
+                } else {
+                    format!("({})", types.join(", "))
+                };
+            }
+            ColumnType::UserDefinedType(name) => return user_defined_type_path(name),
             ColumnType::Custom(c) => c.as_str(),
         };
 
         result.to_string()
     }
+
+    /// The path to the marker type in the cql_type module, used to check the type of values
+    /// Custom types can not be checked, so they don't have a marker
+    pub fn to_cql_type_marker(&self) -> Option<String> {
+        let marker = match self {
+            ColumnType::TinyInt => "TinyInt",
+            ColumnType::SmallInt => "SmallInt",
+            ColumnType::Int => "Int",
+            ColumnType::BigInt => "BigInt",
+            ColumnType::Text | ColumnType::Ascii | ColumnType::Varchar => "Text",
+            ColumnType::Boolean => "Boolean",
+            ColumnType::Time => "Time",
+            ColumnType::Timestamp => "Timestamp",
+            ColumnType::Float => "Float",
+            ColumnType::Double => "Double",
+            ColumnType::Uuid => "Uuid",
+            ColumnType::Counter => "Counter",
+            ColumnType::List(t) => {
+                return Some(format!(
+                    "{}::List<{}>",
+                    CQL_TYPE_MODULE,
+                    t.to_cql_type_marker()?
+                ));
+            }
+            ColumnType::Set(t) => {
+                return Some(format!(
+                    "{}::Set<{}>",
+                    CQL_TYPE_MODULE,
+                    t.to_cql_type_marker()?
+                ));
+            }
+            ColumnType::Map(k, v) => {
+                return Some(format!(
+                    "{}::Map<{}, {}>",
+                    CQL_TYPE_MODULE,
+                    k.to_cql_type_marker()?,
+                    v.to_cql_type_marker()?
+                ));
+            }
+            ColumnType::Tuple(types) => {
+                let markers = types
+                    .iter()
+                    .map(|t| t.to_cql_type_marker())
+                    .collect::<Option<Vec<_>>>()?;
+
+                // The trailing comma makes a single marker a tuple as well
+                return Some(format!("({},)", markers.join(", ")));
+            }
+            ColumnType::UserDefinedType(name) => {
+                return Some(format!(
+                    "{}::UserDefinedType<{}>",
+                    CQL_TYPE_MODULE,
+                    user_defined_type_path(name)
+                ));
+            }
+            ColumnType::Custom(_) => return None,
+        };
+
+        Some(format!("{}::{}", CQL_TYPE_MODULE, marker))
+    }
+
+    /// Only true if the Rust type implements Hash and Ord
+    pub fn is_hashable(&self) -> bool {
+        match self {
+            ColumnType::TinyInt
+            | ColumnType::SmallInt
+            | ColumnType::Int
+            | ColumnType::BigInt
+            | ColumnType::Text
+            | ColumnType::Ascii
+            | ColumnType::Varchar
+            | ColumnType::Boolean
+            | ColumnType::Uuid => true,
+            ColumnType::List(t) | ColumnType::Set(t) => t.is_hashable(),
+            ColumnType::Map(k, v) => k.is_hashable() && v.is_hashable(),
+            ColumnType::Tuple(types) => types.iter().all(|t| t.is_hashable()),
+            ColumnType::Time
+            | ColumnType::Timestamp
+            | ColumnType::Float
+            | ColumnType::Double
+            | ColumnType::Counter
+            | ColumnType::UserDefinedType(_)
+            | ColumnType::Custom(_) => false,
+        }
+    }
+
+    /// The path to the struct of a user defined type is only valid inside the generated dir
+    pub fn contains_user_defined_type(&self) -> bool {
+        match self {
+            ColumnType::UserDefinedType(_) => true,
+            ColumnType::List(t) | ColumnType::Set(t) => t.contains_user_defined_type(),
+            ColumnType::Map(k, v) => {
+                k.contains_user_defined_type() || v.contains_user_defined_type()
+            }
+            ColumnType::Tuple(types) => types.iter().any(|t| t.contains_user_defined_type()),
+            _ => false,
+        }
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Splits a type like map<text, int> into the name and its type arguments, e.g. ("map", ["text", "int"])
+fn split_type_arguments(s: &str) -> Option<(&str, Vec<&str>)> {
+    let open = s.find('<')?;
+
+    if !s.ends_with('>') {
+        return None;
+    }
+
+    let inner = &s[open + 1..s.len() - 1];
+    let mut arguments = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (index, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                arguments.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    arguments.push(inner[start..].trim());
+
+    Some((s[..open].trim(), arguments))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn collection_types() {
+        let ty = |s: &str| ColumnType::new(s).to_ty();
+
+        assert_eq!("std::vec::Vec<i32>", ty("list<int>"));
+        assert_eq!("std::vec::Vec<i32>", ty("frozen<list<int>>"));
+        assert_eq!("std::collections::HashSet<String>", ty("set<text>"));
+        assert_eq!("std::vec::Vec<f32>", ty("set<float>"));
+        assert_eq!(
+            "std::collections::HashMap<String, std::vec::Vec<f64>>",
+            ty("map<text, frozen<list<double>>>")
+        );
+        assert_eq!(
+            "std::collections::HashSet<std::collections::BTreeSet<i32>>",
+            ty("set<frozen<set<int>>>")
+        );
+        assert_eq!(
+            "std::collections::HashMap<std::collections::BTreeMap<i32, String>, i32>",
+            ty("map<frozen<map<int, text>>, int>")
+        );
+        assert_eq!(
+            "(i32, String, uuid::Uuid)",
+            ty("frozen<tuple<int, text, uuid>>")
+        );
+        assert_eq!("(i32,)", ty("tuple<int>"));
+        assert_eq!(
+            "std::collections::HashMap<String, (i32, std::vec::Vec<String>)>",
+            ty("map<text, frozen<tuple<int, frozen<list<text>>>>>")
+        );
+    }
+
+    #[test]
+    fn parse_collection_types() {
+        assert_eq!(
+            ColumnType::Map(
+                Box::new(ColumnType::Text),
+                Box::new(ColumnType::Tuple(vec![
+                    ColumnType::Int,
+                    ColumnType::List(Box::new(ColumnType::Uuid))
+                ]))
+            ),
+            ColumnType::new("map<text, frozen<tuple<int, frozen<list<uuid>>>>>")
+        );
+        assert_eq!(
+            ColumnType::UserDefinedType("frozen_udt".to_string()),
+            ColumnType::new("frozen_udt")
+        );
+        assert_eq!(
+            ColumnType::Custom("blob".to_string()),
+            ColumnType::new("blob")
+        );
+    }
+
+    #[test]
+    fn user_defined_types() {
+        let ty = |s: &str| ColumnType::new(s).to_ty();
+
+        assert_eq!("super::user_defined_types::Address", ty("frozen<address>"));
+        assert_eq!(
+            "std::collections::HashMap<String, super::user_defined_types::PhoneNumber>",
+            ty("map<text, frozen<phone_number>>")
+        );
+        assert_eq!(
+            "std::vec::Vec<super::user_defined_types::Address>",
+            ty("set<frozen<address>>")
+        );
+        assert!(ColumnType::new("list<frozen<tuple<int, address>>>").contains_user_defined_type());
+        assert!(!ColumnType::new("list<int>").contains_user_defined_type());
+    }
+
+    #[test]
+    fn cql_type_markers() {
+        let marker = |s: &str| ColumnType::new(s).to_cql_type_marker();
+
+        assert_eq!(
+            Some("catalytic::cql_type::Text".to_string()),
+            marker("varchar")
+        );
+        assert_eq!(
+            Some("catalytic::cql_type::Map<catalytic::cql_type::Int, catalytic::cql_type::List<catalytic::cql_type::Text>>".to_string()),
+            marker("map<int, frozen<list<text>>>")
+        );
+        assert_eq!(
+            Some("(catalytic::cql_type::Int, catalytic::cql_type::UserDefinedType<super::user_defined_types::Address>,)".to_string()),
+            marker("tuple<int, frozen<address>>")
+        );
+        assert_eq!(None, marker("list<blob>"));
+    }
+
+    #[test]
+    fn float_map_key() {
+        let ty = |s: &str| ColumnType::new(s).to_ty();
+
+        assert_eq!(
+            "catalytic::map_entries::MapEntries<f32, i32>",
+            ty("map<float, int>")
+        );
+        assert_eq!(
+            "std::vec::Vec<catalytic::map_entries::MapEntries<f64, String>>",
+            ty("set<frozen<map<double, text>>>")
+        );
+        assert_eq!(
+            Some(
+                "catalytic::cql_type::Map<catalytic::cql_type::Float, catalytic::cql_type::Int>"
+                    .to_string()
+            ),
+            ColumnType::new("map<float, int>").to_cql_type_marker()
+        );
+    }
 }
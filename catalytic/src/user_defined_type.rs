@@ -0,0 +1,80 @@
+use crate::capitalizing::table_name_to_struct_name;
+use crate::env_property_reader::keyspace;
+use crate::runtime::query_collect_to_vec;
+use scylla::cql_to_rust::FromCqlValError;
+use scylla::frame::response::result::CqlValue;
+use scylla::frame::value::ValueTooBig;
+use std::convert::TryFrom;
+
+/// The module in the generated dir which holds the structs of the user defined types
+pub const USER_DEFINED_TYPES_MODULE: &str = "user_defined_types";
+
+/// A user defined type (UDT), as queried from the database
+#[derive(scylla::FromRow, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UserDefinedType {
+    pub type_name: String,
+    /// The names of the fields, in the order they are defined
+    pub field_names: Vec<String>,
+    /// The data types of the fields, in the same order as field_names
+    pub field_types: Vec<String>,
+}
+
+impl UserDefinedType {
+    pub fn struct_name(&self) -> String {
+        table_name_to_struct_name(&self.type_name)
+    }
+
+    /// The field names with their data types
+    pub fn fields(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.field_names.iter().zip(self.field_types.iter())
+    }
+}
+
+/// The path of the generated struct of a user defined type, relative to a file in the generated dir
+pub fn user_defined_type_path(type_name: &str) -> String {
+    format!(
+        "super::{}::{}",
+        USER_DEFINED_TYPES_MODULE,
+        table_name_to_struct_name(type_name)
+    )
+}
+
+/// Queries all the user defined types from the database
+pub fn query_user_defined_types() -> Vec<UserDefinedType> {
+    let query = format!(
+        "select type_name, field_names, field_types from system_schema.types where keyspace_name = '{}'",
+        keyspace()
+    );
+
+    query_collect_to_vec(query, &[])
+}
+
+/// Used by the generated structs to read the values of the fields, in the order they are defined
+/// Fields that are added to the type after the value was written are missing
+pub fn user_defined_type_fields(
+    cql_val: CqlValue,
+) -> Result<impl Iterator<Item = Option<CqlValue>>, FromCqlValError> {
+    match cql_val {
+        CqlValue::UserDefinedType { fields, .. } => Ok(fields.into_iter().map(|(_, v)| v)),
+        _ => Err(FromCqlValError::BadCqlType),
+    }
+}
+
+/// Used by the generated structs to serialize the fields, the closure should serialize every field
+/// in the order they are defined
+pub fn serialize_user_defined_type(
+    buf: &mut Vec<u8>,
+    serialize_fields: impl FnOnce(&mut Vec<u8>) -> Result<(), ValueTooBig>,
+) -> Result<(), ValueTooBig> {
+    let start = buf.len();
+
+    // Placeholder for the length
+    buf.extend_from_slice(&[0; 4]);
+    serialize_fields(buf)?;
+
+    let len = i32::try_from(buf.len() - start - 4).map_err(|_| ValueTooBig)?;
+
+    buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+
+    Ok(())
+}
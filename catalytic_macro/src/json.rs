@@ -6,6 +6,9 @@ pub(crate) fn json(derive_input: DeriveInput) -> TokenStream {
     let name = derive_input.ident;
 
     quote! {
+        // The value is stored in a text column
+        impl catalytic::cql_type::CqlTypeOf<catalytic::cql_type::Text> for #name {}
+
         impl scylla::frame::value::Value for #name {
             fn serialize(&self, buf: &mut Vec<u8>) -> Result<(), scylla::frame::value::ValueTooBig> {
                 let serialized: String = serde_json::to_string(&self).unwrap().into();
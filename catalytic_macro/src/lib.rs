@@ -1,22 +1,31 @@
 mod json;
 
-use catalytic::query_metadata::QueryType;
-use catalytic_query_parser::Query;
+use catalytic::query_metadata::{Lwt, QueryType};
+use catalytic_query_parser::cql::{Condition, Operator};
+use catalytic_query_parser::crud::Statement;
+use catalytic_query_parser::{Query, QueryAs};
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 use syn::DeriveInput;
 
 /// Transforms a query to to the corresponding type
 /// let transformed_type = catalytic_query::query!("select * from my_table where some_property = ?", my_property);
+/// A query that ends with 'allow filtering' needs the flag: query!(allow_filtering, "select ... allow filtering")
 #[proc_macro]
 pub fn query(input: TokenStream) -> TokenStream {
     let query = parse_macro_input!(input as Query);
 
-    check_predefined_queries(&query);
+    into_token_stream(check_predefined_queries(&query).and_then(|_| query.create_transformed()))
+}
 
-    let transformed = query.create_transformed();
+/// Transforms a select query, the rows are mapped to the given struct instead of the struct of the table
+/// The struct must have a field per selected column (or alias), the columns are mapped to the fields by name
+/// let transformed_type = catalytic_query::query_as!(MyRow, "select a, b as c from my_table where a = ?", a);
+#[proc_macro]
+pub fn query_as(input: TokenStream) -> TokenStream {
+    let query = parse_macro_input!(input as QueryAs).0;
 
-    transformed.into()
+    into_token_stream(query.create_transformed())
 }
 
 /// When a materialized view has the same columns as the base table, an auto-conversion can take place between the structs
@@ -25,31 +34,62 @@ pub fn query(input: TokenStream) -> TokenStream {
 pub fn query_base_table(input: TokenStream) -> TokenStream {
     let query = parse_macro_input!(input as Query);
 
-    check_predefined_queries(&query);
-
-    let transformed = query.create_transformed_materialized_view();
+    into_token_stream(
+        check_predefined_queries(&query).and_then(|_| query.create_transformed_materialized_view()),
+    )
+}
 
-    transformed.into()
+/// Invalid queries result in a compile error that points to the query
+fn into_token_stream(result: syn::Result<proc_macro2::TokenStream>) -> TokenStream {
+    result.unwrap_or_else(|e| e.to_compile_error()).into()
 }
 
-fn check_predefined_queries(query: &Query) {
+fn check_predefined_queries(query: &Query) -> syn::Result<()> {
     let value =
         std::env::var("ALLOW_CUSTOM_PREDEFINED_QUERIES").unwrap_or_else(|_| "0".to_string());
 
     if value == "1" {
-        return;
+        return Ok(());
     }
 
     match query.qmd.query_type {
-        QueryType::SelectUnique
-        | QueryType::DeleteUnique
-        | QueryType::InsertUnique
-        | QueryType::Truncate => {
-            panic!("Use predefined method")
+        QueryType::SelectUnique => {
+            // Selecting some of the columns or metadata is allowed, only the writetime of all columns has a predefined method
+            if query.selects_entity() {
+                return Err(query.error("Use predefined method"));
+            }
+        }
+        QueryType::InsertUnique | QueryType::Truncate => {
+            return Err(query.error("Use predefined method"));
+        }
+        QueryType::DeleteUnique => {
+            let delete = match &query.statement {
+                Statement::Delete(delete) => delete,
+                _ => unreachable!(),
+            };
+            // There is no predefined method for deleting columns or for deletions with conditions on columns
+            if delete.columns.is_empty() && query.qmd.lwt != Some(Lwt::IfCondition) {
+                return Err(query.error("Use predefined method"));
+            }
         }
         QueryType::UpdateUnique => {
-            if !query.qmd.query.contains(',') {
-                panic!("Updating only 1 columns, do it with predefined method");
+            let update = match &query.statement {
+                Statement::Update(update) => update,
+                _ => unreachable!(),
+            };
+            // Updating a single column with a condition on only that column is also predefined
+            let predefined = match &update.condition {
+                None => true,
+                Some(Condition::IfExists) => false,
+                Some(Condition::If(relations)) => {
+                    relations.len() == 1
+                        && relations[0].operator == Operator::Eq
+                        && relations[0].column.name == update.assignments[0].column.name
+                }
+            };
+
+            if update.assignments.len() == 1 && predefined {
+                return Err(query.error("Updating only 1 columns, do it with predefined method"));
             }
         }
         QueryType::SelectMultiple
@@ -59,6 +99,8 @@ fn check_predefined_queries(query: &Query) {
             // Nothing I guess
         }
     }
+
+    Ok(())
 }
 
 /// Annotating a struct with this macro will make it work with scylla's serialization/deserialization
@@ -0,0 +1,232 @@
+//! Tokenizer and parser for the subset of CQL that can be used in queries
+//! The statements itself are parsed in the crud module
+mod parser;
+mod token;
+
+pub use parser::Parser;
+pub use token::{tokenize, Token, TokenKind};
+
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// Byte offsets of a part of the query
+pub type Span = Range<usize>;
+
+/// A query that could not be parsed, the span points to the offending part of the query
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>, span: Span) -> ParseError {
+        ParseError {
+            message: message.into(),
+            span,
+        }
+    }
+
+    /// The message followed by the line of the query that contains the error, with the
+    /// offending part underlined
+    pub fn describe(&self, query: &str) -> String {
+        let start = self.span.start.min(query.len());
+        let line_start = query[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = query[start..].find('\n').map_or(query.len(), |i| start + i);
+        let end = self.span.end.clamp(start, line_end);
+        let indent = query[line_start..start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let underline = "^".repeat(query[start..end].chars().count().max(1));
+
+        format!(
+            "{}\n{}\n{}{}",
+            self.message,
+            &query[line_start..line_end],
+            indent,
+            underline
+        )
+    }
+
+    /// Converts the error to an error that points to the offending part of the query literal
+    /// Pointing inside a literal is only supported by nightly compilers, so on stable the whole
+    /// literal is pointed at
+    pub fn to_syn_error(&self, literal: &syn::LitStr) -> syn::Error {
+        let token = literal.token();
+        let source = token.to_string();
+        // Raw strings have more characters before the query starts
+        let offset = source.find('"').map_or(0, |i| i + 1);
+        // Only if the literal has no escaped characters, the span can be mapped to the source
+        let maps_to_source = source
+            .get(offset..source.rfind('"').unwrap_or(offset))
+            .map_or(false, |s| s == literal.value());
+        let end = self.span.end.max(self.span.start + 1);
+        let span = if maps_to_source {
+            token
+                .subspan(offset + self.span.start..offset + end)
+                .unwrap_or_else(|| literal.span())
+        } else {
+            literal.span()
+        };
+
+        syn::Error::new(span, &self.message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// A column, table or keyspace name
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ident {
+    /// Lowercased if the identifier wasn't quoted
+    pub name: String,
+    pub span: Span,
+}
+
+/// A table name, which can be prefixed with a keyspace
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableRef {
+    pub keyspace: Option<Ident>,
+    pub name: Ident,
+}
+
+/// A value in a query
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    /// The '?' in a query
+    BindMarker(Span),
+    /// A fixed value, like 1, 'text', true, a uuid or a collection
+    Constant(Constant),
+    /// Values between parentheses, used in 'in' relations
+    Tuple(Vec<Term>, Span),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constant {
+    /// The value as written in the query
+    pub text: String,
+    pub span: Span,
+}
+
+impl Term {
+    pub fn span(&self) -> Span {
+        match self {
+            Term::BindMarker(span) | Term::Tuple(_, span) => span.clone(),
+            Term::Constant(c) => c.span.clone(),
+        }
+    }
+
+    pub fn is_bind_marker(&self) -> bool {
+        matches!(self, Term::BindMarker(_))
+    }
+
+    /// Only true if the term is a bind marker or a tuple with a bind marker
+    pub fn contains_bind_marker(&self) -> bool {
+        match self {
+            Term::BindMarker(_) => true,
+            Term::Constant(_) => false,
+            Term::Tuple(terms, _) => terms.iter().any(Term::contains_bind_marker),
+        }
+    }
+
+    /// Only true if the term is a constant with exactly this text
+    pub fn is_constant(&self, text: &str) -> bool {
+        matches!(self, Term::Constant(c) if c.text == text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Eq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    In,
+    /// Only allowed in the 'if' clause of a lightweight transaction
+    NotEq,
+}
+
+/// A single restriction in a where clause, e.g. 'a = ?'
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relation {
+    pub column: Ident,
+    pub operator: Operator,
+    pub value: Term,
+}
+
+/// The 'if' clause of an update or delete query, which makes it a lightweight transaction
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    IfExists,
+    /// E.g. 'if a = ? and b > 1'
+    If(Vec<Relation>),
+}
+
+/// The 'using' clause of an insert, update or delete query, e.g. 'using ttl ? and timestamp ?'
+#[derive(Debug, Clone, PartialEq)]
+pub struct Using {
+    /// The options in the order they appear in the query
+    pub options: Vec<UsingOption>,
+}
+
+/// Both options are either a bind marker or an integer constant
+#[derive(Debug, Clone, PartialEq)]
+pub enum UsingOption {
+    /// The ttl in seconds
+    Ttl(Term),
+    /// The write timestamp in microseconds
+    Timestamp(Term),
+}
+
+impl Using {
+    pub fn ttl(&self) -> Option<&Term> {
+        self.options.iter().find_map(|o| match o {
+            UsingOption::Ttl(t) => Some(t),
+            UsingOption::Timestamp(_) => None,
+        })
+    }
+
+    pub fn timestamp(&self) -> Option<&Term> {
+        self.options.iter().find_map(|o| match o {
+            UsingOption::Timestamp(t) => Some(t),
+            UsingOption::Ttl(_) => None,
+        })
+    }
+}
+
+/// A column in the 'order by' clause
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ordering {
+    pub column: Ident,
+    pub descending: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn describe() {
+        let query = "select *\nform test_table";
+        let error = ParseError::new("Expected 'from', found 'form'", 9..13);
+
+        assert_eq!(
+            "Expected 'from', found 'form'\nform test_table\n^^^^",
+            error.describe(query)
+        );
+
+        let query = "select * from";
+        let error = ParseError::new("Expected a table name", 13..13);
+
+        assert_eq!(
+            "Expected a table name\nselect * from\n             ^",
+            error.describe(query)
+        );
+    }
+}
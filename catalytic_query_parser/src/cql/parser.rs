@@ -0,0 +1,697 @@
+use crate::cql::{
+    tokenize, Condition, Constant, Ident, Operator, Ordering, ParseError, Relation, Span, TableRef,
+    Term, Token, TokenKind, Using, UsingOption,
+};
+use std::str::FromStr;
+
+/// Keywords that can not be used as an identifier unless they are quoted
+const RESERVED_KEYWORDS: [&str; 54] = [
+    "add",
+    "allow",
+    "alter",
+    "and",
+    "apply",
+    "asc",
+    "authorize",
+    "batch",
+    "begin",
+    "by",
+    "columnfamily",
+    "create",
+    "delete",
+    "desc",
+    "describe",
+    "drop",
+    "entries",
+    "execute",
+    "from",
+    "full",
+    "grant",
+    "if",
+    "in",
+    "index",
+    "infinity",
+    "insert",
+    "into",
+    "keyspace",
+    "limit",
+    "modify",
+    "nan",
+    "norecursive",
+    "not",
+    "null",
+    "of",
+    "on",
+    "or",
+    "order",
+    "primary",
+    "rename",
+    "replace",
+    "revoke",
+    "schema",
+    "select",
+    "set",
+    "table",
+    "to",
+    "token",
+    "truncate",
+    "unlogged",
+    "update",
+    "use",
+    "using",
+    "where",
+];
+
+/// Walks over the tokens of a query
+/// The statements build on top of the helper methods to parse their clauses
+pub struct Parser<'a> {
+    query: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(query: &'a str) -> Result<Parser<'a>, ParseError> {
+        Ok(Parser {
+            query,
+            tokens: tokenize(query)?,
+            position: 0,
+        })
+    }
+
+    pub fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+
+        if token.is_some() {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    /// The span of the current token, or an empty span at the end of the query
+    pub fn current_span(&self) -> Span {
+        self.peek()
+            .map_or(self.query.len()..self.query.len(), |t| t.span.clone())
+    }
+
+    /// Creates an error which points to the current token
+    pub fn expected(&self, expected: &str) -> ParseError {
+        let message = match self.peek() {
+            Some(token) => format!(
+                "Expected {}, found '{}'",
+                expected,
+                &self.query[token.span.clone()]
+            ),
+            None => format!("Expected {}, found the end of the query", expected),
+        };
+
+        ParseError::new(message, self.current_span())
+    }
+
+    pub fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token { kind: TokenKind::Word(w), .. }) if w == keyword)
+    }
+
+    /// Skips the keyword if it is the current token
+    pub fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.peek_keyword(keyword);
+
+        if found {
+            self.position += 1;
+        }
+
+        found
+    }
+
+    pub fn expect_keyword(&mut self, keyword: &str) -> Result<Span, ParseError> {
+        let span = self.current_span();
+
+        if self.eat_keyword(keyword) {
+            Ok(span)
+        } else {
+            Err(self.expected(&format!("'{}'", keyword)))
+        }
+    }
+
+    /// Only true if the current token is the name of the function, followed by '('
+    pub fn peek_function(&self, name: &str) -> bool {
+        self.peek_keyword(name)
+            && matches!(
+                self.tokens.get(self.position + 1),
+                Some(Token {
+                    kind: TokenKind::Symbol("("),
+                    ..
+                })
+            )
+    }
+
+    /// Skips the name of the function and the '(' if the function is called
+    pub fn eat_function(&mut self, name: &str) -> bool {
+        let found = self.peek_function(name);
+
+        if found {
+            self.position += 2;
+        }
+
+        found
+    }
+
+    pub fn peek_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token { kind: TokenKind::Symbol(s), .. }) if *s == symbol)
+    }
+
+    /// Skips the symbol if it is the current token
+    pub fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let found = self.peek_symbol(symbol);
+
+        if found {
+            self.position += 1;
+        }
+
+        found
+    }
+
+    pub fn expect_symbol(&mut self, symbol: &str) -> Result<Span, ParseError> {
+        let span = self.current_span();
+
+        if self.eat_symbol(symbol) {
+            Ok(span)
+        } else {
+            Err(self.expected(&format!("'{}'", symbol)))
+        }
+    }
+
+    /// Parses a column, table or keyspace name
+    pub fn ident(&mut self, what: &str) -> Result<Ident, ParseError> {
+        let name = match self.peek() {
+            Some(Token {
+                kind: TokenKind::Word(w),
+                ..
+            }) if !RESERVED_KEYWORDS.contains(&w.as_str()) => w.clone(),
+            Some(Token {
+                kind: TokenKind::QuotedIdent(i),
+                ..
+            }) => i.clone(),
+            _ => return Err(self.expected(what)),
+        };
+        let span = self.next().unwrap().span;
+
+        Ok(Ident { name, span })
+    }
+
+    /// Only true if the current token is the identifier with the name
+    pub fn peek_ident(&self, name: &str) -> bool {
+        match self.peek().map(|t| &t.kind) {
+            Some(TokenKind::Word(w)) => w == name && !RESERVED_KEYWORDS.contains(&w.as_str()),
+            Some(TokenKind::QuotedIdent(i)) => i == name,
+            _ => false,
+        }
+    }
+
+    /// Parses a table name, optionally prefixed with a keyspace
+    pub fn table(&mut self) -> Result<TableRef, ParseError> {
+        let name = self.ident("a table name")?;
+
+        if self.eat_symbol(".") {
+            Ok(TableRef {
+                keyspace: Some(name),
+                name: self.ident("a table name")?,
+            })
+        } else {
+            Ok(TableRef {
+                keyspace: None,
+                name,
+            })
+        }
+    }
+
+    /// Parses a comma separated list, the first element is always parsed
+    pub fn comma_separated<T>(
+        &mut self,
+        mut parse: impl FnMut(&mut Parser<'a>) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut parsed = vec![parse(self)?];
+
+        while self.eat_symbol(",") {
+            parsed.push(parse(self)?);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Parses a bind marker, a constant or a tuple of terms
+    pub fn term(&mut self) -> Result<Term, ParseError> {
+        let start = self.current_span();
+
+        if self.eat_symbol("(") {
+            let terms = self.comma_separated(Parser::term)?;
+            let end = self.expect_symbol(")")?;
+
+            return Ok(Term::Tuple(terms, start.start..end.end));
+        }
+
+        match self.peek().map(|t| &t.kind) {
+            Some(TokenKind::BindMarker(_)) => {
+                self.position += 1;
+
+                Ok(Term::BindMarker(start))
+            }
+            _ => Ok(Term::Constant(self.constant()?)),
+        }
+    }
+
+    /// Parses a value of an insert or update query
+    pub fn value(&mut self) -> Result<Term, ParseError> {
+        let term = self.term()?;
+
+        match &term {
+            Term::Tuple(_, span) if term.contains_bind_marker() => Err(ParseError::new(
+                "Bind markers are not supported inside tuple values, bind the whole tuple instead",
+                span.clone(),
+            )),
+            _ => Ok(term),
+        }
+    }
+
+    /// Parses a fixed value, bind markers are not allowed inside collections
+    fn constant(&mut self) -> Result<Constant, ParseError> {
+        let start = self.current_span();
+
+        match self.peek().map(|t| t.kind.clone()) {
+            Some(TokenKind::Literal) => {
+                self.position += 1;
+            }
+            Some(TokenKind::Word(w))
+                if ["true", "false", "null", "nan", "infinity"].contains(&w.as_str()) =>
+            {
+                self.position += 1;
+            }
+            Some(TokenKind::Symbol("-")) => {
+                self.position += 1;
+
+                match self.peek().map(|t| &t.kind) {
+                    Some(TokenKind::Literal) => self.position += 1,
+                    Some(TokenKind::Word(w)) if w == "nan" || w == "infinity" => {
+                        self.position += 1
+                    }
+                    _ => return Err(self.expected("a number")),
+                }
+            }
+            Some(TokenKind::Symbol("[")) => {
+                self.position += 1;
+                self.collection_elements("]", false)?;
+            }
+            Some(TokenKind::Symbol("{")) => {
+                self.position += 1;
+                self.collection_elements("}", true)?;
+            }
+            Some(TokenKind::BindMarker(_)) => {
+                return Err(ParseError::new(
+                    "Bind markers are not supported inside collection values, bind the whole collection instead",
+                    start,
+                ))
+            }
+            _ => return Err(self.expected("a value")),
+        }
+
+        let end = self.tokens[self.position - 1].span.end;
+        let span = start.start..end;
+
+        Ok(Constant {
+            text: self.query[span.clone()].to_string(),
+            span,
+        })
+    }
+
+    /// Parses the elements of a list, set or map until the closing symbol
+    fn collection_elements(&mut self, closing: &str, allow_map: bool) -> Result<(), ParseError> {
+        if self.eat_symbol(closing) {
+            return Ok(());
+        }
+
+        self.comma_separated(|p| {
+            p.constant()?;
+
+            if allow_map && p.eat_symbol(":") {
+                p.constant()?;
+            }
+
+            Ok(())
+        })?;
+        self.expect_symbol(closing)?;
+
+        Ok(())
+    }
+
+    /// Parses the where clause, if present
+    pub fn where_clause(&mut self) -> Result<Vec<Relation>, ParseError> {
+        if self.eat_keyword("where") {
+            let mut relations = vec![self.relation(false)?];
+
+            while self.eat_keyword("and") {
+                relations.push(self.relation(false)?);
+            }
+
+            Ok(relations)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Parses a relation of a where clause, or of an if clause if 'condition' is true
+    fn relation(&mut self, condition: bool) -> Result<Relation, ParseError> {
+        let column = self.ident("a column name")?;
+        let operator = match self.peek().map(|t| &t.kind) {
+            Some(TokenKind::Symbol("=")) => Operator::Eq,
+            Some(TokenKind::Symbol("<")) => Operator::Lt,
+            Some(TokenKind::Symbol("<=")) => Operator::Lte,
+            Some(TokenKind::Symbol(">")) => Operator::Gt,
+            Some(TokenKind::Symbol(">=")) => Operator::Gte,
+            Some(TokenKind::Word(w)) if w == "in" => Operator::In,
+            Some(TokenKind::Symbol("!=")) if condition => Operator::NotEq,
+            Some(TokenKind::Symbol("!=")) => {
+                return Err(ParseError::new(
+                    "'!=' can only be used in an 'if' condition",
+                    self.current_span(),
+                ))
+            }
+            _ => return Err(self.expected("an operator")),
+        };
+
+        self.position += 1;
+
+        let value = self.term()?;
+
+        match (&value, operator) {
+            (Term::Tuple(terms, span), Operator::In) => {
+                if terms.iter().any(Term::contains_bind_marker) {
+                    return Err(ParseError::new("An in query should invoked by doing 'in ?', not 'in (?)', since this always select only 0/1 rows", span.clone()));
+                }
+            }
+            (Term::Tuple(_, span), _) => {
+                return Err(ParseError::new(
+                    "Only an 'in' relation can have multiple values",
+                    span.clone(),
+                ))
+            }
+            (Term::Constant(c), Operator::In) => {
+                return Err(ParseError::new(
+                    "Expected '?' or values between parentheses",
+                    c.span.clone(),
+                ))
+            }
+            _ => {}
+        }
+
+        Ok(Relation {
+            column,
+            operator,
+            value,
+        })
+    }
+
+    /// Parses the 'if not exists' clause of an insert query, if present
+    pub fn if_not_exists(&mut self) -> Result<bool, ParseError> {
+        if !self.eat_keyword("if") {
+            return Ok(false);
+        }
+
+        self.expect_keyword("not")?;
+        self.expect_keyword("exists")?;
+
+        Ok(true)
+    }
+
+    /// Parses the 'if' clause of an update or delete query, if present
+    pub fn condition(&mut self) -> Result<Option<Condition>, ParseError> {
+        if !self.eat_keyword("if") {
+            return Ok(None);
+        }
+
+        if self.eat_keyword("exists") {
+            return Ok(Some(Condition::IfExists));
+        }
+
+        let mut relations = vec![self.relation(true)?];
+
+        while self.eat_keyword("and") {
+            relations.push(self.relation(true)?);
+        }
+
+        Ok(Some(Condition::If(relations)))
+    }
+
+    /// Parses the using clause, if present
+    /// The ttl and timestamp can both be provided once, in any order
+    pub fn using(&mut self) -> Result<Option<Using>, ParseError> {
+        if !self.eat_keyword("using") {
+            return Ok(None);
+        }
+
+        let mut using = Using { options: vec![] };
+
+        loop {
+            let (has_ttl, has_timestamp) = (using.ttl().is_some(), using.timestamp().is_some());
+            let option = if !has_ttl && self.eat_keyword("ttl") {
+                UsingOption::Ttl(self.integer_or_bind_marker::<i32>("a ttl in seconds")?)
+            } else if !has_timestamp && self.eat_keyword("timestamp") {
+                UsingOption::Timestamp(
+                    self.integer_or_bind_marker::<i64>("a timestamp in microseconds")?,
+                )
+            } else if has_ttl {
+                return Err(self.expected("'timestamp'"));
+            } else if has_timestamp {
+                return Err(self.expected("'ttl'"));
+            } else {
+                return Err(self.expected("'ttl' or 'timestamp'"));
+            };
+
+            using.options.push(option);
+
+            if !self.eat_keyword("and") {
+                return Ok(Some(using));
+            }
+        }
+    }
+
+    /// Parses the order by clause, if present
+    pub fn order_by(&mut self) -> Result<Vec<Ordering>, ParseError> {
+        if !self.eat_keyword("order") {
+            return Ok(vec![]);
+        }
+
+        self.expect_keyword("by")?;
+        self.comma_separated(|p| {
+            let column = p.ident("a column name")?;
+            let descending = p.eat_keyword("desc");
+
+            if !descending {
+                p.eat_keyword("asc");
+            }
+
+            Ok(Ordering { column, descending })
+        })
+    }
+
+    /// Parses the limit clause, if present
+    pub fn limit(&mut self) -> Result<Option<Term>, ParseError> {
+        if self.eat_keyword("limit") {
+            Ok(Some(self.integer_or_bind_marker::<i32>("a limit")?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn integer_or_bind_marker<I: FromStr>(&mut self, expected: &str) -> Result<Term, ParseError> {
+        let is_integer = match self.peek() {
+            Some(Token {
+                kind: TokenKind::Literal,
+                span,
+            }) => self.query[span.clone()].parse::<I>().is_ok(),
+            Some(Token {
+                kind: TokenKind::BindMarker(_),
+                ..
+            }) => true,
+            _ => false,
+        };
+
+        if is_integer {
+            self.term()
+        } else {
+            Err(self.expected(expected))
+        }
+    }
+
+    /// Makes sure the whole query is parsed, a trailing ';' is allowed
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.eat_symbol(";");
+
+        if self.peek().is_some() {
+            Err(self.expected("the end of the query"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn where_clause() {
+        let mut parser =
+            Parser::new("where a = 1 AND \"B\" > ? and c <= -2.5 and d in ? and e in (1, 2)")
+                .unwrap();
+        let relations = parser.where_clause().unwrap();
+
+        parser.end().unwrap();
+
+        let columns = relations
+            .iter()
+            .map(|r| (r.column.name.as_str(), r.operator, r.value.is_bind_marker()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ("a", Operator::Eq, false),
+                ("B", Operator::Gt, true),
+                ("c", Operator::Lte, false),
+                ("d", Operator::In, true),
+                ("e", Operator::In, false),
+            ],
+            columns
+        );
+        assert!(relations[2].value.is_constant("-2.5"));
+    }
+
+    #[test]
+    fn no_where_clause() {
+        let mut parser = Parser::new("").unwrap();
+
+        assert!(parser.where_clause().unwrap().is_empty());
+    }
+
+    #[test]
+    fn in_with_bind_markers() {
+        let error = Parser::new("where a in (?, ?)")
+            .unwrap()
+            .where_clause()
+            .unwrap_err();
+
+        assert_eq!(11..17, error.span);
+    }
+
+    #[test]
+    fn reserved_keyword() {
+        let error = Parser::new("where from = 1")
+            .unwrap()
+            .where_clause()
+            .unwrap_err();
+
+        assert_eq!("Expected a column name, found 'from'", error.message);
+        assert_eq!(6..10, error.span);
+    }
+
+    #[test]
+    fn condition() {
+        let mut parser = Parser::new("if exists").unwrap();
+
+        assert_eq!(Some(Condition::IfExists), parser.condition().unwrap());
+
+        let mut parser = Parser::new("if a = ? and b > 1").unwrap();
+        let relations = match parser.condition().unwrap() {
+            Some(Condition::If(relations)) => relations,
+            c => panic!("Unexpected condition: {:?}", c),
+        };
+
+        parser.end().unwrap();
+
+        assert_eq!(2, relations.len());
+        assert!(relations[0].value.is_bind_marker());
+        assert_eq!(None, Parser::new("").unwrap().condition().unwrap());
+
+        let mut parser = Parser::new("if a != ?").unwrap();
+        let relations = match parser.condition().unwrap() {
+            Some(Condition::If(relations)) => relations,
+            c => panic!("Unexpected condition: {:?}", c),
+        };
+
+        assert_eq!(Operator::NotEq, relations[0].operator);
+
+        let error = Parser::new("where a != ?")
+            .unwrap()
+            .where_clause()
+            .unwrap_err();
+
+        assert_eq!("'!=' can only be used in an 'if' condition", error.message);
+        assert_eq!(8..10, error.span);
+
+        let error = Parser::new("if not").unwrap().condition().unwrap_err();
+
+        assert_eq!("Expected a column name, found 'not'", error.message);
+    }
+
+    #[test]
+    fn using() {
+        let mut parser = Parser::new("using timestamp ? and ttl 10").unwrap();
+        let using = parser.using().unwrap().unwrap();
+
+        parser.end().unwrap();
+
+        assert!(matches!(
+            using.options.as_slice(),
+            [UsingOption::Timestamp(_), UsingOption::Ttl(_)]
+        ));
+        assert!(using.timestamp().unwrap().is_bind_marker());
+        assert!(using.ttl().unwrap().is_constant("10"));
+
+        let using = Parser::new("using timestamp 1634567890123456")
+            .unwrap()
+            .using()
+            .unwrap()
+            .unwrap();
+
+        assert!(using.timestamp().unwrap().is_constant("1634567890123456"));
+        assert_eq!(None, using.ttl());
+
+        let error = Parser::new("using ttl 1634567890123456")
+            .unwrap()
+            .using()
+            .unwrap_err();
+
+        assert_eq!(
+            "Expected a ttl in seconds, found '1634567890123456'",
+            error.message
+        );
+
+        let error = Parser::new("using ttl ? and ttl ?")
+            .unwrap()
+            .using()
+            .unwrap_err();
+
+        assert_eq!("Expected 'timestamp', found 'ttl'", error.message);
+
+        let error = Parser::new("using").unwrap().using().unwrap_err();
+
+        assert_eq!(
+            "Expected 'ttl' or 'timestamp', found the end of the query",
+            error.message
+        );
+    }
+
+    #[test]
+    fn collections() {
+        let mut parser = Parser::new("[1, 2] {'a': 1} {} [?]").unwrap();
+
+        assert!(parser.term().unwrap().is_constant("[1, 2]"));
+        assert!(parser.term().unwrap().is_constant("{'a': 1}"));
+        assert!(parser.term().unwrap().is_constant("{}"));
+        assert_eq!(20..21, parser.term().unwrap_err().span);
+    }
+}
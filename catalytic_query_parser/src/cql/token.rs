@@ -0,0 +1,291 @@
+use crate::cql::{ParseError, Span};
+
+/// Symbols are matched in this order, so the symbols of 2 characters should come first
+const SYMBOLS: [&str; 19] = [
+    "<=", ">=", "!=", "=", "<", ">", ",", "(", ")", "*", ".", ";", "[", "]", "{", "}", ":", "+",
+    "-",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    /// An unquoted identifier or keyword, lowercased since those are case insensitive
+    Word(String),
+    /// An identifier between double quotes, which keeps its case
+    QuotedIdent(String),
+    /// A string, number, uuid or blob
+    Literal,
+    /// The '?' in a query, or a named bind marker like ':name' which contains the name
+    BindMarker(Option<String>),
+    Symbol(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// The location of the token in the query
+    pub span: Span,
+}
+
+/// Splits a query in tokens, skipping whitespaces and comments
+pub fn tokenize(query: &str) -> Result<Vec<Token>, ParseError> {
+    let bytes = query.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+    // Inside map literals a ':' separates the key and the value
+    let mut braces = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        let next = bytes.get(i + 1).copied();
+
+        let kind = match bytes[i] {
+            c if c.is_ascii_whitespace() => {
+                i += 1;
+
+                continue;
+            }
+            b'-' if next == Some(b'-') => {
+                i = skip_line(query, i);
+
+                continue;
+            }
+            b'/' if next == Some(b'/') => {
+                i = skip_line(query, i);
+
+                continue;
+            }
+            b'/' if next == Some(b'*') => {
+                i = match query[i + 2..].find("*/") {
+                    Some(end) => i + 2 + end + 2,
+                    None => return Err(ParseError::new("Unterminated comment", i..query.len())),
+                };
+
+                continue;
+            }
+            b'\'' => {
+                i = end_of_quoted(query, i, '\'', "Unterminated string")?;
+
+                TokenKind::Literal
+            }
+            b'"' => {
+                i = end_of_quoted(query, i, '"', "Unterminated quoted identifier")?;
+
+                TokenKind::QuotedIdent(query[start + 1..i - 1].replace("\"\"", "\""))
+            }
+            b'?' => {
+                i += 1;
+
+                TokenKind::BindMarker(None)
+            }
+            b':' if braces == 0 && next.map_or(false, |c| c.is_ascii_alphabetic()) => {
+                i = skip_while(bytes, i + 1, |c| c.is_ascii_alphanumeric() || c == b'_');
+
+                TokenKind::BindMarker(Some(query[start + 1..i].to_string()))
+            }
+            c if c.is_ascii_alphanumeric() && is_uuid(&query[i..]) => {
+                i += UUID_LENGTH;
+
+                TokenKind::Literal
+            }
+            b'0' if matches!(next, Some(b'x') | Some(b'X')) => {
+                i = skip_while(bytes, i + 2, |c| c.is_ascii_hexdigit());
+
+                TokenKind::Literal
+            }
+            c if c.is_ascii_digit() => {
+                i = end_of_number(bytes, i);
+
+                TokenKind::Literal
+            }
+            c if c.is_ascii_alphabetic() => {
+                i = skip_while(bytes, i, |c| c.is_ascii_alphanumeric() || c == b'_');
+
+                TokenKind::Word(query[start..i].to_lowercase())
+            }
+            _ => match SYMBOLS.iter().find(|s| query[i..].starts_with(*s)) {
+                Some(symbol) => {
+                    i += symbol.len();
+
+                    match *symbol {
+                        "{" => braces += 1,
+                        "}" => braces -= 1,
+                        _ => {}
+                    }
+
+                    TokenKind::Symbol(symbol)
+                }
+                None => {
+                    let c = query[i..].chars().next().unwrap();
+
+                    return Err(ParseError::new(
+                        format!("Unexpected character '{}'", c),
+                        i..i + c.len_utf8(),
+                    ));
+                }
+            },
+        };
+
+        tokens.push(Token {
+            kind,
+            span: start..i,
+        });
+    }
+
+    Ok(tokens)
+}
+
+const UUID_LENGTH: usize = 36;
+
+fn is_uuid(s: &str) -> bool {
+    s.len() >= UUID_LENGTH
+        && s.as_bytes()[..UUID_LENGTH]
+            .iter()
+            .enumerate()
+            .all(|(index, c)| match index {
+                8 | 13 | 18 | 23 => *c == b'-',
+                _ => c.is_ascii_hexdigit(),
+            })
+        && !s
+            .as_bytes()
+            .get(UUID_LENGTH)
+            .map_or(false, |c| c.is_ascii_alphanumeric())
+}
+
+fn skip_while(bytes: &[u8], mut i: usize, predicate: impl Fn(u8) -> bool) -> usize {
+    while i < bytes.len() && predicate(bytes[i]) {
+        i += 1;
+    }
+
+    i
+}
+
+fn skip_line(query: &str, i: usize) -> usize {
+    query[i..].find('\n').map_or(query.len(), |end| i + end + 1)
+}
+
+/// Numbers like 1, 1.5 and 1e10
+fn end_of_number(bytes: &[u8], i: usize) -> usize {
+    let mut i = skip_while(bytes, i, |c| c.is_ascii_digit());
+
+    if bytes.get(i) == Some(&b'.') && bytes.get(i + 1).map_or(false, |c| c.is_ascii_digit()) {
+        i = skip_while(bytes, i + 1, |c| c.is_ascii_digit());
+    }
+
+    if matches!(bytes.get(i), Some(b'e') | Some(b'E')) {
+        let mut exponent = i + 1;
+
+        if matches!(bytes.get(exponent), Some(b'+') | Some(b'-')) {
+            exponent += 1;
+        }
+
+        if bytes.get(exponent).map_or(false, |c| c.is_ascii_digit()) {
+            i = skip_while(bytes, exponent, |c| c.is_ascii_digit());
+        }
+    }
+
+    i
+}
+
+/// Returns the index after the closing quote, a quote is escaped by writing it twice
+fn end_of_quoted(query: &str, start: usize, quote: char, error: &str) -> Result<usize, ParseError> {
+    let mut i = start + 1;
+
+    loop {
+        match query[i..].find(quote) {
+            Some(end) => {
+                i += end + 1;
+
+                if query[i..].starts_with(quote) {
+                    i += 1;
+                } else {
+                    return Ok(i);
+                }
+            }
+            None => return Err(ParseError::new(error, start..query.len())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn kinds(query: &str) -> Vec<TokenKind> {
+        tokenize(query)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.kind)
+            .collect()
+    }
+
+    fn word(w: &str) -> TokenKind {
+        TokenKind::Word(w.to_string())
+    }
+
+    #[test]
+    fn tokens() {
+        assert_eq!(
+            kinds("SELECT \"MyColumn\" FROM ks.My_Table\n\tWHERE a >= ? -- comment\n"),
+            vec![
+                word("select"),
+                TokenKind::QuotedIdent("MyColumn".to_string()),
+                word("from"),
+                word("ks"),
+                TokenKind::Symbol("."),
+                word("my_table"),
+                word("where"),
+                word("a"),
+                TokenKind::Symbol(">="),
+                TokenKind::BindMarker(None),
+            ]
+        );
+    }
+
+    #[test]
+    fn named_bind_markers() {
+        assert_eq!(
+            kinds("a = :myName and m = {'k': v, 'l':true}"),
+            vec![
+                word("a"),
+                TokenKind::Symbol("="),
+                TokenKind::BindMarker(Some("myName".to_string())),
+                word("and"),
+                word("m"),
+                TokenKind::Symbol("="),
+                TokenKind::Symbol("{"),
+                TokenKind::Literal,
+                TokenKind::Symbol(":"),
+                word("v"),
+                TokenKind::Symbol(","),
+                TokenKind::Literal,
+                TokenKind::Symbol(":"),
+                word("true"),
+                TokenKind::Symbol("}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn literals() {
+        let query = "'it''s' 1.5e3 0xcafe 3866a82f-f37c-446c-8838-fb6686c3acf2 -1";
+        let tokens = tokenize(query).unwrap();
+
+        assert_eq!(6, tokens.len());
+        assert_eq!("'it''s'", &query[tokens[0].span.clone()]);
+        assert_eq!("1.5e3", &query[tokens[1].span.clone()]);
+        assert_eq!("0xcafe", &query[tokens[2].span.clone()]);
+        assert_eq!(
+            "3866a82f-f37c-446c-8838-fb6686c3acf2",
+            &query[tokens[3].span.clone()]
+        );
+        assert_eq!(TokenKind::Symbol("-"), tokens[4].kind);
+        assert_eq!(TokenKind::Literal, tokens[5].kind);
+    }
+
+    #[test]
+    fn unterminated_string() {
+        let error = tokenize("select * from t where a = 'oops").unwrap_err();
+
+        assert_eq!(26..31, error.span);
+    }
+}
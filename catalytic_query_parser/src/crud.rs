@@ -5,185 +5,109 @@ mod select;
 mod truncate;
 mod update;
 
-use crate::crud::operation::Operation;
-use catalytic::query_metadata::ColumnInQuery;
-
-pub use crate::crud::operation::find_operation;
-
-/// Extracts a table name from a query
-pub fn extract_table_name<'a, S: AsRef<str>>(query: &'a S, crud: &dyn Operation) -> &'a str {
-    let index = query
-        .as_ref()
-        .find(crud.table_name_after())
-        .expect("No table name found in query")
-        + crud.table_name_after().len()
-        - 1;
-    let suffix = &query.as_ref()[index + 1..];
-    let end = suffix.find(' ').unwrap_or_else(|| suffix.len());
-
-    &suffix[..end]
+use crate::cql::{ParseError, Parser};
+
+pub use crate::crud::delete::{Delete, DeletedColumn};
+pub use crate::crud::insert::Insert;
+pub use crate::crud::operation::{BindMarker, Operation};
+pub use crate::crud::select::{MetadataFunction, Select, Selection, Selector};
+pub use crate::crud::truncate::Truncate;
+pub use crate::crud::update::{Assignment, AssignmentOperation, Update};
+
+/// A parsed query
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Select(Select),
+    Insert(Insert),
+    Update(Update),
+    Delete(Delete),
+    Truncate(Truncate),
 }
 
-/// Extracts the used columns in a query
-pub fn extract_columns(query: &str, crud: &dyn Operation) -> Vec<ColumnInQuery> {
-    // remove the parameterized ttl and limit since it isn't a column
-    let without_ttl_and_limit = query.replace(" using ttl ?", "").replace(" limit ?", "");
-    let (query, query_without_where) = split_query(&without_ttl_and_limit);
-
-    crud.column_clauses(query_without_where)
-        .into_iter()
-        .chain(columns_after_where(query))
-        .collect()
-}
-
-/// Splits the query in a tuple
-/// The first element is the query without the limit clause (if any)
-/// The second element is the query without the limit and where clause
-///
-/// use catalytic::crud::split_query;
-/// let query = "select * from my_table where a = 1 limit 5";
-/// let (q, p) = split_query(query);
-///
-/// assert_eq!("select * from my_table where a = 1", q);
-/// assert_eq!("select * from my_table", p);
-///
-pub fn split_query(q: &str) -> (&str, &str) {
-    let mut query = q;
-
-    // Remove the 'limit' if present
-    if let Some(i) = query.rfind(" limit ") {
-        query = &query[..i]
+impl Statement {
+    pub fn operation(&self) -> &dyn Operation {
+        match self {
+            Statement::Select(s) => s,
+            Statement::Insert(i) => i,
+            Statement::Update(u) => u,
+            Statement::Delete(d) => d,
+            Statement::Truncate(t) => t,
+        }
     }
 
-    let where_clause = query.find(" where ").unwrap_or_else(|| query.len());
-
-    (query, &query[..where_clause])
+    /// Only true for 'select *' queries
+    pub fn selects_all_columns(&self) -> bool {
+        matches!(
+            self,
+            Statement::Select(Select {
+                selection: Selection::Wildcard(_),
+                ..
+            })
+        )
+    }
 }
 
-/// Extracts columns that are used in the where clause
-///
-/// use catalytic::crud::columns_after_where;
-/// let query = "select * from my_table where a = ? and c in ?";
-/// let extracted = columns_after_where(query);
-/// assert_eq!(2, extracted.len());
-/// assert_eq!("a", &extracted[0].column_name);
-/// assert_eq!("c", &extracted[1].column_name);
-/// assert!(!extracted[0].uses_in_value);
-/// assert!(extracted[1].uses_in_value);
-///
-pub fn columns_after_where(query: &str) -> Vec<ColumnInQuery> {
-    let w = " where ";
-    let index = match query.find(w) {
-        None => return vec![],
-        Some(i) => i,
+/// Parses a query, the first keyword determines the CRUD operation
+pub fn parse_statement(query: &str) -> Result<Statement, ParseError> {
+    let mut parser = Parser::new(query)?;
+    let statement = if parser.peek_keyword("select") {
+        Statement::Select(Select::parse(&mut parser)?)
+    } else if parser.peek_keyword("update") {
+        Statement::Update(Update::parse(&mut parser)?)
+    } else if parser.peek_keyword("insert") {
+        Statement::Insert(Insert::parse(&mut parser)?)
+    } else if parser.peek_keyword("delete") {
+        Statement::Delete(Delete::parse(&mut parser)?)
+    } else if parser.peek_keyword("truncate") {
+        Statement::Truncate(Truncate::parse(&mut parser)?)
+    } else {
+        return Err(parser.expected("select, update, delete, insert or truncate"));
     };
 
-    let mut suffix = query[index + w.len()..].to_string();
-    let mut column_values = vec![];
-    let operators_before_column_name = vec![" = ", " >= ", " > ", " <= ", " < ", " in "];
-
-    loop {
-        let mut operator_with_lowest_index = None;
-
-        for operator in operators_before_column_name.iter() {
-            if let Some(s) = suffix.find(operator) {
-                match operator_with_lowest_index {
-                    None => operator_with_lowest_index = Some((s, operator)),
-                    Some((o, _)) => {
-                        if s < o {
-                            operator_with_lowest_index = Some((s, operator))
-                        }
-                    }
-                }
-            }
-        }
+    parser.end()?;
 
-        match operator_with_lowest_index {
-            None => break,
-            Some((index, operator)) => {
-                let and = " and ";
-                let val = &suffix[suffix.find(operator).unwrap() + operator.len()
-                    ..suffix.find(and).unwrap_or_else(|| suffix.len())];
-
-                let cv = ColumnInQuery {
-                    column_name: suffix[..index].to_string(),
-                    parameterized: val.contains('?'),
-                    uses_in_value: operator == &" in ",
-                    is_part_of_where_clause: true,
-                };
-
-                column_values.push(cv);
-
-                if let Some(p) = suffix.find(and) {
-                    suffix = suffix[p + and.len()..].to_string();
-                } else {
-                    break;
-                }
-            }
-        }
-    }
-
-    column_values
+    Ok(statement)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::crud::{columns_after_where, extract_columns, split_query};
-
-    #[test]
-    fn test_query_before_process() {
-        let query = "select * from some_table where a = 1 and b = ?";
-        let before_where = "select * from some_table";
-        let (query_extracted, before_where_extracted) = split_query(query);
-
-        assert_eq!(query, query_extracted);
-        assert_eq!(before_where, before_where_extracted);
-
-        let (query_extracted, before_where_extracted) =
-            split_query("select * from some_table where a = 1 and b = ? limit 100");
-
-        assert_eq!(
-            "select * from some_table where a = 1 and b = ?",
-            query_extracted
-        );
-        assert_eq!(before_where, before_where_extracted);
-
-        let query = "insert into my_table(a, b) values (1, ?)";
-        let (query_extracted, before_where_extracted) = split_query(query);
-
-        assert_eq!(query, query_extracted);
-        assert_eq!(query, before_where_extracted);
-    }
+    use crate::crud::parse_statement;
 
     #[test]
     fn test_columns_after_where() {
-        let v = columns_after_where("");
-
-        assert!(v.is_empty());
-
-        let v = columns_after_where("select * from dummy");
+        let columns = |query: &str| {
+            parse_statement(query)
+                .unwrap()
+                .operation()
+                .columns()
+                .into_iter()
+                .filter(|c| c.is_part_of_where_clause)
+                .collect::<Vec<_>>()
+        };
+
+        let v = columns("select * from dummy");
 
         assert!(v.is_empty());
 
-        let v = columns_after_where("select * from dummy where a = 1");
+        let v = columns("select * from dummy where a = 1");
 
         assert_eq!("a", &v[0].column_name);
         assert!(!v[0].parameterized);
         assert!(!v[0].uses_in_value);
 
-        let v = columns_after_where("select * from dummy where a = ?");
+        let v = columns("select * from dummy where a = ?");
 
         assert_eq!("a", &v[0].column_name);
         assert!(v[0].parameterized);
         assert!(!v[0].uses_in_value);
 
-        let v = columns_after_where("select * from dummy where a in ?");
+        let v = columns("select * from dummy where a in ?");
 
         assert_eq!("a", &v[0].column_name);
         assert!(v[0].parameterized);
         assert!(v[0].uses_in_value);
 
-        let v = columns_after_where("select * from dummy where a = 1 and b > 0 and c <= somethingrandom and d < ? and e in (hi) and f = 2");
+        let v = columns("select * from dummy where a = 1 and b > 0 and c <= 'somethingrandom' and d < ? and e in ('hi') and f = 2");
 
         assert_eq!("a", &v[0].column_name);
         assert_eq!("b", &v[1].column_name);
@@ -198,9 +122,7 @@ mod tests {
         assert!(!v[4].parameterized);
         assert!(!v[5].parameterized);
 
-        let v = columns_after_where(
-            "select * from test_table where b = ? and c = 5 and d in ? limit 1",
-        );
+        let v = columns("select * from test_table where b = ? and c = 5 and d in ? limit 1");
 
         assert!(!v[0].uses_in_value);
         assert!(v[0].parameterized);
@@ -211,18 +133,38 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_columns() {
-        let c = extract_columns(
-            "select a, b as c, d from table where a = 1 and b > 2 and e in ? limit ?",
-            &crate::crud::select::Select,
+    fn test_whitespace_and_case_insensitivity() {
+        let formatted = parse_statement(
+            "SELECT *\n    FROM  test_table\n    WHERE b = ?\n      AND c IN ?\n    LIMIT 1;",
+        )
+        .unwrap();
+        let compact = parse_statement("select * from test_table where b=? and c in? limit 1")
+            .unwrap()
+            .operation()
+            .columns();
+
+        assert_eq!(compact, formatted.operation().columns());
+        assert!(formatted.selects_all_columns());
+    }
+
+    #[test]
+    fn test_truncate() {
+        parse_statement("truncate my_table").unwrap();
+        parse_statement("truncate table ks.my_table").unwrap();
+
+        let error = parse_statement("truncate my_table where a = 1").unwrap_err();
+
+        assert_eq!(
+            "Expected the end of the query, found 'where'",
+            error.message
         );
+        assert_eq!(18..23, error.span);
+    }
+
+    #[test]
+    fn test_unknown_operation() {
+        let error = parse_statement("  selec * from my_table").unwrap_err();
 
-        assert_eq!(6, c.len());
-        assert_eq!("a", &c[0].column_name);
-        assert_eq!("b", &c[1].column_name);
-        assert_eq!("d", &c[2].column_name);
-        assert_eq!("a", &c[3].column_name);
-        assert_eq!("b", &c[4].column_name);
-        assert_eq!("e", &c[5].column_name);
+        assert_eq!(2..7, error.span);
     }
 }
@@ -1,26 +1,212 @@
-use crate::crud::operation::Operation;
-use catalytic::query_metadata::{ColumnInQuery, QueryType};
+use crate::cql::{Condition, Ident, ParseError, Parser, Relation, TableRef, Term, Using};
+use crate::crud::operation::{
+    condition_bind_markers, condition_columns, lwt, using_bind_markers, where_bind_markers,
+    where_columns, where_restricted_columns, BindMarker, Operation,
+};
+use catalytic::query_metadata::{ColumnInQuery, Lwt, QueryType};
 
-pub struct Delete;
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delete {
+    /// The columns that are deleted, the whole row is deleted if this is empty
+    pub columns: Vec<DeletedColumn>,
+    pub table: TableRef,
+    /// Only the timestamp can be provided
+    pub using: Option<Using>,
+    pub where_clause: Vec<Relation>,
+    pub condition: Option<Condition>,
+}
+
+/// A column of which the value is deleted, e.g. 'e', or an element of a collection, e.g. 'm[?]'
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeletedColumn {
+    pub column: Ident,
+    /// The key of a map or the index of a list
+    pub element: Option<Term>,
+}
+
+impl DeletedColumn {
+    fn parse(parser: &mut Parser) -> Result<DeletedColumn, ParseError> {
+        let column = parser.ident("a column name")?;
+        let element = if parser.eat_symbol("[") {
+            let element = parser.term()?;
+
+            parser.expect_symbol("]")?;
+
+            Some(element)
+        } else {
+            None
+        };
+
+        Ok(DeletedColumn { column, element })
+    }
+
+    fn is_parameterized(&self) -> bool {
+        self.element.as_ref().map_or(false, Term::is_bind_marker)
+    }
+}
+
+impl Delete {
+    pub fn parse(parser: &mut Parser) -> Result<Delete, ParseError> {
+        parser.expect_keyword("delete")?;
+
+        let columns = if parser.peek_keyword("from") {
+            vec![]
+        } else {
+            parser.comma_separated(DeletedColumn::parse)?
+        };
+
+        parser.expect_keyword("from")?;
+
+        let table = parser.table()?;
+        let using_span = parser.current_span();
+        let using = parser.using()?;
+
+        if using.as_ref().and_then(Using::ttl).is_some() {
+            return Err(ParseError::new(
+                "A delete query can not have a ttl",
+                using_span,
+            ));
+        }
+
+        if !parser.peek_keyword("where") {
+            return Err(parser.expected("'where'"));
+        }
+
+        Ok(Delete {
+            columns,
+            table,
+            using,
+            where_clause: parser.where_clause()?,
+            condition: parser.condition()?,
+        })
+    }
+}
 
 impl Operation for Delete {
-    fn crud_query_start(&self) -> &'static str {
-        "delete"
+    fn table(&self) -> &TableRef {
+        &self.table
     }
 
-    fn table_name_after(&self) -> &'static str {
-        "from "
+    fn columns(&self) -> Vec<ColumnInQuery> {
+        self.columns
+            .iter()
+            .map(|c| ColumnInQuery {
+                column_name: c.column.name.clone(),
+                parameterized: c.is_parameterized(),
+                uses_in_value: false,
+                is_part_of_where_clause: false,
+            })
+            .chain(where_columns(&self.where_clause))
+            .chain(condition_columns(&self.condition))
+            .collect()
     }
 
-    fn column_clauses(&self, _query: &str) -> Vec<ColumnInQuery> {
-        vec![]
+    fn bind_markers(&self) -> Vec<BindMarker> {
+        self.columns
+            .iter()
+            .filter(|c| c.is_parameterized())
+            .map(|_| BindMarker::Column)
+            .chain(using_bind_markers(&self.using))
+            .chain(where_bind_markers(&self.where_clause))
+            .chain(condition_bind_markers(&self.condition))
+            .collect()
     }
 
-    fn query_type(&self, _query: &str, full_pk: bool) -> QueryType {
+    fn restricted_columns(&self) -> Vec<&str> {
+        where_restricted_columns(&self.where_clause)
+    }
+
+    fn lwt(&self) -> Option<Lwt> {
+        lwt(&self.condition)
+    }
+
+    fn query_type(&self, full_pk: bool) -> Result<QueryType, ParseError> {
         if full_pk {
-            QueryType::DeleteUnique
+            Ok(QueryType::DeleteUnique)
         } else {
-            QueryType::DeleteMultiple
+            Ok(QueryType::DeleteMultiple)
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(query: &str) -> Result<Delete, ParseError> {
+        let mut parser = Parser::new(query)?;
+        let delete = Delete::parse(&mut parser)?;
+
+        parser.end()?;
+
+        Ok(delete)
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let delete = parse("delete from t using timestamp ? where a = ? if b = ?").unwrap();
+
+        assert_eq!(
+            vec![
+                BindMarker::Timestamp,
+                BindMarker::Column,
+                BindMarker::Column
+            ],
+            delete.bind_markers()
+        );
+        assert_eq!(vec!["a"], delete.restricted_columns());
+
+        let error = parse("delete from t using ttl ? where a = ?").unwrap_err();
+
+        assert_eq!("A delete query can not have a ttl", error.message);
+        assert_eq!(14..19, error.span);
+    }
+
+    #[test]
+    fn test_columns() {
+        let delete = parse("delete e from t where a = ?").unwrap();
+        let columns = delete.columns();
+
+        assert_eq!("e", delete.columns[0].column.name);
+        assert_eq!(None, delete.columns[0].element);
+        assert_eq!(2, columns.len());
+        assert_eq!("e", &columns[0].column_name);
+        assert!(!columns[0].parameterized);
+        assert!(!columns[0].is_part_of_where_clause);
+        assert_eq!(vec![BindMarker::Column], delete.bind_markers());
+
+        // The elements are bound before the timestamp
+        let delete =
+            parse("DELETE m[?], l[1], \"E\" FROM t USING TIMESTAMP ? WHERE a = ?").unwrap();
+        let columns = delete.columns();
+
+        assert_eq!(3, delete.columns.len());
+        assert_eq!("E", delete.columns[2].column.name);
+        assert_eq!(
+            vec!["m", "l", "E", "a"],
+            columns
+                .iter()
+                .map(|c| c.column_name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(columns[0].parameterized);
+        assert!(!columns[1].parameterized);
+        assert_eq!(
+            vec![
+                BindMarker::Column,
+                BindMarker::Timestamp,
+                BindMarker::Column
+            ],
+            delete.bind_markers()
+        );
+
+        assert!(parse("delete from t where a = ?")
+            .unwrap()
+            .columns
+            .is_empty());
+
+        let error = parse("delete e, from t where a = ?").unwrap_err();
+
+        assert_eq!("Expected a column name, found 'from'", error.message);
+    }
+}
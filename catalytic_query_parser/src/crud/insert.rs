@@ -1,76 +1,202 @@
-use crate::crud::operation::Operation;
-use catalytic::query_metadata::{ColumnInQuery, QueryType};
+use crate::cql::{Ident, ParseError, Parser, TableRef, Term, Using};
+use crate::crud::operation::{ttl, using_bind_markers, BindMarker, Operation};
+use catalytic::query_metadata::{ColumnInQuery, Lwt, QueryType, Ttl};
 
-pub struct Insert;
+#[derive(Debug, Clone, PartialEq)]
+pub struct Insert {
+    pub table: TableRef,
+    pub columns: Vec<Ident>,
+    /// Has the same length as columns
+    pub values: Vec<Term>,
+    pub if_not_exists: bool,
+    pub using: Option<Using>,
+}
 
-impl Operation for Insert {
-    fn crud_query_start(&self) -> &'static str {
-        "insert"
+impl Insert {
+    pub fn parse(parser: &mut Parser) -> Result<Insert, ParseError> {
+        parser.expect_keyword("insert")?;
+        parser.expect_keyword("into")?;
+
+        let table = parser.table()?;
+
+        parser.expect_symbol("(")?;
+
+        let columns = parser.comma_separated(|p| p.ident("a column name"))?;
+
+        parser.expect_symbol(")")?;
+        parser.expect_keyword("values")?;
+
+        let start = parser.expect_symbol("(")?;
+        let values = parser.comma_separated(Parser::value)?;
+        let end = parser.expect_symbol(")")?;
+
+        if columns.len() != values.len() {
+            return Err(ParseError::new(
+                format!("Expected {} values, found {}", columns.len(), values.len()),
+                start.start..end.end,
+            ));
+        }
+
+        Ok(Insert {
+            table,
+            columns,
+            values,
+            if_not_exists: parser.if_not_exists()?,
+            using: parser.using()?,
+        })
     }
+}
 
-    fn table_name_after(&self) -> &'static str {
-        "into "
+impl Operation for Insert {
+    fn table(&self) -> &TableRef {
+        &self.table
     }
 
-    fn column_clauses(&self, query: &str) -> Vec<ColumnInQuery> {
-        let clause = self.in_between_parentheses(query);
-        let new_starting_point =
-            &query[query.find(')').expect("Can not find trailing ')' in query") + 1..];
-        let values = self.in_between_parentheses(new_starting_point);
-        let columns = self.split(clause);
-        let values = self.split(values);
-
-        columns
-            .into_iter()
-            .enumerate()
-            .map(|(index, val)| ColumnInQuery {
-                column_name: val,
-                parameterized: values[index].as_str() == "?",
+    fn columns(&self) -> Vec<ColumnInQuery> {
+        self.columns
+            .iter()
+            .zip(&self.values)
+            .map(|(column, value)| ColumnInQuery {
+                column_name: column.name.clone(),
+                parameterized: value.is_bind_marker(),
                 uses_in_value: false,
                 is_part_of_where_clause: false,
             })
             .collect()
     }
 
-    fn query_type(&self, _query: &str, full_pk: bool) -> QueryType {
-        assert!(full_pk);
+    fn bind_markers(&self) -> Vec<BindMarker> {
+        self.values
+            .iter()
+            .filter(|v| v.is_bind_marker())
+            .map(|_| BindMarker::Column)
+            .chain(using_bind_markers(&self.using))
+            .collect()
+    }
 
-        QueryType::InsertUnique
+    fn restricted_columns(&self) -> Vec<&str> {
+        self.columns.iter().map(|c| c.name.as_str()).collect()
     }
-}
 
-impl Insert {
-    /// Finds the content between '(' and ')'
-    fn in_between_parentheses<'a>(&self, q: &'a str) -> &'a str {
-        let error = |c: char| format!("Can not find '{}' in query {}", c, q);
-        let find = |c: char| q.find(c).unwrap_or_else(|| panic!("{}", error(c)));
-        let opening = find('(');
-        let closing = find(')');
-
-        &q[opening + 1..closing]
+    fn ttl(&self) -> Option<Ttl> {
+        ttl(&self.using)
     }
 
-    fn split(&self, val: &str) -> Vec<String> {
-        val.split(", ").map(|c| c.to_string()).collect()
+    fn lwt(&self) -> Option<Lwt> {
+        if self.if_not_exists {
+            Some(Lwt::IfNotExists)
+        } else {
+            None
+        }
+    }
+
+    fn query_type(&self, full_pk: bool) -> Result<QueryType, ParseError> {
+        if !full_pk {
+            return Err(ParseError::new(
+                "Insert query is missing primary key columns",
+                self.table.name.span.clone(),
+            ));
+        }
+
+        Ok(QueryType::InsertUnique)
     }
 }
 
-#[test]
-fn test_columns_select_clause() {
-    let insert = Insert;
-    let q = insert.column_clauses("insert into table (a) values (1)");
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    assert_eq!(q.len(), 1);
-    assert_eq!("a", &q[0].column_name);
-    assert!(!q[0].parameterized);
+    fn parse(query: &str) -> Result<Insert, ParseError> {
+        let mut parser = Parser::new(query)?;
+        let insert = Insert::parse(&mut parser)?;
 
-    let q = insert.column_clauses("insert into table (a, b, c) values (1, ?, 3)");
+        parser.end()?;
+
+        Ok(insert)
+    }
 
-    assert_eq!(q.len(), 3);
-    assert_eq!("a", &q[0].column_name);
-    assert_eq!("b", &q[1].column_name);
-    assert_eq!("c", &q[2].column_name);
-    assert!(!q[0].parameterized);
-    assert!(q[1].parameterized);
-    assert!(!q[2].parameterized);
+    #[test]
+    fn test_columns_select_clause() {
+        let q = parse("insert into table_name (a) values (1)")
+            .unwrap()
+            .columns();
+
+        assert_eq!(q.len(), 1);
+        assert_eq!("a", &q[0].column_name);
+        assert!(!q[0].parameterized);
+
+        let q = parse("INSERT INTO table_name(a,b,c)\nVALUES (1,?,'3')")
+            .unwrap()
+            .columns();
+
+        assert_eq!(q.len(), 3);
+        assert_eq!("a", &q[0].column_name);
+        assert_eq!("b", &q[1].column_name);
+        assert_eq!("c", &q[2].column_name);
+        assert!(!q[0].parameterized);
+        assert!(q[1].parameterized);
+        assert!(!q[2].parameterized);
+    }
+
+    #[test]
+    fn test_ttl() {
+        let insert = parse("insert into t (a, b) values (?, ?) using ttl ?").unwrap();
+
+        assert_eq!(Some(Ttl::Parameterized), insert.ttl());
+        assert_eq!(
+            vec![BindMarker::Column, BindMarker::Column, BindMarker::Ttl],
+            insert.bind_markers()
+        );
+
+        let insert = parse("insert into t (a) values (1) using ttl 102").unwrap();
+
+        assert_eq!(Some(Ttl::Fixed(102)), insert.ttl());
+        assert!(insert.bind_markers().is_empty());
+        assert!(parse("insert into t (a) values (1)")
+            .unwrap()
+            .ttl()
+            .is_none());
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let insert = parse("insert into t (a) values (?) using ttl ? and timestamp ?").unwrap();
+
+        assert_eq!(Some(Ttl::Parameterized), insert.ttl());
+        assert_eq!(
+            vec![BindMarker::Column, BindMarker::Ttl, BindMarker::Timestamp],
+            insert.bind_markers()
+        );
+
+        let insert = parse("insert into t (a) values (?) using timestamp 1").unwrap();
+
+        assert!(insert.ttl().is_none());
+        assert_eq!(vec![BindMarker::Column], insert.bind_markers());
+    }
+
+    #[test]
+    fn test_if_not_exists() {
+        let insert = parse("insert into t (a) values (?) if not exists using ttl ?").unwrap();
+
+        assert_eq!(Some(Lwt::IfNotExists), insert.lwt());
+        assert_eq!(Some(Ttl::Parameterized), insert.ttl());
+        assert!(parse("insert into t (a) values (?)")
+            .unwrap()
+            .lwt()
+            .is_none());
+        assert_eq!(
+            "Expected 'not', found 'exists'",
+            parse("insert into t (a) values (?) if exists")
+                .unwrap_err()
+                .message
+        );
+    }
+
+    #[test]
+    fn test_value_count() {
+        let error = parse("insert into t (a, b) values (1)").unwrap_err();
+
+        assert_eq!("Expected 2 values, found 1", error.message);
+        assert_eq!(28..31, error.span);
+    }
 }
@@ -1,40 +1,144 @@
-use crate::crud::delete::Delete;
-use crate::crud::insert::Insert;
-use crate::crud::select::Select;
-use crate::crud::truncate::Truncate;
-use crate::crud::update::Update;
-use catalytic::query_metadata::{ColumnInQuery, QueryType};
+use crate::cql::{
+    Condition, Operator, ParseError, Relation, Span, TableRef, Term, Using, UsingOption,
+};
+use catalytic::query_metadata::{ColumnInQuery, Lwt, QueryType, Ttl};
 
 /// Trait that is implemented for every CRUD operation
 pub trait Operation {
-    /// Is either insert, select, update or delete
-    /// Based on this value, the correct CRUD operation can be determined from a query
-    fn crud_query_start(&self) -> &'static str;
+    /// The table to execute the CRUD operation for
+    fn table(&self) -> &TableRef;
 
-    /// Is either from, into or update
-    /// Based on this value, the table name can be determined to execute the CRUD operation for
-    fn table_name_after(&self) -> &'static str;
+    /// Determines all the columns that are used in the query, in the order they appear in the query
+    fn columns(&self) -> Vec<ColumnInQuery>;
 
-    /// Determines all the columns that are used in the query
-    fn column_clauses(&self, query: &str) -> Vec<ColumnInQuery>;
+    /// Determines what is bound to the question marks, in the order they appear in the query
+    fn bind_markers(&self) -> Vec<BindMarker>;
+
+    /// The columns that are restricted to a single value
+    /// If all the primary key columns are in here, the query is executed for a single row
+    fn restricted_columns(&self) -> Vec<&str>;
+
+    /// The TTL of the query if provided
+    fn ttl(&self) -> Option<Ttl> {
+        None
+    }
+
+    /// Only true if the query is limited
+    fn limited(&self) -> bool {
+        false
+    }
+
+    /// The condition if the query is a lightweight transaction
+    fn lwt(&self) -> Option<Lwt> {
+        None
+    }
+
+    /// Only true if the query ends with 'allow filtering'
+    fn allow_filtering(&self) -> bool {
+        false
+    }
 
     /// Determines the query type for the query
     /// parameter full_pk means if the query parameter contains the full primary key
-    fn query_type(&self, query: &str, full_pk: bool) -> QueryType;
-}
-
-/// Dynamically choose from a given query the correct CRUD type
-pub fn find_operation(query: &str) -> Box<dyn Operation> {
-    let cruds: Vec<Box<dyn Operation>> = vec![
-        Box::new(Select),
-        Box::new(Update),
-        Box::new(Insert),
-        Box::new(Delete),
-        Box::new(Truncate),
-    ];
-
-    cruds
-        .into_iter()
-        .find(|c| query.starts_with(c.crud_query_start()))
-        .expect("Queries should start with select, update, delete, insert or truncate")
+    /// Fails if the operation is not supported for the restricted columns
+    fn query_type(&self, full_pk: bool) -> Result<QueryType, ParseError>;
+}
+
+/// The value a question mark is bound to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BindMarker {
+    /// The next parameterized column of `Operation::columns`
+    Column,
+    Ttl,
+    Timestamp,
+    Limit,
+}
+
+/// The columns in the where clause
+pub fn where_columns(where_clause: &[Relation]) -> impl Iterator<Item = ColumnInQuery> + '_ {
+    where_clause.iter().map(|r| ColumnInQuery {
+        column_name: r.column.name.clone(),
+        parameterized: r.value.is_bind_marker(),
+        uses_in_value: r.operator == Operator::In,
+        is_part_of_where_clause: true,
+    })
+}
+
+/// The span of the where clause, or of the table if there is no where clause
+pub fn where_span(where_clause: &[Relation], table: &TableRef) -> Span {
+    match (where_clause.first(), where_clause.last()) {
+        (Some(first), Some(last)) => first.column.span.start..last.value.span().end,
+        _ => table.name.span.clone(),
+    }
+}
+
+/// The bind markers in the where clause
+pub fn where_bind_markers(where_clause: &[Relation]) -> impl Iterator<Item = BindMarker> + '_ {
+    where_clause
+        .iter()
+        .filter(|r| r.value.is_bind_marker())
+        .map(|_| BindMarker::Column)
+}
+
+/// The columns in the where clause that are compared with '='
+pub fn where_restricted_columns(where_clause: &[Relation]) -> Vec<&str> {
+    where_clause
+        .iter()
+        .filter(|r| r.operator == Operator::Eq)
+        .map(|r| r.column.name.as_str())
+        .collect()
+}
+
+/// The bind markers of the using clause
+pub fn using_bind_markers(using: &Option<Using>) -> impl Iterator<Item = BindMarker> + '_ {
+    using
+        .iter()
+        .flat_map(|u| u.options.iter())
+        .filter_map(|o| match o {
+            UsingOption::Ttl(t) if t.is_bind_marker() => Some(BindMarker::Ttl),
+            UsingOption::Timestamp(t) if t.is_bind_marker() => Some(BindMarker::Timestamp),
+            _ => None,
+        })
+}
+
+pub fn ttl(using: &Option<Using>) -> Option<Ttl> {
+    using.as_ref().and_then(Using::ttl).map(|t| match t {
+        Term::Constant(c) => Ttl::Fixed(c.text.parse().unwrap()),
+        _ => Ttl::Parameterized,
+    })
+}
+
+/// The columns in the 'if' clause, these are not part of the where clause
+pub fn condition_columns(
+    condition: &Option<Condition>,
+) -> impl Iterator<Item = ColumnInQuery> + '_ {
+    condition_relations(condition).map(|r| ColumnInQuery {
+        column_name: r.column.name.clone(),
+        parameterized: r.value.is_bind_marker(),
+        uses_in_value: r.operator == Operator::In,
+        is_part_of_where_clause: false,
+    })
+}
+
+/// The bind markers in the 'if' clause
+pub fn condition_bind_markers(
+    condition: &Option<Condition>,
+) -> impl Iterator<Item = BindMarker> + '_ {
+    condition_relations(condition)
+        .filter(|r| r.value.is_bind_marker())
+        .map(|_| BindMarker::Column)
+}
+
+pub fn lwt(condition: &Option<Condition>) -> Option<Lwt> {
+    condition.as_ref().map(|c| match c {
+        Condition::IfExists => Lwt::IfExists,
+        Condition::If(_) => Lwt::IfCondition,
+    })
+}
+
+fn condition_relations(condition: &Option<Condition>) -> impl Iterator<Item = &Relation> {
+    match condition {
+        Some(Condition::If(relations)) => relations.iter(),
+        _ => [].iter(),
+    }
 }
@@ -1,82 +1,425 @@
-use crate::crud::operation::Operation;
+use crate::cql::{Ident, Ordering, ParseError, Parser, Relation, Span, TableRef, Term};
+use crate::crud::operation::{
+    where_bind_markers, where_columns, where_restricted_columns, BindMarker, Operation,
+};
 use catalytic::query_metadata::{ColumnInQuery, QueryType};
 
-pub struct Select;
+#[derive(Debug, Clone, PartialEq)]
+pub struct Select {
+    pub selection: Selection,
+    pub table: TableRef,
+    pub where_clause: Vec<Relation>,
+    pub order_by: Vec<Ordering>,
+    /// Either a bind marker or an integer constant
+    pub limit: Option<Term>,
+    /// The span of the trailing 'allow filtering', if present
+    pub allow_filtering: Option<Span>,
+}
 
-impl Operation for Select {
-    fn crud_query_start(&self) -> &'static str {
-        "select"
+/// What is selected in a select query
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    /// 'select *', the span points to the '*'
+    Wildcard(Span),
+    /// 'select count(*)' or 'select count(1)'
+    Count(Span),
+    Columns(Vec<Selector>),
+}
+
+/// A selected column, optionally with an alias ('select a as b')
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    pub column: Ident,
+    /// Set if the metadata of the column is selected instead of the value, e.g. 'writetime(a)'
+    pub metadata: Option<MetadataFunction>,
+    pub alias: Option<Ident>,
+}
+
+/// The functions that select metadata of a cell
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MetadataFunction {
+    /// The write timestamp in microseconds
+    Writetime,
+    /// The remaining time to live in seconds
+    Ttl,
+}
+
+impl Selector {
+    /// The name of the field the selected value is mapped to
+    /// This is the alias if present, else the column name prefixed with the metadata function
+    pub fn field_name(&self) -> String {
+        if let Some(alias) = &self.alias {
+            return alias.name.clone();
+        }
+
+        match self.metadata {
+            None => self.column.name.clone(),
+            Some(MetadataFunction::Writetime) => format!("writetime_{}", self.column.name),
+            Some(MetadataFunction::Ttl) => format!("ttl_{}", self.column.name),
+        }
     }
+}
+
+impl Select {
+    pub fn parse(parser: &mut Parser) -> Result<Select, ParseError> {
+        parser.expect_keyword("select")?;
 
-    fn table_name_after(&self) -> &'static str {
-        "from "
+        let selection = Select::selection(parser)?;
+
+        parser.expect_keyword("from")?;
+
+        Ok(Select {
+            selection,
+            table: parser.table()?,
+            where_clause: parser.where_clause()?,
+            order_by: parser.order_by()?,
+            limit: parser.limit()?,
+            allow_filtering: Select::allow_filtering(parser)?,
+        })
     }
 
-    fn column_clauses(&self, query: &str) -> Vec<ColumnInQuery> {
-        let select = "select ";
-        let from_index = query.find(" from").unwrap();
-        let select_index = query.find(select).expect(query);
-        let select_clause = &query[select_index + select.len()..from_index];
+    fn allow_filtering(parser: &mut Parser) -> Result<Option<Span>, ParseError> {
+        if !parser.peek_keyword("allow") {
+            return Ok(None);
+        }
+
+        let start = parser.expect_keyword("allow")?;
+        let end = parser.expect_keyword("filtering")?;
 
-        if select_clause.contains("count(*)") {
-            return vec![];
+        Ok(Some(start.start..end.end))
+    }
+
+    fn selection(parser: &mut Parser) -> Result<Selection, ParseError> {
+        if parser.peek_symbol("*") {
+            return Ok(Selection::Wildcard(parser.expect_symbol("*")?));
         }
 
-        select_clause
-            .split(", ")
-            .map(|c| {
-                let alias_split = c.split(" as ").collect::<Vec<_>>();
+        if parser.peek_function("count") {
+            let start = parser.current_span();
+
+            parser.eat_keyword("count");
+            parser.expect_symbol("(")?;
 
-                ColumnInQuery {
-                    column_name: alias_split.first().unwrap().to_string(),
+            if !parser.eat_symbol("*") {
+                let error = parser.expected("'*' or '1'");
+
+                if !parser.term().map_or(false, |t| t.is_constant("1")) {
+                    return Err(error);
+                }
+            }
+
+            let end = parser.expect_symbol(")")?;
+
+            if parser.eat_keyword("as") {
+                parser.ident("an alias")?;
+            }
+
+            return Ok(Selection::Count(start.start..end.end));
+        }
+
+        let selectors = parser.comma_separated(|p| {
+            let metadata = if p.eat_function("writetime") {
+                Some(MetadataFunction::Writetime)
+            } else if p.eat_function("ttl") {
+                Some(MetadataFunction::Ttl)
+            } else {
+                None
+            };
+            let column = p.ident("a column name")?;
+
+            if metadata.is_some() {
+                p.expect_symbol(")")?;
+            }
+
+            let alias = if p.eat_keyword("as") {
+                Some(p.ident("an alias")?)
+            } else {
+                None
+            };
+
+            Ok(Selector {
+                column,
+                metadata,
+                alias,
+            })
+        })?;
+
+        Ok(Selection::Columns(selectors))
+    }
+
+    /// The selectors of which the value of the column is selected
+    pub fn value_selectors(&self) -> Vec<&Selector> {
+        self.selectors()
+            .iter()
+            .filter(|s| s.metadata.is_none())
+            .collect()
+    }
+
+    /// The 'writetime(column)' and 'ttl(column)' selectors
+    pub fn metadata_selectors(&self) -> Vec<&Selector> {
+        self.selectors()
+            .iter()
+            .filter(|s| s.metadata.is_some())
+            .collect()
+    }
+
+    /// Only true if all the metadata selectors come after the value selectors
+    pub fn metadata_is_selected_last(&self) -> bool {
+        self.selectors()
+            .iter()
+            .skip_while(|s| s.metadata.is_none())
+            .all(|s| s.metadata.is_some())
+    }
+
+    /// The selected columns, empty for 'select *' and 'select count(*)'
+    pub fn selectors(&self) -> &[Selector] {
+        match &self.selection {
+            Selection::Columns(selectors) => selectors,
+            Selection::Wildcard(_) | Selection::Count(_) => &[],
+        }
+    }
+}
+
+impl Operation for Select {
+    fn table(&self) -> &TableRef {
+        &self.table
+    }
+
+    fn columns(&self) -> Vec<ColumnInQuery> {
+        let selected = match &self.selection {
+            Selection::Wildcard(_) | Selection::Count(_) => vec![],
+            Selection::Columns(selectors) => selectors
+                .iter()
+                .map(|s| ColumnInQuery {
+                    column_name: s.column.name.clone(),
                     parameterized: false,
                     uses_in_value: false,
                     is_part_of_where_clause: false,
-                }
-            })
+                })
+                .collect(),
+        };
+
+        selected
+            .into_iter()
+            .chain(where_columns(&self.where_clause))
+            .collect()
+    }
+
+    fn bind_markers(&self) -> Vec<BindMarker> {
+        where_bind_markers(&self.where_clause)
+            .chain(
+                self.limit
+                    .iter()
+                    .filter(|l| l.is_bind_marker())
+                    .map(|_| BindMarker::Limit),
+            )
             .collect()
     }
 
-    fn query_type(&self, query: &str, full_pk: bool) -> QueryType {
-        assert!(!query.contains(" in (?)"), "An in query should invoked by doing 'in ?', not 'in (?)', since this always select only 0/1 rows");
+    fn restricted_columns(&self) -> Vec<&str> {
+        where_restricted_columns(&self.where_clause)
+    }
+
+    fn limited(&self) -> bool {
+        self.limit.is_some()
+    }
+
+    fn allow_filtering(&self) -> bool {
+        self.allow_filtering.is_some()
+    }
+
+    fn query_type(&self, full_pk: bool) -> Result<QueryType, ParseError> {
+        let query_is_limited_by_one = self.limit.as_ref().map_or(false, |l| l.is_constant("1"));
+
+        if let Selection::Count(span) = &self.selection {
+            if let Some(limit) = &self.limit {
+                return Err(ParseError::new(
+                    "Both using count and limit is strange",
+                    limit.span(),
+                ));
+            }
+
+            if full_pk {
+                return Err(ParseError::new(
+                    "Counting a query which only returns 0 or 1 row",
+                    span.clone(),
+                ));
+            }
+        }
 
-        let query_is_limited_by_one = query.ends_with(" limit 1");
-        let counts = query.contains("count(");
+        let query_type = if full_pk {
+            if query_is_limited_by_one {
+                return Err(ParseError::new(
+                    "The query returns 0 or 1 row, 'limit 1' is not needed",
+                    self.limit.as_ref().unwrap().span(),
+                ));
+            }
 
-        if full_pk {
-            assert!(!query_is_limited_by_one);
-            assert!(
-                !counts,
-                "Counting a query which only returns 0 or 1 row, query: {}",
-                query
-            );
             QueryType::SelectUnique
         } else if query_is_limited_by_one {
             QueryType::SelectUniqueByLimit
-        } else if counts {
+        } else if matches!(self.selection, Selection::Count(_)) {
             QueryType::SelectCount
         } else {
             QueryType::SelectMultiple
-        }
+        };
+
+        Ok(query_type)
     }
 }
 
-#[test]
-fn test_columns_select_clause() {
-    let select = Select;
-    let q = select.column_clauses("select a from table");
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(query: &str) -> Select {
+        let mut parser = Parser::new(query).unwrap();
+        let select = Select::parse(&mut parser).unwrap();
+
+        parser.end().unwrap();
+
+        select
+    }
+
+    #[test]
+    fn test_columns_select_clause() {
+        let q = parse("select a from table_name").columns();
 
-    assert_eq!(1, q.len());
+        assert_eq!(1, q.len());
 
-    let q = select.column_clauses("select 1 from table");
+        let q = parse("SELECT a, \"B\" AS j,\n  c FROM table_name").columns();
+
+        assert_eq!(3, q.len());
+        assert_eq!("a", &q[0].column_name);
+        assert_eq!("B", &q[1].column_name);
+        assert_eq!("c", &q[2].column_name);
+
+        assert!(parse("select count(*) from table_name")
+            .columns()
+            .is_empty());
+        assert!(parse("select count(1) as c from table_name")
+            .columns()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_extract_columns() {
+        let select = parse(
+            "select a, b as c, d from ks.table_name where a = 1 and b > 2 and e in ? limit ?",
+        );
+        let c = select.columns();
+
+        assert_eq!("ks", &select.table.keyspace.unwrap().name);
+        assert_eq!("table_name", &select.table.name.name);
+        assert_eq!(6, c.len());
+        assert_eq!("a", &c[0].column_name);
+        assert_eq!("b", &c[1].column_name);
+        assert_eq!("d", &c[2].column_name);
+        assert_eq!("a", &c[3].column_name);
+        assert_eq!("b", &c[4].column_name);
+        assert_eq!("e", &c[5].column_name);
+        assert!(!c[3].parameterized);
+        assert!(c[5].parameterized);
+        assert!(c[5].uses_in_value);
+    }
 
-    assert_eq!(1, q.len());
-    assert_eq!("1", &q[0].column_name);
+    #[test]
+    fn test_bind_markers() {
+        let select = parse("select * from t where a = ? and b = 1 and c in ? limit ?");
 
-    let q = select.column_clauses("select 1, 2 as j, 3 from table");
+        assert_eq!(
+            vec![BindMarker::Column, BindMarker::Column, BindMarker::Limit],
+            select.bind_markers()
+        );
+        assert_eq!(vec!["a", "b"], select.restricted_columns());
 
-    assert_eq!(3, q.len());
-    assert_eq!("1", &q[0].column_name);
-    assert_eq!("2", &q[1].column_name);
-    assert_eq!("3", &q[2].column_name);
+        let select = parse("select * from t where a = :a and c in :c limit :limit");
+
+        assert_eq!(
+            vec![BindMarker::Column, BindMarker::Column, BindMarker::Limit],
+            select.bind_markers()
+        );
+    }
+
+    #[test]
+    fn test_metadata() {
+        let select = parse("select a, b, WRITETIME(b), ttl(b) as t from table_name");
+        let metadata = select.metadata_selectors();
+
+        assert_eq!(2, select.value_selectors().len());
+        assert_eq!(2, metadata.len());
+        assert_eq!(Some(MetadataFunction::Writetime), metadata[0].metadata);
+        assert_eq!("b", &metadata[1].column.name);
+        assert_eq!(Some(MetadataFunction::Ttl), metadata[1].metadata);
+        assert!(select.metadata_is_selected_last());
+        assert_eq!(4, select.columns().len());
+
+        // A column can be named ttl
+        let select = parse("select ttl, writetime(ttl), a from table_name");
+
+        assert_eq!("ttl", &select.value_selectors()[0].column.name);
+        assert!(!select.metadata_is_selected_last());
+    }
+
+    #[test]
+    fn test_field_names() {
+        let select = parse("select a, b as c, writetime(a), ttl(a) as t from table_name");
+        let field_names = select
+            .selectors()
+            .iter()
+            .map(|s| s.field_name())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["a", "c", "writetime_a", "t"], field_names);
+        assert!(parse("select * from table_name").selectors().is_empty());
+    }
+
+    #[test]
+    fn test_order_by() {
+        let select = parse("select * from person where name = ? order by age desc limit 1");
+
+        assert!(select.order_by[0].descending);
+        assert_eq!(
+            QueryType::SelectUniqueByLimit,
+            select.query_type(false).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_allow_filtering() {
+        let select = parse("select * from t where e = ? limit 10 ALLOW FILTERING");
+
+        assert_eq!(Some(37..52), select.allow_filtering);
+        assert!(Operation::allow_filtering(&select));
+        assert!(!Operation::allow_filtering(&parse("select * from t")));
+
+        let mut parser = Parser::new("select * from t allow").unwrap();
+
+        assert!(Select::parse(&mut parser).is_err());
+    }
+
+    #[test]
+    fn test_query_type_errors() {
+        let error = parse("select count(*) from t limit 2")
+            .query_type(false)
+            .unwrap_err();
+
+        assert_eq!(29..30, error.span);
+
+        let error = parse("select count(*) from t where a = ?")
+            .query_type(true)
+            .unwrap_err();
+
+        assert_eq!(
+            "Counting a query which only returns 0 or 1 row",
+            error.message
+        );
+        assert_eq!(7..15, error.span);
+
+        let error = parse("select * from t where a = ? limit 1")
+            .query_type(true)
+            .unwrap_err();
+
+        assert_eq!(34..35, error.span);
+    }
 }
@@ -1,48 +1,49 @@
-use crate::crud::operation::Operation;
+use crate::cql::{ParseError, Parser, TableRef};
+use crate::crud::operation::{BindMarker, Operation};
 use catalytic::query_metadata::{ColumnInQuery, QueryType};
 
-pub struct Truncate;
+#[derive(Debug, Clone, PartialEq)]
+pub struct Truncate {
+    pub table: TableRef,
+}
 
-impl Operation for Truncate {
-    fn crud_query_start(&self) -> &'static str {
-        "truncate"
-    }
+impl Truncate {
+    pub fn parse(parser: &mut Parser) -> Result<Truncate, ParseError> {
+        parser.expect_keyword("truncate")?;
+        parser.eat_keyword("table");
 
-    fn table_name_after(&self) -> &'static str {
-        " "
+        Ok(Truncate {
+            table: parser.table()?,
+        })
     }
+}
 
-    fn column_clauses(&self, query: &str) -> Vec<ColumnInQuery> {
-        // Check if a table is followed after 'truncate' and nothing more
-        // A check on a single whitespace is enough
-        let table = &query[query.find(' ').unwrap() + 1..];
-        // If there is another whitespace, it is an invalid query
-        assert!(
-            !table.contains(' '),
-            "This truncate query contains multiple whitespaces, but only 1 is allowed: '{}'",
-            query
-        );
+impl Operation for Truncate {
+    fn table(&self) -> &TableRef {
+        &self.table
+    }
 
+    fn columns(&self) -> Vec<ColumnInQuery> {
         // No columns are present in a truncate query
         vec![]
     }
 
-    fn query_type(&self, _query: &str, full_pk: bool) -> QueryType {
-        assert!(!full_pk);
+    fn bind_markers(&self) -> Vec<BindMarker> {
+        vec![]
+    }
 
-        QueryType::Truncate
+    fn restricted_columns(&self) -> Vec<&str> {
+        vec![]
     }
-}
 
-#[test]
-fn valid() {
-    let select = Truncate;
-    let _ = select.column_clauses("truncate my_table");
-}
+    fn query_type(&self, full_pk: bool) -> Result<QueryType, ParseError> {
+        if full_pk {
+            return Err(ParseError::new(
+                "A table without primary key columns can not be truncated",
+                self.table.name.span.clone(),
+            ));
+        }
 
-#[test]
-#[should_panic]
-fn invalid() {
-    let select = Truncate;
-    let _ = select.column_clauses("truncate my_table where a = 1");
+        Ok(QueryType::Truncate)
+    }
 }
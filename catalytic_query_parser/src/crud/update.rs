@@ -1,65 +1,351 @@
-use crate::crud::operation::Operation;
-use catalytic::query_metadata::{ColumnInQuery, QueryType};
+use crate::cql::{Condition, Ident, ParseError, Parser, Relation, TableRef, Term, Using};
+use crate::crud::operation::{
+    condition_bind_markers, condition_columns, lwt, ttl, using_bind_markers, where_bind_markers,
+    where_columns, where_restricted_columns, where_span, BindMarker, Operation,
+};
+use catalytic::query_metadata::{ColumnInQuery, Lwt, QueryType, Ttl};
 
-pub struct Update;
+#[derive(Debug, Clone, PartialEq)]
+pub struct Update {
+    pub table: TableRef,
+    pub using: Option<Using>,
+    pub assignments: Vec<Assignment>,
+    pub where_clause: Vec<Relation>,
+    pub condition: Option<Condition>,
+}
 
-impl Operation for Update {
-    fn crud_query_start(&self) -> &'static str {
-        "update"
-    }
+/// A single assignment in the set clause, e.g. 'a = ?', 'c = c + ?' or 'l = ? + l'
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    pub column: Ident,
+    pub operation: AssignmentOperation,
+    pub value: Term,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssignmentOperation {
+    /// 'a = value'
+    Set,
+    /// 'a = a + value', increments a counter or adds the elements to a collection
+    Add,
+    /// 'a = a - value', decrements a counter or removes the elements (or map keys) from a collection
+    Remove,
+    /// 'l = value + l', only for lists
+    Prepend,
+}
+
+impl Assignment {
+    fn parse(parser: &mut Parser) -> Result<Assignment, ParseError> {
+        let column = parser.ident("a column name")?;
+
+        parser.expect_symbol("=")?;
+
+        if parser.peek_ident(&column.name) {
+            parser.ident("a column name")?;
+
+            let operation = if parser.eat_symbol("+") {
+                AssignmentOperation::Add
+            } else if parser.eat_symbol("-") {
+                AssignmentOperation::Remove
+            } else {
+                return Err(parser.expected("'+' or '-'"));
+            };
 
-    fn table_name_after(&self) -> &'static str {
-        "update "
+            return Ok(Assignment {
+                column,
+                operation,
+                value: parser.value()?,
+            });
+        }
+
+        let value = parser.value()?;
+
+        if !parser.eat_symbol("+") {
+            return Ok(Assignment {
+                column,
+                operation: AssignmentOperation::Set,
+                value,
+            });
+        }
+
+        if !parser.peek_ident(&column.name) {
+            return Err(parser.expected(&format!("'{}'", column.name)));
+        }
+
+        parser.ident("a column name")?;
+
+        Ok(Assignment {
+            column,
+            operation: AssignmentOperation::Prepend,
+            value,
+        })
     }
+}
+
+impl Update {
+    pub fn parse(parser: &mut Parser) -> Result<Update, ParseError> {
+        parser.expect_keyword("update")?;
+
+        let table = parser.table()?;
+        let using = parser.using()?;
 
-    fn column_clauses(&self, query: &str) -> Vec<ColumnInQuery> {
-        let s = " set ";
-        let set = query.find(s).expect("Could not find 'set' in query");
-        let update = &query[set + s.len()..];
+        parser.expect_keyword("set")?;
 
-        update
-            .split(", ")
-            .map(|u| {
-                let split = u.split(" = ").collect::<Vec<_>>();
+        let assignments = parser.comma_separated(Assignment::parse)?;
+
+        // Updates are always on full primary key
+        if !parser.peek_keyword("where") {
+            return Err(parser.expected("'where'"));
+        }
 
-                ColumnInQuery {
-                    column_name: split[0].to_string(),
-                    parameterized: split[1] == "?",
-                    uses_in_value: false,
-                    is_part_of_where_clause: false,
-                }
+        Ok(Update {
+            table,
+            using,
+            assignments,
+            where_clause: parser.where_clause()?,
+            condition: parser.condition()?,
+        })
+    }
+}
+
+impl Operation for Update {
+    fn table(&self) -> &TableRef {
+        &self.table
+    }
+
+    fn columns(&self) -> Vec<ColumnInQuery> {
+        self.assignments
+            .iter()
+            .map(|a| ColumnInQuery {
+                column_name: a.column.name.clone(),
+                parameterized: a.value.is_bind_marker(),
+                uses_in_value: false,
+                is_part_of_where_clause: false,
             })
+            .chain(where_columns(&self.where_clause))
+            .chain(condition_columns(&self.condition))
             .collect()
     }
 
-    fn query_type(&self, _query: &str, full_pk: bool) -> QueryType {
+    fn bind_markers(&self) -> Vec<BindMarker> {
+        using_bind_markers(&self.using)
+            .chain(
+                self.assignments
+                    .iter()
+                    .filter(|a| a.value.is_bind_marker())
+                    .map(|_| BindMarker::Column),
+            )
+            .chain(where_bind_markers(&self.where_clause))
+            .chain(condition_bind_markers(&self.condition))
+            .collect()
+    }
+
+    fn restricted_columns(&self) -> Vec<&str> {
+        where_restricted_columns(&self.where_clause)
+    }
+
+    fn ttl(&self) -> Option<Ttl> {
+        ttl(&self.using)
+    }
+
+    fn lwt(&self) -> Option<Lwt> {
+        lwt(&self.condition)
+    }
+
+    fn query_type(&self, full_pk: bool) -> Result<QueryType, ParseError> {
         // Updates are always on full primary key
-        assert!(full_pk);
+        if !full_pk {
+            return Err(ParseError::new(
+                "An update query should restrict all the primary key columns",
+                where_span(&self.where_clause, &self.table),
+            ));
+        }
 
-        QueryType::UpdateUnique
+        Ok(QueryType::UpdateUnique)
     }
 }
 
-#[test]
-fn test_columns_update_clause() {
-    let update = Update;
-    let q = update.column_clauses("update table set a = 1");
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(query: &str) -> Result<Update, ParseError> {
+        let mut parser = Parser::new(query)?;
+        let update = Update::parse(&mut parser)?;
+
+        parser.end()?;
+
+        Ok(update)
+    }
 
-    assert_eq!(1, q.len());
-    assert_eq!("a", &q[0].column_name);
+    #[test]
+    fn test_columns_update_clause() {
+        let q = parse("update table_name set a = 1 where b = 1")
+            .unwrap()
+            .columns();
 
-    let q = update.column_clauses("update table set a = 1, b = ?, c = 3");
+        assert_eq!(2, q.len());
+        assert_eq!("a", &q[0].column_name);
+        assert!(!q[0].is_part_of_where_clause);
+        assert!(q[1].is_part_of_where_clause);
 
-    assert_eq!(3, q.len());
-    assert_eq!("a", &q[0].column_name);
-    assert_eq!("b", &q[1].column_name);
-    assert_eq!("c", &q[2].column_name);
-    assert!(!q[0].parameterized);
-    assert!(q[1].parameterized);
-    assert!(!q[2].parameterized);
+        let q = parse("UPDATE table_name SET a=1, b=?,\n    c='3' WHERE d = ?")
+            .unwrap()
+            .columns();
 
-    let q = update.column_clauses("update table set a = ?, b = ?");
+        assert_eq!(4, q.len());
+        assert_eq!("a", &q[0].column_name);
+        assert_eq!("b", &q[1].column_name);
+        assert_eq!("c", &q[2].column_name);
+        assert!(!q[0].parameterized);
+        assert!(q[1].parameterized);
+        assert!(!q[2].parameterized);
 
-    assert!(q[0].parameterized);
-    assert!(q[1].parameterized);
+        let q = parse("update table_name set a = ?, b = ? where c = 1")
+            .unwrap()
+            .columns();
+
+        assert!(q[0].parameterized);
+        assert!(q[1].parameterized);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let update = parse("update t set c = c + ?, d = d - 1 where a = ?").unwrap();
+        let operations = update
+            .assignments
+            .iter()
+            .map(|a| a.operation)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![AssignmentOperation::Add, AssignmentOperation::Remove],
+            operations
+        );
+
+        let columns = update.columns();
+
+        assert_eq!(3, columns.len());
+        assert_eq!("c", &columns[0].column_name);
+        assert!(columns[0].parameterized);
+        assert_eq!("d", &columns[1].column_name);
+        assert!(!columns[1].parameterized);
+        assert_eq!(
+            vec![BindMarker::Column, BindMarker::Column],
+            update.bind_markers()
+        );
+    }
+
+    #[test]
+    fn test_collection_arithmetic() {
+        let operation = |query| parse(query).unwrap().assignments[0].operation;
+
+        assert_eq!(
+            AssignmentOperation::Add,
+            operation("update t set l = l + ? where a = ?")
+        );
+        assert_eq!(
+            AssignmentOperation::Remove,
+            operation("update t set s = s - {'a'} where a = ?")
+        );
+        assert_eq!(
+            AssignmentOperation::Prepend,
+            operation("update t set l = [1, 2] + l where a = ?")
+        );
+        assert_eq!(
+            AssignmentOperation::Prepend,
+            operation("update t set \"L\" = ? + \"L\" where a = ?")
+        );
+
+        let update = parse("update t set l = ? + l, s = s + ? where a = ?").unwrap();
+
+        assert_eq!(3, update.bind_markers().len());
+        assert!(update.columns()[0].parameterized);
+    }
+
+    #[test]
+    fn test_arithmetic_on_other_column() {
+        let error = parse("update t set c = ? + d where a = ?").unwrap_err();
+
+        assert_eq!("Expected 'c', found 'd'", error.message);
+        assert_eq!(21..22, error.span);
+
+        let error = parse("update t set c = c * 2 where a = ?").unwrap_err();
+
+        assert_eq!("Expected '+' or '-', found '*'", error.message);
+
+        let error = parse("update t set c = d + 1 where a = ?").unwrap_err();
+
+        assert_eq!("Expected a value, found 'd'", error.message);
+    }
+
+    #[test]
+    fn test_ttl_is_bound_first() {
+        let update = parse("update t using ttl ? set a = ? where b = ?").unwrap();
+
+        assert_eq!(Some(Ttl::Parameterized), update.ttl());
+        assert_eq!(
+            vec![BindMarker::Ttl, BindMarker::Column, BindMarker::Column],
+            update.bind_markers()
+        );
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let update = parse("update t using timestamp ? and ttl ? set a = ? where b = ?").unwrap();
+
+        assert_eq!(
+            vec![
+                BindMarker::Timestamp,
+                BindMarker::Ttl,
+                BindMarker::Column,
+                BindMarker::Column
+            ],
+            update.bind_markers()
+        );
+    }
+
+    #[test]
+    fn test_condition() {
+        let update = parse("update t set a = ? where b = ? if a = ? and c = 1").unwrap();
+        let columns = update.columns();
+
+        assert_eq!(Some(Lwt::IfCondition), update.lwt());
+        assert_eq!(4, columns.len());
+        assert_eq!("a", &columns[2].column_name);
+        assert!(columns[2].parameterized);
+        assert!(!columns[2].is_part_of_where_clause);
+        assert_eq!(3, update.bind_markers().len());
+        assert_eq!(vec!["b"], update.restricted_columns());
+
+        let update = parse("update t set a = ? where b = ? if exists").unwrap();
+
+        assert_eq!(Some(Lwt::IfExists), update.lwt());
+        assert_eq!(2, update.columns().len());
+    }
+
+    #[test]
+    fn test_missing_where() {
+        let error = parse("update t set a = 1").unwrap_err();
+
+        assert_eq!(
+            "Expected 'where', found the end of the query",
+            error.message
+        );
+    }
+
+    #[test]
+    fn test_query_type() {
+        let update = parse("update t set a = ? where b = ? and c > 1").unwrap();
+
+        assert_eq!(QueryType::UpdateUnique, update.query_type(true).unwrap());
+
+        let error = update.query_type(false).unwrap_err();
+
+        assert_eq!(
+            "An update query should restrict all the primary key columns",
+            error.message
+        );
+        assert_eq!(25..40, error.span);
+    }
 }
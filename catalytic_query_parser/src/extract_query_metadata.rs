@@ -1,104 +1,351 @@
-use crate::crud::{extract_columns, extract_table_name, find_operation};
+use crate::cql::{tokenize, Operator, ParseError, Relation, Span, TokenKind};
+use crate::crud::{
+    parse_statement, AssignmentOperation, BindMarker, Delete, Select, Selection, Statement, Update,
+};
 use catalytic::capitalizing::table_name_to_struct_name;
-use catalytic::env_property_reader::keyspace;
 use catalytic::query_metadata::{
-    query_columns, ColumnInQuery, ParameterizedColumnType, ParameterizedValue, QueryMetadata, Ttl,
+    ColumnInQuery, ParameterizedColumnType, ParameterizedValue, QueryMetadata,
 };
 use catalytic::runtime::{block_on, GLOBAL_CONNECTION};
-use catalytic::table_metadata::{ColumnInTable, ColumnType};
-use scylla::frame::value::SerializedValues;
-use std::collections::HashSet;
+use catalytic::schema_provider::{is_offline, schema_from_env};
+use catalytic::secondary_index::indexed_columns;
+use catalytic::table_metadata::{ColumnInTable, ColumnKind, ColumnType};
+use scylla::frame::value::{SerializedValues, Value, ValueTooBig};
+use std::convert::TryFrom;
 
 /// Extract the query meta data from a query
-pub fn extract_query_meta_data(query: impl AsRef<str>) -> QueryMetadata {
-    // Find the correct operation for the query
-    let crud = find_operation(query.as_ref());
-    let table_name = extract_table_name(&query, &*crud);
-    let columns = query_columns(table_name);
-
-    assert!(
-        !columns.is_empty(),
-        "Table '{}' in keyspace '{}' does not exists (or does not have columns, which is useless)",
-        table_name,
-        keyspace()
-    );
-
+/// The span of the error points to the offending part of the query
+pub fn extract_query_meta_data(query: impl AsRef<str>) -> Result<QueryMetadata, ParseError> {
     let query = query.as_ref();
-    let query = replace_select_wildcard(query, &columns);
-    // Before extracting the columns, remove the parameterized ttl from the query if it exists
-    let extracted_columns = extract_columns(&query, &*crud);
+    let statement = parse_statement(query)?;
+    let crud = statement.operation();
+    // The keyspace is not checked, all tables should be in the keyspace of the schema
+    let table = &crud.table().name;
+    let table_name = table.name.as_str();
+    let schema = schema_from_env();
+    let columns = schema.columns(table_name);
 
-    if query.starts_with("insert") && extracted_columns.len() != columns.len() {
-        panic!("Insert query is missing values");
+    if columns.is_empty() {
+        return Err(ParseError::new(
+            format!(
+                "Table '{}' in {} does not exists (or does not have columns, which is useless)",
+                table_name,
+                schema.location()
+            ),
+            table.span.clone(),
+        ));
     }
 
-    let ttl = extract_ttl(&query);
-    let mut parameterized_columns_types =
-        create_parameterized_column_types(&columns, &extracted_columns);
+    let extracted_columns = crud.columns();
 
-    if query.ends_with(" limit ?") {
-        // parameterized_columns_types does not contain the limit type, add it back
-        parameterized_columns_types.push(ParameterizedColumnType {
-            column_type: ColumnType::Int,
-            value: ParameterizedValue::Limit,
-        });
-    } else {
-        match ttl {
-            Some(ttl) if ttl == Ttl::Parameterized => {
-                parameterized_columns_types.push(ParameterizedColumnType {
-                    column_type: ColumnType::Int,
-                    value: ParameterizedValue::UsingTtl,
-                });
-            }
-            _ => {} // Do nothing
-        }
+    if matches!(statement, Statement::Insert(_)) && extracted_columns.len() != columns.len() {
+        return Err(ParseError::new(
+            "Insert query is missing values",
+            table.span.clone(),
+        ));
     }
 
-    // ColumnInTable can be reused in ranges, so filter duplicates
-    let unique_columns_where_clause = extracted_columns
-        .iter()
-        .map(|r| r.column_name.clone())
-        .collect::<HashSet<_>>();
+    let mut column_types = create_parameterized_column_types(query, &columns, &extracted_columns)?;
 
-    // For this variable a hashset is also used although it will not filter any elements
-    // but is used for comparing later on
-    let unique_columns = columns
-        .iter()
-        .filter(|r| r.kind().is_part_of_pk())
-        .map(|r| r.column_name.clone())
-        .collect::<HashSet<_>>();
-    let columns_in_where_all_pk = unique_columns_where_clause.eq(&unique_columns);
+    match &statement {
+        Statement::Update(update) => check_assignments(update, &columns, &mut column_types)?,
+        Statement::Delete(delete) => check_deleted_columns(delete, &columns, &mut column_types)?,
+        _ => {}
+    }
 
-    // Maybe a range is added to the last column, or an 'in' query
-    let where_pattern = " where ";
-    let is_selecting_unique = if let Some(start) = query.find(where_pattern) {
-        let end = query.find(" limit ").unwrap_or(query.len() - 1);
-        let slice = &query[start..end];
+    let mut column_types = column_types.into_iter();
 
-        slice.chars().filter(|i| i == &'=').count() == unique_columns.len()
-    } else {
-        query.ends_with("limit 1")
-    };
+    // With 'allow filtering' every restriction is accepted by the server
+    if let Statement::Select(select) = &statement {
+        if select.allow_filtering.is_none() {
+            check_restrictions(
+                &select.where_clause,
+                &columns,
+                &indexed_columns(schema, table_name),
+            )?;
+        }
+    }
 
-    let is_full_pk = columns_in_where_all_pk && is_selecting_unique;
+    // The bind markers are in the same order as the values that should be provided
+    let parameterized_columns_types = crud
+        .bind_markers()
+        .into_iter()
+        .map(|b| match b {
+            BindMarker::Column => column_types.next().unwrap(),
+            BindMarker::Ttl => ParameterizedColumnType {
+                column_type: ColumnType::Int,
+                value: ParameterizedValue::UsingTtl,
+            },
+            BindMarker::Timestamp => ParameterizedColumnType {
+                column_type: ColumnType::BigInt,
+                value: ParameterizedValue::UsingTimestamp,
+            },
+            BindMarker::Limit => ParameterizedColumnType {
+                column_type: ColumnType::Int,
+                value: ParameterizedValue::Limit,
+            },
+        })
+        .collect();
 
-    if query.contains("count(") && query.contains(" limit ") {
-        panic!("Both using count and limit is strange")
-    }
+    // If all the primary key columns are restricted to a single value, a single row is affected
+    let restricted_columns = crud.restricted_columns();
+    let is_full_pk = columns
+        .iter()
+        .filter(|r| r.kind().is_part_of_pk())
+        .all(|r| restricted_columns.contains(&r.column_name.as_str()));
 
-    let query_type = crud.query_type(&query, is_full_pk);
-    let limited = query.contains(" limit ");
+    let query_type = crud.query_type(is_full_pk)?;
 
-    QueryMetadata {
-        query,
+    Ok(QueryMetadata {
+        query: query_to_server(query, &columns),
         extracted_columns,
         parameterized_columns_types,
         query_type,
-        limited,
+        limited: crud.limited(),
         struct_name: table_name_to_struct_name(table_name),
-        ttl,
+        ttl: crud.ttl(),
+        lwt: crud.lwt(),
+        allow_filtering: crud.allow_filtering(),
         table_name: table_name.to_string(),
+    })
+}
+
+/// Checks that only counters and collections are incremented or decremented and that counters are
+/// never set, the keys removed from a map are bound as a set
+/// The parameterized columns types start with the assigned columns
+fn check_assignments(
+    update: &Update,
+    columns: &[ColumnInTable],
+    column_types: &mut [ParameterizedColumnType],
+) -> Result<(), ParseError> {
+    let mut assigned_types = column_types.iter_mut();
+
+    for assignment in &update.assignments {
+        let column = columns
+            .iter()
+            .find(|c| c.column_name == assignment.column.name)
+            .unwrap();
+        let column_type = ColumnType::new(column.data_type.as_str());
+        let error = match (assignment.operation, &column_type) {
+            (AssignmentOperation::Set, ColumnType::Counter) => {
+                Some("A counter can only be incremented or decremented, e.g. 'c = c + ?'")
+            }
+            (AssignmentOperation::Set, _)
+            | (AssignmentOperation::Prepend, ColumnType::List(_))
+            | (AssignmentOperation::Add, ColumnType::Counter)
+            | (AssignmentOperation::Add, ColumnType::List(_))
+            | (AssignmentOperation::Add, ColumnType::Set(_))
+            | (AssignmentOperation::Add, ColumnType::Map(_, _))
+            | (AssignmentOperation::Remove, ColumnType::Counter)
+            | (AssignmentOperation::Remove, ColumnType::List(_))
+            | (AssignmentOperation::Remove, ColumnType::Set(_))
+            | (AssignmentOperation::Remove, ColumnType::Map(_, _)) => None,
+            (AssignmentOperation::Prepend, _) => Some("Only a list can be prepended to"),
+            _ => Some("Only counters and collections can be incremented or decremented"),
+        };
+
+        if let Some(error) = error {
+            return Err(ParseError::new(error, assignment.column.span.clone()));
+        }
+
+        if !assignment.value.is_bind_marker() {
+            continue;
+        }
+
+        let assigned_type = assigned_types.next().unwrap();
+
+        if let (AssignmentOperation::Remove, ColumnType::Map(key, _)) =
+            (assignment.operation, column_type)
+        {
+            assigned_type.column_type = ColumnType::Set(key);
+        }
+    }
+
+    Ok(())
+}
+
+/// Deleting an element of a collection binds the key of a map or the index of a list
+/// The parameterized columns types start with the deleted columns
+fn check_deleted_columns(
+    delete: &Delete,
+    columns: &[ColumnInTable],
+    column_types: &mut [ParameterizedColumnType],
+) -> Result<(), ParseError> {
+    let mut deleted_types = column_types.iter_mut();
+
+    for deleted in &delete.columns {
+        let element = match &deleted.element {
+            Some(element) => element,
+            None => continue,
+        };
+        let column = columns
+            .iter()
+            .find(|c| c.column_name == deleted.column.name)
+            .unwrap();
+        let element_type = match ColumnType::new(column.data_type.as_str()) {
+            ColumnType::Map(key, _) => *key,
+            ColumnType::List(_) => ColumnType::Int,
+            _ => {
+                return Err(ParseError::new(
+                    "Only an element of a list or a map can be deleted",
+                    deleted.column.span.clone(),
+                ))
+            }
+        };
+
+        if element.is_bind_marker() {
+            deleted_types.next().unwrap().column_type = element_type;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that a select query can be executed without 'allow filtering'
+/// The primary key columns should be restricted in the order of the primary key and other columns
+/// can only be restricted with '=' if they have a secondary index
+fn check_restrictions(
+    where_clause: &[Relation],
+    columns: &[ColumnInTable],
+    indexed_columns: &[String],
+) -> Result<(), ParseError> {
+    let relations = |column: &ColumnInTable| {
+        where_clause
+            .iter()
+            .filter(|r| r.column.name == column.column_name)
+            .collect::<Vec<_>>()
+    };
+    let mut uses_index = false;
+
+    for relation in where_clause {
+        let name = &relation.column.name;
+        let span = relation.column.span.clone();
+        let is_regular = columns
+            .iter()
+            .any(|c| &c.column_name == name && c.kind() == ColumnKind::Regular);
+
+        if !is_regular {
+            continue;
+        }
+
+        if !indexed_columns.contains(name) {
+            return Err(ParseError::new(
+                format!("Column '{}' is not part of the primary key and has no secondary index, the query requires 'allow filtering'", name),
+                span,
+            ));
+        }
+
+        if relation.operator != Operator::Eq {
+            return Err(ParseError::new(
+                format!(
+                    "Column '{}' has a secondary index, which can only be used with '='",
+                    name
+                ),
+                span,
+            ));
+        }
+
+        if uses_index {
+            return Err(ParseError::new(
+                "Only a single secondary index can be used, restricting multiple indexed columns requires 'allow filtering'",
+                span,
+            ));
+        }
+
+        uses_index = true;
+    }
+
+    let partition_key = columns
+        .iter()
+        .filter(|c| c.kind() == ColumnKind::PartitionKey)
+        .collect::<Vec<_>>();
+    let restricted_partition_key = partition_key
+        .iter()
+        .filter(|c| !relations(c).is_empty())
+        .count();
+
+    for relation in partition_key.iter().flat_map(|c| relations(c)) {
+        if !matches!(relation.operator, Operator::Eq | Operator::In) {
+            return Err(ParseError::new(
+                format!(
+                    "Partition key column '{}' can only be restricted with '=' or 'in'",
+                    relation.column.name
+                ),
+                relation.column.span.clone(),
+            ));
+        }
+    }
+
+    if restricted_partition_key > 0 && restricted_partition_key < partition_key.len() {
+        let names = partition_key
+            .iter()
+            .map(|c| c.column_name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let relation = partition_key
+            .iter()
+            .flat_map(|c| relations(c))
+            .next()
+            .unwrap();
+
+        return Err(ParseError::new(
+            format!("Restrict all the partition key columns ({}), else the query requires 'allow filtering'", names),
+            relation.column.span.clone(),
+        ));
+    }
+
+    // Clustering columns can only be restricted after the partition key and the previous
+    // clustering columns are restricted to a single value (or multiple with 'in')
+    let mut previous = None;
+    let mut can_restrict = restricted_partition_key > 0;
+
+    for column in columns
+        .iter()
+        .filter(|c| c.kind() == ColumnKind::Clustering)
+    {
+        let column_relations = relations(column);
+
+        if let Some(relation) = column_relations.first() {
+            if !can_restrict {
+                let message = match previous {
+                    Some(previous) if restricted_partition_key > 0 => format!(
+                        "Clustering column '{}' can only be restricted if '{}' is restricted with '=' or 'in'",
+                        column.column_name, previous
+                    ),
+                    _ => format!(
+                        "Clustering column '{}' can only be restricted if the partition key is restricted, else the query requires 'allow filtering'",
+                        column.column_name
+                    ),
+                };
+
+                return Err(ParseError::new(message, relation.column.span.clone()));
+            }
+        }
+
+        can_restrict = !column_relations.is_empty()
+            && column_relations
+                .iter()
+                .all(|r| matches!(r.operator, Operator::Eq | Operator::In));
+        previous = Some(&column.column_name);
+    }
+
+    Ok(())
+}
+
+/// The query that is sent to the server: the wildcard is replaced by the columns and the named
+/// bind markers by question marks, since the values are bound by position
+pub fn query_to_server(query: &str, columns: &[ColumnInTable]) -> String {
+    let mut query = replace_select_wildcard(query, columns);
+
+    if let Ok(tokens) = tokenize(&query) {
+        for token in tokens.iter().rev() {
+            if let TokenKind::BindMarker(Some(_)) = token.kind {
+                query.replace_range(token.span.clone(), "?");
+            }
+        }
     }
+
+    query
 }
 
 pub fn replace_select_wildcard(query: &str, columns: &[ColumnInTable]) -> String {
@@ -107,20 +354,31 @@ pub fn replace_select_wildcard(query: &str, columns: &[ColumnInTable]) -> String
         .map(|c| c.column_name.clone())
         .collect::<Vec<_>>()
         .join(", ");
+    let mut query = query.to_string();
 
     // Wildcard should not be used: https://github.com/scylladb/scylla-rust-driver/issues/151
-    if query.starts_with("select *") {
-        query.replacen("*", &columns_separated, 1)
-    } else {
-        query.to_string()
+    if let Ok(Statement::Select(Select {
+        selection: Selection::Wildcard(span),
+        ..
+    })) = parse_statement(&query)
+    {
+        query.replace_range(span, &columns_separated);
     }
+
+    query
 }
 
 /// Tests is a query is correct
-/// If not, it will panic
-pub fn test_query(query: impl AsRef<str>) -> QueryMetadata {
+/// When validating against a schema snapshot, the query is only checked against the snapshot and
+/// is not executed
+pub fn test_query(query: impl AsRef<str>) -> Result<QueryMetadata, ParseError> {
     let query = query.as_ref();
-    let qmd = extract_query_meta_data(query);
+    let qmd = extract_query_meta_data(query)?;
+
+    if is_offline() {
+        return Ok(qmd);
+    }
+
     let mut values = SerializedValues::with_capacity(qmd.parameterized_columns_types.len());
 
     for parameterized_column_type in &qmd.parameterized_columns_types {
@@ -128,65 +386,66 @@ pub fn test_query(query: impl AsRef<str>) -> QueryMetadata {
     }
 
     // Execute the query with test values
-    if let Err(e) = block_on(GLOBAL_CONNECTION.query(query, values.clone())) {
-        panic!(
-            "Query failed:
+    if let Err(e) = block_on(GLOBAL_CONNECTION.query(qmd.query.as_str(), values.clone())) {
+        return Err(ParseError::new(
+            format!(
+                "Query failed:
             Query: {}
             Result: {:#?}
             Values: {:#?}",
-            qmd.query, e, values
-        );
+                qmd.query, e, values
+            ),
+            0..query.len(),
+        ));
     }
 
-    qmd
+    Ok(qmd)
 }
 
-fn extract_ttl(query: &str) -> Option<Ttl> {
-    let ttl_regex = regex::Regex::new("using ttl (.*)").unwrap();
-
-    if let Some(m) = ttl_regex.captures(query) {
-        // Extract the ttl value
-        let ttl = m.get(1).unwrap().as_str();
-
-        if ttl == "?" {
-            Some(Ttl::Parameterized)
-        } else {
-            Some(Ttl::Fixed(ttl.parse().unwrap()))
-        }
-    } else {
-        None
-    }
+/// The span of the first identifier with the given name, or the whole query if it's not found
+fn ident_span(query: &str, name: &str) -> Span {
+    tokenize(query)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|t| match &t.kind {
+            TokenKind::Word(w) => w == name,
+            TokenKind::QuotedIdent(q) => q == name,
+            _ => false,
+        })
+        .map_or(0..query.len(), |t| t.span)
 }
 
 /// Checks if all the used columns in the query are present in the table itself
 /// and after that, filter out only parameterized column values
 fn create_parameterized_column_types(
+    query: &str,
     columns: &[ColumnInTable],
     columns_used_in_query: &[ColumnInQuery],
-) -> Vec<ParameterizedColumnType> {
-    columns_used_in_query
-        .iter()
+) -> Result<Vec<ParameterizedColumnType>, ParseError> {
+    let mut result = vec![];
+
+    for cq in columns_used_in_query {
         // First check if all the columns that are used are in the table definition
-        .map(|cq| {
-            (
-                columns
-                    .iter()
-                    .find(|c| c.column_name.as_str() == cq.column_name.as_str())
-                    .unwrap_or_else(|| panic!("Illegal column: {}", cq.column_name)),
-                cq,
-            )
-        })
-        // Only keep the parameterized values, since random values needs to be generated for that
-        .filter(|(_, cq)| cq.parameterized)
-        .map(|(c, cq)| {
-            let column_type = ColumnType::new(c.data_type.as_str());
+        let c = columns
+            .iter()
+            .find(|c| c.column_name.as_str() == cq.column_name.as_str())
+            .ok_or_else(|| {
+                ParseError::new(
+                    format!("Illegal column: {}", cq.column_name),
+                    ident_span(query, &cq.column_name),
+                )
+            })?;
 
-            ParameterizedColumnType {
-                column_type,
+        // Only keep the parameterized values, since random values needs to be generated for that
+        if cq.parameterized {
+            result.push(ParameterizedColumnType {
+                column_type: ColumnType::new(c.data_type.as_str()),
                 value: ParameterizedValue::ExtractedColumn(cq.clone()),
-            }
-        })
-        .collect()
+            });
+        }
+    }
+
+    Ok(result)
 }
 
 /// Generates a random value for a given data type
@@ -197,66 +456,97 @@ fn add_random_value(
     let uses_in_query = match &parameterized_column_type.value {
         ParameterizedValue::ExtractedColumn(c) => c.uses_in_value,
         ParameterizedValue::UsingTtl => false,
+        ParameterizedValue::UsingTimestamp => {
+            // The max value would make the test row impossible to overwrite
+            serialized_values.add_value(&1i64).unwrap();
+
+            return;
+        }
         ParameterizedValue::Limit => false,
     };
 
-    macro_rules! into {
-        ($val: expr) => {{
-            if uses_in_query {
-                // Execute it with two values
-                serialized_values.add_value(&vec![$val, $val]).unwrap();
-            } else {
-                serialized_values.add_value(&$val).unwrap();
-            }
-        }};
+    let value = TestValue(&parameterized_column_type.column_type);
+
+    if uses_in_query {
+        // Execute it with two values
+        serialized_values.add_value(&vec![value, value]).unwrap();
+    } else {
+        serialized_values.add_value(&value).unwrap();
     }
+}
 
-    match parameterized_column_type.column_type {
-        ColumnType::TinyInt => into!(i8::MAX),
-        ColumnType::SmallInt => into!(i16::MAX),
-        // No max here, since that will crash if generating a test value for TTL
-        ColumnType::Int => into!(1),
-        ColumnType::BigInt | ColumnType::Time | ColumnType::Timestamp | ColumnType::Counter => {
-            into!(i64::MAX)
-        }
-        ColumnType::Text | ColumnType::Ascii | ColumnType::Varchar => {
-            into!("_VALUE_FOR_QUERY_VALUE_TESTING")
-        }
-        ColumnType::Boolean => into!(true),
-        ColumnType::Float => into!(f32::MAX),
-        ColumnType::Double => into!(f64::MAX),
-        ColumnType::Uuid => {
-            into!(uuid::Uuid::parse_str("3866a82f-f37c-446c-8838-fb6686c3acf2").unwrap())
-        }
-        ColumnType::Custom(_) => {
-            panic!("https://github.com/scylladb/scylla-rust-driver/issues/104")
+/// Serializes a test value for a given data type
+/// Collections and tuples are filled with a single test value of their element types
+#[derive(Clone, Copy)]
+struct TestValue<'a>(&'a ColumnType);
+
+impl Value for TestValue<'_> {
+    fn serialize(&self, buf: &mut Vec<u8>) -> Result<(), ValueTooBig> {
+        match self.0 {
+            ColumnType::TinyInt => i8::MAX.serialize(buf),
+            ColumnType::SmallInt => i16::MAX.serialize(buf),
+            // No max here, since that will crash if generating a test value for TTL
+            ColumnType::Int => 1i32.serialize(buf),
+            ColumnType::BigInt | ColumnType::Time | ColumnType::Timestamp | ColumnType::Counter => {
+                i64::MAX.serialize(buf)
+            }
+            ColumnType::Text | ColumnType::Ascii | ColumnType::Varchar => {
+                "_VALUE_FOR_QUERY_VALUE_TESTING".serialize(buf)
+            }
+            ColumnType::Boolean => true.serialize(buf),
+            ColumnType::Float => f32::MAX.serialize(buf),
+            ColumnType::Double => f64::MAX.serialize(buf),
+            ColumnType::Uuid => uuid::Uuid::parse_str("3866a82f-f37c-446c-8838-fb6686c3acf2")
+                .unwrap()
+                .serialize(buf),
+            ColumnType::List(t) | ColumnType::Set(t) => with_length(buf, |buf| {
+                buf.extend_from_slice(&1i32.to_be_bytes());
+                TestValue(t).serialize(buf)
+            }),
+            ColumnType::Map(k, v) => with_length(buf, |buf| {
+                buf.extend_from_slice(&1i32.to_be_bytes());
+                TestValue(k).serialize(buf)?;
+                TestValue(v).serialize(buf)
+            }),
+            ColumnType::Tuple(types) => with_length(buf, |buf| {
+                types.iter().try_for_each(|t| TestValue(t).serialize(buf))
+            }),
+            // Missing fields at the end of a user defined type are null, so no fields is valid
+            ColumnType::UserDefinedType(_) => with_length(buf, |_| Ok(())),
+            ColumnType::Custom(_) => {
+                panic!("https://github.com/scylladb/scylla-rust-driver/issues/104")
+            }
         }
     }
 }
 
+/// Writes the length of the bytes that are written by the closure in front of them
+fn with_length(
+    buf: &mut Vec<u8>,
+    write: impl FnOnce(&mut Vec<u8>) -> Result<(), ValueTooBig>,
+) -> Result<(), ValueTooBig> {
+    let start = buf.len();
+
+    buf.extend_from_slice(&[0; 4]);
+    write(buf)?;
+
+    let len = i32::try_from(buf.len() - start - 4).map_err(|_| ValueTooBig)?;
+
+    buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod query_tests {
     use super::*;
     use catalytic::query_metadata::ParameterizedValue::ExtractedColumn;
-    use catalytic::query_metadata::QueryType;
+    use catalytic::query_metadata::{Lwt, QueryType, Ttl};
     use catalytic::runtime::{query, TEST_TABLE};
 
-    #[test]
-    fn ttl() {
-        assert!(extract_ttl("insert into no_ttl(c) values (1)").is_none());
-        assert_eq!(
-            extract_ttl("insert into no_ttl(c) values (1) using ttl 102"),
-            Some(Ttl::Fixed(102))
-        );
-        assert_eq!(
-            extract_ttl("insert into no_ttl(c) values (1) using ttl ?"),
-            Some(Ttl::Parameterized)
-        );
-    }
-
     #[test]
     fn wildcard_replacement() {
-        let result = test_query("select * from test_table");
+        let result = test_query("select * from test_table").unwrap();
 
         assert_eq!(result.query, "select b, c, d, a, e from test_table");
     }
@@ -264,7 +554,8 @@ mod query_tests {
     #[test]
     fn test_in() {
         let result =
-            test_query("select * from test_table where b = ? and c = 5 and d in ? limit 1");
+            test_query("select * from test_table where b = ? and c = 5 and d in ? limit 1")
+                .unwrap();
 
         assert_eq!(
             result.parameterized_columns_types,
@@ -291,14 +582,16 @@ mod query_tests {
         );
 
         // Just check if they run correctly
-        test_query("select * from test_table where b = ? and c = 5 and d in ? limit ?");
-        test_query("select * from test_table where b = ? and c = ? and d in (1, 2) limit 1");
+        test_query("select * from test_table where b = ? and c = 5 and d in ? limit ?").unwrap();
+        test_query("select * from test_table where b = ? and c = ? and d in (1, 2) limit 1")
+            .unwrap();
     }
 
     #[test]
     fn test_query_metadata() {
-        let query = "select c from test_table where a = ? and b = 1 limit ?";
-        let qmd = extract_query_meta_data(query);
+        // The partition key (b, c) is fully restricted, so no 'allow filtering' is needed
+        let query = "select c from test_table where c = ? and b = 1 limit ?";
+        let qmd = extract_query_meta_data(query).unwrap();
 
         assert_eq!(
             qmd,
@@ -312,7 +605,7 @@ mod query_tests {
                         is_part_of_where_clause: false,
                     },
                     ColumnInQuery {
-                        column_name: "a".to_string(),
+                        column_name: "c".to_string(),
                         parameterized: true,
                         uses_in_value: false,
                         is_part_of_where_clause: true,
@@ -328,7 +621,7 @@ mod query_tests {
                     ParameterizedColumnType {
                         column_type: ColumnType::Int,
                         value: ExtractedColumn(ColumnInQuery {
-                            column_name: "a".to_string(),
+                            column_name: "c".to_string(),
                             parameterized: true,
                             uses_in_value: false,
                             is_part_of_where_clause: true,
@@ -344,33 +637,34 @@ mod query_tests {
                 table_name: "test_table".to_string(),
                 limited: true,
                 ttl: None,
+                lwt: None,
+                allow_filtering: false,
             }
         );
     }
 
-    macro_rules! write_panic_test {
+    macro_rules! write_error_test {
         ($name: ident, $query: expr) => {
             #[test]
-            #[should_panic]
             fn $name() {
-                test_query($query);
+                assert!(test_query($query).is_err());
             }
         };
     }
 
-    write_panic_test!(
+    write_error_test!(
         test_count_and_limit_single_query,
         format!("select count(*) from {} limit 1", TEST_TABLE)
     );
-    write_panic_test!(
+    write_error_test!(
         test_invalid_pk,
         format!("select * from {} where a = 1 and c = 1", TEST_TABLE)
     );
-    write_panic_test!(
+    write_error_test!(
         test_invalid_pk_another,
         format!("select * from {} where a = ? and c = 1", TEST_TABLE)
     );
-    write_panic_test!(
+    write_error_test!(
         test_not_allow_filtering,
         format!("select * from {} where b = 1 and c > 1", TEST_TABLE)
     );
@@ -380,11 +674,68 @@ mod query_tests {
         test_query(format!(
             "select * from {} where b = 1 and c = ?",
             TEST_TABLE
-        ));
+        ))
+        .unwrap();
         test_query(format!(
             "select * from {} where b = 1 and c = 1",
             TEST_TABLE
-        ));
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn test_lwt() {
+        let result = test_query(format!(
+            "insert into {}(a, b, c, d, e) values (?, ?, ?, ?, ?) if not exists",
+            TEST_TABLE
+        ))
+        .unwrap();
+
+        assert_eq!(Some(Lwt::IfNotExists), result.lwt);
+
+        let result = test_query(format!(
+            "update {} set e = ? where b = ? and c = ? and d = ? and a = ? if e = ?",
+            TEST_TABLE
+        ))
+        .unwrap();
+
+        assert_eq!(Some(Lwt::IfCondition), result.lwt);
+        assert_eq!(6, result.parameterized_columns_types.len());
+
+        let result = test_query(format!(
+            "delete from {} where b = ? and c = ? and d = ? and a = ? if exists",
+            TEST_TABLE
+        ))
+        .unwrap();
+
+        assert_eq!(Some(Lwt::IfExists), result.lwt);
+        assert_eq!(QueryType::DeleteUnique, result.query_type);
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let result = test_query(format!(
+            "update {} using ttl ? and timestamp ? set e = ? where b = ? and c = ? and d = ? and a = ?",
+            TEST_TABLE
+        )).unwrap();
+        let types = &result.parameterized_columns_types;
+
+        assert_eq!(Some(Ttl::Parameterized), result.ttl);
+        assert_eq!(ParameterizedValue::UsingTtl, types[0].value);
+        assert_eq!(ColumnType::BigInt, types[1].column_type);
+        assert_eq!(ParameterizedValue::UsingTimestamp, types[1].value);
+
+        let result = test_query(format!(
+            "delete from {} using timestamp ? where b = ? and c = ? and d = ? and a = ?",
+            TEST_TABLE
+        ))
+        .unwrap();
+
+        assert_eq!(
+            ParameterizedValue::UsingTimestamp,
+            result.parameterized_columns_types[0].value
+        );
+        assert_eq!(QueryType::DeleteUnique, result.query_type);
     }
 
     #[test]
@@ -394,7 +745,7 @@ mod query_tests {
             &[],
         );
 
-        let result = test_query("select * from UUIDTable where u = ?");
+        let result = test_query("select * from UUIDTable where u = ?").unwrap();
         let column_type = &result.parameterized_columns_types[0].column_type;
 
         match column_type {
@@ -404,6 +755,126 @@ mod query_tests {
             _ => panic!("Expected uuid"),
         }
     }
+
+    #[test]
+    fn test_collections() {
+        query(
+            "create table if not exists collection_table(a int, l list<int>, m map<text, frozen<tuple<int, text>>>, s set<text>, primary key((a)))",
+            &[],
+        );
+
+        let result =
+            test_query("update collection_table set l = ?, m = ?, s = ? where a = ?").unwrap();
+        let column_types = result
+            .parameterized_columns_types
+            .iter()
+            .map(|p| p.column_type.to_ty())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                "std::vec::Vec<i32>",
+                "std::collections::HashMap<String, (i32, String)>",
+                "std::collections::HashSet<String>",
+                "i32"
+            ],
+            column_types
+        );
+
+        test_query("insert into collection_table(a, l, m, s) values (?, ?, ?, ?)").unwrap();
+
+        let result =
+            test_query("update collection_table set l = ? + l, m = m - ?, s = s + ? where a = ?")
+                .unwrap();
+        let column_types = result
+            .parameterized_columns_types
+            .iter()
+            .map(|p| p.column_type.to_ty())
+            .collect::<Vec<_>>();
+
+        // Keys are removed from a map by binding a set of keys
+        assert_eq!(
+            vec![
+                "std::vec::Vec<i32>",
+                "std::collections::HashSet<String>",
+                "std::collections::HashSet<String>",
+                "i32"
+            ],
+            column_types
+        );
+
+        let error = test_query("update collection_table set s = ? + s where a = ?").unwrap_err();
+
+        assert_eq!("Only a list can be prepended to", error.message);
+    }
+
+    #[test]
+    fn test_delete_columns() {
+        query(
+            "create table if not exists collection_table(a int, l list<int>, m map<text, frozen<tuple<int, text>>>, s set<text>, primary key((a)))",
+            &[],
+        );
+
+        let result = test_query(format!(
+            "delete e from {} where b = ? and c = ? and d = ? and a = ?",
+            TEST_TABLE
+        ))
+        .unwrap();
+
+        assert_eq!(4, result.parameterized_columns_types.len());
+        assert_eq!(QueryType::DeleteUnique, result.query_type);
+
+        let result = test_query("delete l[?], m[?] from collection_table where a = ?").unwrap();
+        let column_types = result
+            .parameterized_columns_types
+            .iter()
+            .map(|p| p.column_type.to_ty())
+            .collect::<Vec<_>>();
+
+        // The index of the list and the key of the map are bound
+        assert_eq!(vec!["i32", "String", "i32"], column_types);
+
+        let error = test_query("delete s[?] from collection_table where a = ?").unwrap_err();
+
+        assert_eq!(
+            "Only an element of a list or a map can be deleted",
+            error.message
+        );
+    }
+
+    #[test]
+    fn test_counters() {
+        query(
+            "create table if not exists counter_test_table(a int, c counter, primary key((a)))",
+            &[],
+        );
+
+        let result = test_query("update counter_test_table set c = c - ? where a = ?").unwrap();
+
+        assert_eq!(
+            ColumnType::Counter,
+            result.parameterized_columns_types[0].column_type
+        );
+        assert_eq!(QueryType::UpdateUnique, result.query_type);
+
+        let error = test_query("update counter_test_table set c = ? where a = ?").unwrap_err();
+
+        assert_eq!(
+            "A counter can only be incremented or decremented, e.g. 'c = c + ?'",
+            error.message
+        );
+
+        let error = test_query(format!(
+            "update {} set e = e + 1 where b = ? and c = ? and d = ? and a = ?",
+            TEST_TABLE
+        ))
+        .unwrap_err();
+
+        assert_eq!(
+            "Only counters and collections can be incremented or decremented",
+            error.message
+        );
+    }
 }
 
 #[cfg(test)]
@@ -441,17 +912,123 @@ mod subset_tests {
     #[test]
     fn test_check_subset() {
         let c = check_subset_columns();
-        let r = create_parameterized_column_types(&c, &create_columns_used_in_query("a"));
+        let r =
+            create_parameterized_column_types("", &c, &create_columns_used_in_query("a")).unwrap();
 
         assert_eq!(1, r.len());
     }
 
     #[test]
-    #[should_panic]
     fn test_check_subset_fail() {
-        create_parameterized_column_types(
+        let error = create_parameterized_column_types(
+            "",
             &check_subset_columns(),
             &create_columns_used_in_query("c"),
+        )
+        .unwrap_err();
+
+        assert_eq!("Illegal column: c", error.message);
+    }
+}
+
+#[cfg(test)]
+mod restriction_tests {
+    use crate::crud::{parse_statement, Statement};
+    use crate::extract_query_metadata::check_restrictions;
+    use catalytic::table_metadata::{ColumnInTable, ColumnKind};
+
+    /// Like the test table: primary key((b, c), d, a) with a regular column e and an indexed column f
+    fn columns() -> Vec<ColumnInTable> {
+        let column = |name: &str, kind: ColumnKind, position| ColumnInTable {
+            column_name: name.to_string(),
+            kind: kind.to_string(),
+            position,
+            data_type: "int".to_string(),
+        };
+
+        vec![
+            column("b", ColumnKind::PartitionKey, 0),
+            column("c", ColumnKind::PartitionKey, 1),
+            column("d", ColumnKind::Clustering, 0),
+            column("a", ColumnKind::Clustering, 1),
+            column("e", ColumnKind::Regular, -1),
+            column("f", ColumnKind::Regular, -1),
+        ]
+    }
+
+    fn check(where_clause: &str) -> Result<(), (String, std::ops::Range<usize>)> {
+        let query = format!("select * from t {}", where_clause);
+        let where_clause = match parse_statement(&query).unwrap() {
+            Statement::Select(select) => select.where_clause,
+            _ => unreachable!(),
+        };
+
+        check_restrictions(&where_clause, &columns(), &["f".to_string()])
+            // The span relative to the where clause is easier to read
+            .map_err(|e| (e.message, e.span.start - 16..e.span.end - 16))
+    }
+
+    #[test]
+    fn test_allowed_restrictions() {
+        check("").unwrap();
+        check("where b = 1 and c = 1").unwrap();
+        check("where b = 1 and c in ? and d > 1").unwrap();
+        check("where b = 1 and c = 1 and d = 1 and a < 2").unwrap();
+        check("where b = 1 and c = 1 and d in ? and a = 2").unwrap();
+        check("where f = ?").unwrap();
+        check("where b = 1 and c = 1 and f = ?").unwrap();
+    }
+
+    #[test]
+    fn test_filtering_restrictions() {
+        assert_eq!(
+            Err((
+                "Column 'e' is not part of the primary key and has no secondary index, the query requires 'allow filtering'".to_string(),
+                6..7
+            )),
+            check("where e = 1")
+        );
+        assert_eq!(
+            Err((
+                "Column 'f' has a secondary index, which can only be used with '='".to_string(),
+                6..7
+            )),
+            check("where f > 1")
+        );
+        assert_eq!(
+            Err((
+                "Restrict all the partition key columns (b, c), else the query requires 'allow filtering'".to_string(),
+                6..7
+            )),
+            check("where b = 1")
+        );
+        assert_eq!(
+            Err((
+                "Partition key column 'c' can only be restricted with '=' or 'in'".to_string(),
+                16..17
+            )),
+            check("where b = 1 and c > 1")
+        );
+        assert_eq!(
+            Err((
+                "Clustering column 'd' can only be restricted if the partition key is restricted, else the query requires 'allow filtering'".to_string(),
+                6..7
+            )),
+            check("where d = 1")
+        );
+        assert_eq!(
+            Err((
+                "Clustering column 'a' can only be restricted if 'd' is restricted with '=' or 'in'".to_string(),
+                26..27
+            )),
+            check("where b = 1 and c = 1 and a = 1")
+        );
+        assert_eq!(
+            Err((
+                "Clustering column 'a' can only be restricted if 'd' is restricted with '=' or 'in'".to_string(),
+                36..37
+            )),
+            check("where b = 1 and c = 1 and d > 1 and a = 1")
         );
     }
 }
@@ -1,46 +1,234 @@
-use crate::extract_query_metadata::{replace_select_wildcard, test_query};
+use crate::cql::{tokenize, ParseError, TokenKind};
+use crate::crud::{parse_statement, MetadataFunction, Operation, Select, Selection, Statement};
+use crate::extract_query_metadata::{query_to_server, test_query};
 use proc_macro2::TokenStream;
 
 use catalytic::capitalizing::struct_name_to_table_name;
-use catalytic::materialized_view::query_materialized_view;
-use catalytic::query_metadata::{query_columns, QueryMetadata, QueryType};
-use quote::quote;
+use catalytic::cql_type::CQL_TYPE_MODULE;
+use catalytic::materialized_view::materialized_view;
+use catalytic::query_metadata::{ParameterizedValue, QueryMetadata, QueryType};
+use catalytic::schema_provider::schema_from_env;
+use catalytic::table_metadata::ColumnType;
+use catalytic::user_defined_type::USER_DEFINED_TYPES_MODULE;
+use quote::{format_ident, quote, quote_spanned};
+use std::fmt::Display;
 use syn::parse::{Parse, ParseStream};
-use syn::parse_quote::ParseQuote;
-use syn::parse_str;
 use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parse_quote, parse_str};
 
+pub mod cql;
 pub mod crud;
 pub mod extract_query_metadata;
 
 #[derive(Clone)]
 pub struct Query {
+    /// The query as written in the macro
     pub query_pretty: String,
-    pub idents: Vec<syn::Ident>,
-    /// The query values for the idents
+    /// The literal of the query, errors point to (a part of) this literal
+    pub literal: syn::LitStr,
+    /// The parsed query
+    pub statement: Statement,
+    /// The expressions of the values, in the order of the bind markers
+    pub values: Vec<syn::Expr>,
+    /// The serialized values of the expressions
     pub serialized_values: proc_macro2::TokenStream,
     /// More metadata
     pub qmd: QueryMetadata,
+    /// The struct the selected rows are mapped to, only set by 'query_as!'
+    pub row_type: Option<syn::Type>,
+}
+
+/// Parses the input of 'query_as!': the struct to map the rows to, followed by the input of 'query!'
+pub struct QueryAs(pub Query);
+
+fn struct_prefix() -> String {
+    std::env::var("GENERATED_DB_ENTITIES_PATH_PREFIX")
+        .expect("Please provide the struct prefix, e.g.: 'crate::entities' for environment variable GENERATED_DB_ENTITIES_PATH_PREFIX")
+        // It ends sometimes with a newline
+        .replace("\n", "")
+}
+
+/// The path to a user defined type is relative to the generated dir, this makes it absolute
+fn with_user_defined_types_from_prefix(ty: String) -> String {
+    ty.replace(
+        &format!("super::{}", USER_DEFINED_TYPES_MODULE),
+        &format!("{}::{}", struct_prefix(), USER_DEFINED_TYPES_MODULE),
+    )
+}
+
+fn metadata_ty(metadata: MetadataFunction) -> TokenStream {
+    match metadata {
+        MetadataFunction::Writetime => {
+            quote! { Option<catalytic::query_transform::TimestampType> }
+        }
+        MetadataFunction::Ttl => quote! { Option<catalytic::query_transform::TtlType> },
+    }
 }
 
 impl Query {
+    /// An error that points to the query literal
+    pub fn error(&self, message: impl Display) -> syn::Error {
+        syn::Error::new(self.literal.span(), message)
+    }
+
     /// Can't add this in the parse method because it fails with a lot of other compile errors
-    fn check_counts(&self) {
-        if self.qmd.parameterized_columns_types.len() != self.idents.len() {
-            panic!("Parameterized column count is different than the amount of parameters");
+    fn check_counts(&self) -> syn::Result<()> {
+        if self.qmd.parameterized_columns_types.len() != self.values.len() {
+            return Err(
+                self.error("Parameterized column count is different than the amount of parameters")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Only true if the queried table has counters, which means all regular columns are counters
+    fn is_counter_table(&self) -> bool {
+        schema_from_env()
+            .columns(&self.qmd.table_name)
+            .iter()
+            .any(|c| c.data_type == "counter")
+    }
+
+    /// Only true if the selected rows are mapped to the struct of the table, without metadata
+    pub fn selects_entity(&self) -> bool {
+        let select = match &self.statement {
+            Statement::Select(select) if self.row_type.is_none() => select,
+            _ => return false,
+        };
+
+        select.metadata_selectors().is_empty() && self.selects_entity_columns(select)
+    }
+
+    /// Only true for 'select *' or if all the columns of the table are selected in the order of the struct fields
+    fn selects_entity_columns(&self, select: &Select) -> bool {
+        if self.statement.selects_all_columns() {
+            return true;
+        }
+
+        let columns = schema_from_env().columns(&self.qmd.table_name);
+        let selected = select.value_selectors();
+
+        selected.len() == columns.len()
+            && columns
+                .iter()
+                .zip(&selected)
+                .all(|(c, s)| c.column_name == s.column.name && s.alias.is_none())
+    }
+
+    /// The type a selected row is mapped to (as the generic arguments of the select), with the items
+    /// that need to be defined for that type
+    /// This is the struct of the table, paired with the metadata if 'writetime' or 'ttl' is selected
+    /// When only some columns are selected, an anonymous struct is created with a field per selected column
+    fn selected_entity(&self, struct_name: &TokenStream) -> (TokenStream, TokenStream) {
+        let select = match &self.statement {
+            Statement::Select(select) => select,
+            _ => unreachable!(),
+        };
+
+        // The rows are decoded as the projection and converted by field name, so the order of the
+        // fields of the struct doesn't matter
+        if let Some(row_type) = &self.row_type {
+            let (fields, row) = self.projection(select);
+            let field_names = fields.iter().map(|(name, _)| name);
+
+            return (
+                quote! {
+                    #row
+
+                    impl From<Row> for #row_type {
+                        fn from(row: Row) -> Self {
+                            Self {
+                                #(#field_names: row.#field_names,)*
+                            }
+                        }
+                    }
+                },
+                quote! { #row_type, _, _, Row },
+            );
         }
+
+        let metadata = select.metadata_selectors();
+
+        if !self.selects_entity_columns(select) || !select.metadata_is_selected_last() {
+            let (_, row) = self.projection(select);
+
+            return (row, quote! { Row });
+        }
+
+        if metadata.is_empty() {
+            return (quote! {}, struct_name.clone());
+        }
+
+        let types = metadata.iter().map(|s| metadata_ty(s.metadata.unwrap()));
+
+        (
+            quote! {},
+            quote! {
+                catalytic::query_transform::WithMetadata<#struct_name, (#(#types,)*)>
+            },
+        )
     }
 
-    pub fn create_transformed(self) -> proc_macro2::TokenStream {
-        self.check_counts();
+    /// Creates a struct named 'Row' with a field per selector, the types are taken from the schema
+    fn projection(&self, select: &Select) -> (Vec<(syn::Ident, TokenStream)>, TokenStream) {
+        let columns = schema_from_env().columns(&self.qmd.table_name);
+        let column_ty = |name: &str| {
+            let column = columns
+                .iter()
+                .find(|c| c.column_name == name)
+                .unwrap_or_else(|| panic!("Column {} not found", name));
+            let ty = with_user_defined_types_from_prefix(
+                ColumnType::new(column.data_type.as_str()).to_ty(),
+            );
+
+            ty.parse::<TokenStream>().unwrap()
+        };
+        let fields = if self.statement.selects_all_columns() {
+            columns
+                .iter()
+                .map(|c| {
+                    (
+                        format_ident!("{}", c.column_name),
+                        column_ty(&c.column_name),
+                    )
+                })
+                .collect::<Vec<_>>()
+        } else {
+            select
+                .selectors()
+                .iter()
+                .map(|s| {
+                    let ty = match s.metadata {
+                        None => column_ty(&s.column.name),
+                        Some(metadata) => metadata_ty(metadata),
+                    };
+
+                    (format_ident!("{}", s.field_name()), ty)
+                })
+                .collect()
+        };
+        let field_ts = fields.iter().map(|(name, ty)| quote! { pub #name: #ty, });
+        let row = quote! {
+            #[derive(scylla::FromRow, Debug, Clone, PartialEq)]
+            #[allow(dead_code)]
+            struct Row {
+                #(#field_ts)*
+            }
+        };
+
+        (fields, row)
+    }
+
+    pub fn create_transformed(self) -> syn::Result<proc_macro2::TokenStream> {
+        self.check_counts()?;
         let query_to_server = &self.qmd.query;
-        let struct_prefix = std::env::var("GENERATED_DB_ENTITIES_PATH_PREFIX")
-            .expect("Please provide the struct prefix, e.g.: 'crate::entities' for environment variable GENERATED_DB_ENTITIES_PATH_PREFIX")
-            // It ends sometimes with a newline
-            .replace("\n", "");
-        let path_to_struct = format!("{}::{}", struct_prefix, self.qmd.struct_name);
+        let path_to_struct = format!("{}::{}", struct_prefix(), self.qmd.struct_name);
         let struct_name: TokenStream = path_to_struct.parse().unwrap();
         let serialized_values = &self.serialized_values;
+        // Items that are defined before the transformed type, e.g. the struct of a projection
+        let mut items = quote! {};
 
         macro_rules! t {
             ($ty: ident) => {
@@ -54,21 +242,40 @@ impl Query {
         }
 
         let ts = match self.qmd.query_type {
+            // The result of an 'insert ... if not exists' contains the existing row
+            QueryType::InsertUnique if self.qmd.lwt.is_some() => {
+                quote! {
+                    catalytic::query_transform::LightweightTransaction::<#struct_name>::new(catalytic::query_transform::Qv {
+                        query: #query_to_server,
+                        values: #serialized_values,
+                    })
+                }
+            }
+            // The columns after '[applied]' of a conditional update or delete depend on the database
+            QueryType::UpdateUnique | QueryType::DeleteUnique | QueryType::DeleteMultiple
+                if self.qmd.lwt.is_some() =>
+            {
+                t!(ConditionalMutation)
+            }
             QueryType::SelectMultiple => {
-                assert!(self.query_pretty.starts_with("select *"));
+                let (row_items, entity) = self.selected_entity(&struct_name);
+
+                items = row_items;
 
                 quote! {
-                    catalytic::query_transform::SelectMultiple::<#struct_name>::new(catalytic::query_transform::Qv {
+                    catalytic::query_transform::SelectMultiple::<#entity>::new(catalytic::query_transform::Qv {
                         query: #query_to_server,
                         values: #serialized_values,
                     })
                 }
             }
             QueryType::SelectUniqueByLimit | QueryType::SelectUnique => {
-                assert!(self.query_pretty.starts_with("select *"));
+                let (row_items, entity) = self.selected_entity(&struct_name);
+
+                items = row_items;
 
                 quote! {
-                    catalytic::query_transform::SelectUnique::<#struct_name>::new(catalytic::query_transform::Qv {
+                    catalytic::query_transform::SelectUnique::<#entity>::new(catalytic::query_transform::Qv {
                         query: #query_to_server,
                         values: #serialized_values,
                     })
@@ -82,6 +289,10 @@ impl Query {
                     })
                 }
             }
+            // Counter updates can only be batched with other counter updates
+            QueryType::UpdateUnique if self.is_counter_table() => {
+                t!(UpdateCounter)
+            }
             QueryType::UpdateUnique => {
                 t!(Update)
             }
@@ -99,26 +310,42 @@ impl Query {
             }
         };
 
-        let idents = &self.idents;
-
-        quote! {{
-            #(tracing::debug!("Used value {:#?} for {:#?} for upcoming query", #idents, stringify!(#idents));)*
+        Ok(quote! {{
+            #items
 
             #ts
-        }}
+        }})
     }
 
     /// The base table can be different from the table that is being queried from, if it's a materialized view
     /// with exactly the same columns as the base table
-    pub fn create_transformed_materialized_view(mut self) -> proc_macro2::TokenStream {
-        self.check_counts();
+    pub fn create_transformed_materialized_view(mut self) -> syn::Result<proc_macro2::TokenStream> {
+        self.check_counts()?;
 
         // Make sure the current table is a materialized view with the same columns
         let table_name = struct_name_to_table_name(&self.qmd.struct_name);
-        let mv = query_materialized_view(&table_name).expect("Table is not a materialized view");
+        let schema = schema_from_env();
+        let table_span = self.statement.operation().table().name.span.clone();
+        let mv = materialized_view(schema, &table_name).ok_or_else(|| {
+            ParseError::new("Table is not a materialized view", table_span.clone())
+                .to_syn_error(&self.literal)
+        })?;
 
         // If the columns are not the same, the mapping will fail
-        assert!(mv.same_columns);
+        if !mv.same_columns {
+            return Err(ParseError::new(
+                "The materialized view does not have the same columns as the base table",
+                table_span,
+            )
+            .to_syn_error(&self.literal));
+        }
+
+        // The columns of a materialized view are in a different order than the base table
+        if matches!(&self.statement, Statement::Select(s) if !s.selectors().is_empty()) {
+            return Err(self.error(
+                "Select all columns with '*' when mapping to the base table, selecting columns, writetime or ttl is not supported",
+            ));
+        }
 
         // Make sure all rows are selected
         // This is because the query needs to be transformed a little:
@@ -131,16 +358,16 @@ impl Query {
         // An odd thing about Scylla is that the ordering of the column of the mv is different than the base table
         // This is a problem since the Scylla driver will map rows to structs with indexes
         // When a select query is performed, rearrange it in the correct order
-        if self.query_pretty.starts_with("select * ") {
-            let columns_mv = query_columns(&table_name);
-            let columns_base_table = query_columns(&mv.base_table_name);
-            let query_should_be = replace_select_wildcard(&self.query_pretty, &columns_mv);
+        if self.statement.selects_all_columns() {
+            let columns_mv = schema.columns(&table_name);
+            let columns_base_table = schema.columns(&mv.base_table_name);
+            let query_should_be = query_to_server(&self.query_pretty, &columns_mv);
 
             // Make sure the query equals what is expected
             assert_eq!(self.qmd.query, query_should_be);
 
             // Now make sure the order is correct when mapping to the base table
-            self.qmd.query = replace_select_wildcard(&self.query_pretty, &columns_base_table);
+            self.qmd.query = query_to_server(&self.query_pretty, &columns_base_table);
         }
 
         self.create_transformed()
@@ -150,79 +377,220 @@ impl Query {
 impl Parse for Query {
     /// Parses a query like this: my_proc_macro!("select * from table where a = 1 and b = ?", b);
     /// So a literal query followed by a comma separated list of arguments that will replace the
-    /// question marks
+    /// question marks. The arguments can be any expression.
+    /// Named bind markers like ':b' are replaced by the argument 'b = <expression>' or else by the variable 'b'
+    /// A query that ends with 'allow filtering' must be preceded by the flag: my_proc_macro!(allow_filtering, "...")
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Queries that end with 'allow filtering' can scan the whole table, so it must be opted in
+        let allow_filtering = if input.peek(syn::Ident) {
+            let flag: syn::Ident = input.parse()?;
+
+            if flag != "allow_filtering" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "Expected 'allow_filtering' or the query as a string literal",
+                ));
+            }
+
+            let _: syn::Token![,] = input.parse()?;
+
+            Some(flag)
+        } else {
+            None
+        };
         let query: syn::Lit = syn::parse::Parse::parse(input)?;
-        // TODO: This is a lot of work just to get the str out of a LitStr, isn't there a better way?
         let query_raw = match query {
             syn::Lit::Str(s) => s,
-            _ => panic!("First argument is not a literal"),
+            query => {
+                return Err(syn::Error::new_spanned(
+                    query,
+                    "Expected the query as a string literal",
+                ))
+            }
         };
-        let stringified = format!("{:?}", query_raw);
-        let starting_quote = stringified
-            .find('\"')
-            .expect("Failed to find leading quote");
-        let ending_quote = stringified
-            .rfind('\"')
-            .expect("Failed to find trailing quote");
-        let query_pretty = stringified[starting_quote + 1..ending_quote].to_string();
-        let idents = if input.is_empty() {
-            vec![]
+        let query_written = query_raw.value();
+        let statement = parse_statement(&query_written).map_err(|e| e.to_syn_error(&query_raw))?;
+
+        if let (
+            Statement::Select(Select {
+                allow_filtering: Some(span),
+                ..
+            }),
+            None,
+        ) = (&statement, &allow_filtering)
+        {
+            return Err(ParseError::new(
+                "The query uses 'allow filtering', which can scan the whole table, confirm this with query!(allow_filtering, \"...\")",
+                span.clone(),
+            )
+            .to_syn_error(&query_raw));
+        }
+
+        if let Some(flag) = &allow_filtering {
+            if !statement.operation().allow_filtering() {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "The 'allow_filtering' flag is only valid for a select query that ends with 'allow filtering'",
+                ));
+            }
+        }
+        let arguments = if input.is_empty() {
+            Punctuated::new()
         } else {
             let _: syn::Token![,] = syn::parse::Parse::parse(input)?;
-            let punc_idents: Punctuated<syn::Ident, syn::Token![,]> =
-                <Punctuated<syn::Ident, syn::Token![,]>>::parse(input)?;
 
-            punc_idents.iter().cloned().collect()
+            Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(input)?
         };
+        let mut positional = vec![];
+        let mut named = vec![];
+
+        for argument in arguments {
+            match argument {
+                syn::Expr::Assign(syn::ExprAssign { left, right, .. }) => match *left {
+                    syn::Expr::Path(syn::ExprPath { path, .. }) if path.get_ident().is_some() => {
+                        named.push((path.get_ident().unwrap().clone(), *right))
+                    }
+                    left => {
+                        return Err(syn::Error::new_spanned(
+                            left,
+                            "Expected the name of a bind marker",
+                        ))
+                    }
+                },
+                argument => positional.push(argument),
+            }
+        }
+
+        // Parsing succeeded, so tokenizing can not fail
+        let bind_markers = tokenize(&query_written)
+            .unwrap()
+            .into_iter()
+            .filter_map(|t| match t.kind {
+                TokenKind::BindMarker(name) => Some((name, t.span)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let positional_count = bind_markers.iter().filter(|(n, _)| n.is_none()).count();
 
-        let qmd = test_query(&query_pretty);
+        if positional.len() != positional_count {
+            return Err(syn::Error::new(
+                query_raw.span(),
+                format!(
+                    "Expected {} argument(s) for the '?' bind markers, found {}",
+                    positional_count,
+                    positional.len()
+                ),
+            ));
+        }
+
+        if let Some((name, _)) = named.iter().find(|(name, _)| {
+            !bind_markers
+                .iter()
+                .any(|(n, _)| n.as_ref() == Some(&name.to_string()))
+        }) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("The query has no bind marker ':{}'", name),
+            ));
+        }
 
-        // Parameterized columns equals columns values that are filled with dynamic values from the user
-        let parameterized_columns = qmd
-            .extracted_columns
+        // The values in the order of the bind markers
+        let mut positional = positional.into_iter();
+        let values = bind_markers
             .iter()
-            .filter(|r| r.parameterized)
-            .collect::<Vec<_>>();
-        let ident_count = idents.len();
-        let types_comparison = idents
+            .map(|(name, _)| match name {
+                None => positional.next().unwrap(),
+                Some(name) => named
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or_else(|| {
+                        let ident = format_ident!("{}", name);
+
+                        parse_quote! { #ident }
+                    }),
+            })
+            .collect::<Vec<syn::Expr>>();
+        let qmd = test_query(&query_written).map_err(|e| e.to_syn_error(&query_raw))?;
+        let value_count = values.len();
+        let add_values = values
             .iter()
             .enumerate()
-            .map(|(index, _)| {
-                let column_type = &qmd.parameterized_columns_types[index].column_type;
-                let mut ty_comparison = column_type.to_ty();
-
-                if index == parameterized_columns.len() {
-                    // The limit column
-                    assert!(qmd.limited);
-                } else if parameterized_columns[index].uses_in_value {
-                    // This is used to compare types, to see if the types are assignable
-                    // Another turbofish is needed
-                    ty_comparison = format!("std::vec::Vec::<{}>", ty_comparison);
-                }
+            .map(|(index, value)| {
+                let parameterized_column_type = &qmd.parameterized_columns_types[index];
+                let marker = parameterized_column_type
+                    .column_type
+                    .to_cql_type_marker()
+                    .map(|marker| match &parameterized_column_type.value {
+                        ParameterizedValue::ExtractedColumn(c) if c.uses_in_value => {
+                            format!("{}::In<{}>", CQL_TYPE_MODULE, marker)
+                        }
+                        _ => marker,
+                    });
+
+                // Custom types can not be checked
+                let type_comparison = match marker {
+                    None => quote! {},
+                    Some(marker) => {
+                        let marker = with_user_defined_types_from_prefix(marker);
+                        let marker: syn::Type =
+                            parse_str(&marker).expect("Failed to parse to type");
+
+                        // The error points to the argument if the type is not correct
+                        quote_spanned! {value.span()=>
+                            catalytic::cql_type::assert_cql_type::<#marker, _>(value);
+                        }
+                    }
+                };
 
-                parse_str(&ty_comparison).expect("Failed to parse to type")
+                // Every value is evaluated once, in its own scope so it can't shadow variables
+                // that are used by the next values
+                quote! {{
+                    let value = &(#value);
+
+                    #type_comparison
+
+                    tracing::debug!("Used value {:#?} for {:#?} for upcoming query", value, stringify!(#value));
+
+                    serialized_values.add_value(value)?;
+                }}
             })
-            .collect::<Vec<syn::Type>>();
+            .collect::<Vec<_>>();
 
         let serialized_values = quote! {{
-            let mut serialized_values = scylla::frame::value::SerializedValues::with_capacity(#ident_count);
+            let mut serialized_values = scylla::frame::value::SerializedValues::with_capacity(#value_count);
 
-            #(
-                // Check if the type is correct
-                debug_assert!((#types_comparison::from(#idents.clone()), true).1);
-
-                serialized_values.add_value(&#idents)?;
-            )*
+            #(#add_values)*
 
             serialized_values
         }};
 
         Ok(Query {
-            query_pretty,
-            idents,
+            query_pretty: query_written,
+            literal: query_raw,
+            statement,
+            values,
             serialized_values,
             qmd,
+            row_type: None,
         })
     }
 }
+
+impl Parse for QueryAs {
+    /// Parses a query like this: query_as!(MyRow, "select a, b from table where a = ?", a);
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let row_type: syn::Type = input.parse()?;
+        let _: syn::Token![,] = input.parse()?;
+        let mut query: Query = input.parse()?;
+
+        match &query.statement {
+            Statement::Select(select) if !matches!(select.selection, Selection::Count(_)) => {}
+            _ => return Err(query.error("Only select queries can be mapped to a struct")),
+        }
+
+        query.row_type = Some(row_type);
+
+        Ok(QueryAs(query))
+    }
+}
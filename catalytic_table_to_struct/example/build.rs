@@ -19,7 +19,19 @@ fn main() {
             primary key ((email), name, age)",
         &[],
     );
+    query("create index if not exists on person(email)", &[]);
     query("create table if not exists child(birthday int, json text, json_nullable text, enum_json text, primary key((birthday)))", &[]);
+    query("create table if not exists collection_table(a int, l list<int>, m map<text, frozen<tuple<int, text>>>, s set<text>, primary key((a)))", &[]);
+    query(
+        "create table if not exists counter_table(a int, b counter, c counter, primary key((a)))",
+        &[],
+    );
+    query(
+        "create type if not exists address(street text, number int)",
+        &[],
+    );
+    query("create type if not exists person_details(address frozen<address>, previous_addresses list<frozen<address>>)", &[]);
+    query("create table if not exists udt_table(a int, address frozen<address>, details frozen<person_details>, addresses map<text, frozen<address>>, primary key((a)))", &[]);
 
     create_test_tables();
 
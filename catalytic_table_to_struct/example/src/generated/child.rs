@@ -1,15 +1,16 @@
 // Generated file
 #[allow(unused_imports)]
 use catalytic::query_transform::{
-    CountType, DeleteUnique, Insert, MultipleSelectQueryErrorTransform, QueryEntityVec,
-    QueryEntityVecResult, QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult,
-    SelectMultiple, SelectUnique, SelectUniqueExpect, SingleSelectQueryErrorTransform, Truncate,
-    TtlType, Update,
+    ConditionalMutation, CountType, DeleteMultiple, DeleteUnique, Insert, LightweightTransaction,
+    QueryEntityVec, QueryEntityVecResult, QueryResultConditional, QueryResultLwt,
+    QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult, SelectMultiple,
+    SelectUnique, SelectUniqueExpect, TimestampType, TokenType, Truncate, TtlType, Update,
+    UpdateCounter, WithMetadata,
 };
+use catalytic::Error;
 #[allow(unused_imports)]
 use scylla::frame::value::SerializeValuesError;
 use scylla::frame::value::SerializedValues;
-use scylla::transport::errors::QueryError;
 use scylla::transport::iterator::TypedRowIterator;
 use scylla::CachingSession;
 #[doc = r" The query to select all rows in the table"]
@@ -22,20 +23,61 @@ pub const INSERT_QUERY: &str =
 #[doc = r" The query to insert a unique row in the table with a TTL"]
 pub const INSERT_TTL_QUERY: &str =
     "insert into child(birthday, enum_json, json, json_nullable) values (?, ?, ?, ?) using ttl ?";
+#[doc = r" The query to insert a unique row in the table if it doesn't exist yet"]
+pub const INSERT_IF_NOT_EXISTS_QUERY: &str =
+    "insert into child(birthday, enum_json, json, json_nullable) values (?, ?, ?, ?) if not exists";
+#[doc = r" The query to insert a unique row in the table with a write timestamp"]
+pub const INSERT_WITH_QUERY: &str = "insert into child(birthday, enum_json, json, json_nullable) values (?, ?, ?, ?) using timestamp ?";
 #[doc = r" The query truncate the whole table"]
 pub const TRUNCATE_QUERY: &str = "truncate child";
 #[doc = r" The query to retrieve a unique row in this table"]
 pub const SELECT_UNIQUE_QUERY: &str =
     "select birthday, enum_json, json, json_nullable from child where birthday = ?";
+#[doc = r" The query to retrieve a unique row in this table, including the writetime of the columns"]
+pub const SELECT_UNIQUE_WITH_WRITETIME_QUERY: &str = "select birthday, enum_json, json, json_nullable, writetime(enum_json), writetime(json), writetime(json_nullable) from child where birthday = ?";
+#[doc = "The row together with the writetime of the columns: enum_json, json, json_nullable"]
+pub type WithWritetime = WithMetadata<
+    Child,
+    (
+        Option<TimestampType>,
+        Option<TimestampType>,
+        Option<TimestampType>,
+    ),
+>;
 #[doc = "The query to update column enum_json"]
 pub const UPDATE_ENUM_JSON_QUERY: &str = "update child set enum_json = ? where birthday = ?";
+#[doc = "The query to update column enum_json if it has the expected value"]
+pub const UPDATE_ENUM_JSON_IF_QUERY: &str =
+    "update child set enum_json = ? where birthday = ? if enum_json = ?";
+#[doc = "The query to update column enum_json with a write timestamp"]
+pub const UPDATE_ENUM_JSON_WITH_QUERY: &str =
+    "update child using timestamp ? set enum_json = ? where birthday = ?";
 #[doc = "The query to update column json"]
 pub const UPDATE_JSON_QUERY: &str = "update child set json = ? where birthday = ?";
+#[doc = "The query to update column json if it has the expected value"]
+pub const UPDATE_JSON_IF_QUERY: &str = "update child set json = ? where birthday = ? if json = ?";
+#[doc = "The query to update column json with a write timestamp"]
+pub const UPDATE_JSON_WITH_QUERY: &str =
+    "update child using timestamp ? set json = ? where birthday = ?";
 #[doc = "The query to update column json_nullable"]
 pub const UPDATE_JSON_NULLABLE_QUERY: &str =
     "update child set json_nullable = ? where birthday = ?";
+#[doc = "The query to update column json_nullable if it has the expected value"]
+pub const UPDATE_JSON_NULLABLE_IF_QUERY: &str =
+    "update child set json_nullable = ? where birthday = ? if json_nullable = ?";
+#[doc = "The query to update column json_nullable with a write timestamp"]
+pub const UPDATE_JSON_NULLABLE_WITH_QUERY: &str =
+    "update child using timestamp ? set json_nullable = ? where birthday = ?";
 #[doc = r" The query to delete a unique row in the table"]
 pub const DELETE_QUERY: &str = "delete from child where birthday = ?";
+#[doc = r" The query to delete a unique row in the table if it exists"]
+pub const DELETE_IF_EXISTS_QUERY: &str = "delete from child where birthday = ? if exists";
+#[doc = r" The query to delete a unique row in the table with a write timestamp"]
+pub const DELETE_WITH_QUERY: &str = "delete from child using timestamp ? where birthday = ?";
+#[doc = r" The query to select the token of a partition"]
+pub const TOKEN_QUERY: &str = "select token(birthday) from child where birthday = ? limit 1";
+#[doc = r" The query to select the rows of which the token of the partition key is within a range"]
+pub const SELECT_BY_TOKEN_RANGE_QUERY: &str = "select birthday, enum_json, json, json_nullable from child where token(birthday) > ? and token(birthday) <= ?";
 #[doc = r" This is the struct which is generated from the table"]
 #[doc = r" If you want to perform CRUD operations, do the following:"]
 #[doc = r"     Create -> convert this struct to a borrowed struct"]
@@ -82,7 +124,7 @@ pub fn select_all_count_qv(
 #[doc = r" Performs the count query"]
 pub async fn select_all_count(
     session: &CachingSession,
-) -> Result<QueryResultUniqueRowExpect<CountType>, SingleSelectQueryErrorTransform> {
+) -> Result<QueryResultUniqueRowExpect<CountType>, Error> {
     select_all_count_qv().select_count(session).await
 }
 #[doc = r" Returns a struct that can perform a selection of all rows in the database"]
@@ -97,7 +139,7 @@ pub fn select_all_qv() -> SelectMultiple<Child, &'static str, &'static [u8; 0]>
 pub async fn select_all(
     session: &CachingSession,
     page_size: Option<i32>,
-) -> Result<TypedRowIterator<Child>, QueryError> {
+) -> Result<TypedRowIterator<Child>, Error> {
     select_all_qv().select(session, page_size).await
 }
 #[doc = r" Returns a struct that can perform a selection of all rows in the database"]
@@ -105,7 +147,7 @@ pub async fn select_all(
 pub async fn select_all_in_memory(
     session: &CachingSession,
     page_size: i32,
-) -> Result<QueryEntityVec<Child>, MultipleSelectQueryErrorTransform> {
+) -> Result<QueryEntityVec<Child>, Error> {
     select_all_qv()
         .select_all_in_memory(session, page_size)
         .await
@@ -165,7 +207,7 @@ pub async fn truncate(session: &CachingSession) -> ScyllaQueryResult {
 }
 impl<'a> ChildRef<'a> {
     #[doc = r" Returns a struct that can perform an insert operation"]
-    pub fn insert_qv(&self) -> Result<Insert, SerializeValuesError> {
+    pub fn insert_qv(&self) -> Result<Insert, Error> {
         let mut serialized = SerializedValues::with_capacity(4usize);
         serialized.add_value(&self.birthday)?;
         serialized.add_value(&self.enum_json)?;
@@ -182,7 +224,7 @@ impl<'a> ChildRef<'a> {
         self.insert_qv()?.insert(session).await
     }
     #[doc = r" Returns a struct that can perform an insert operation with a TTL"]
-    pub fn insert_ttl_qv(&self, ttl: TtlType) -> Result<Insert, SerializeValuesError> {
+    pub fn insert_ttl_qv(&self, ttl: TtlType) -> Result<Insert, Error> {
         let mut serialized = SerializedValues::with_capacity(5usize);
         serialized.add_value(&self.birthday)?;
         serialized.add_value(&self.enum_json)?;
@@ -199,6 +241,48 @@ impl<'a> ChildRef<'a> {
         tracing::debug!("Insert with ttl {}, {:#?}", ttl, self);
         self.insert_ttl_qv(ttl)?.insert(session).await
     }
+    #[doc = r" Returns a struct that can perform an insert operation, which is only applied if the row doesn't exist"]
+    pub fn insert_if_not_exists_qv(&self) -> Result<LightweightTransaction<Child>, Error> {
+        let mut serialized = SerializedValues::with_capacity(4usize);
+        serialized.add_value(&self.birthday)?;
+        serialized.add_value(&self.enum_json)?;
+        serialized.add_value(&self.json)?;
+        serialized.add_value(&self.json_nullable)?;
+        Ok(LightweightTransaction::new(Qv {
+            query: INSERT_IF_NOT_EXISTS_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert if the row doesn't exist, else the existing row is returned"]
+    pub async fn insert_if_not_exists(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultLwt<Child>, Error> {
+        tracing::debug!("Inserting if not exists: {:#?}", self);
+        self.insert_if_not_exists_qv()?.execute(session).await
+    }
+    #[doc = r" Returns a struct that can perform an insert operation with a write timestamp"]
+    pub fn insert_with_qv(&self, timestamp: TimestampType) -> Result<Insert, Error> {
+        let mut serialized = SerializedValues::with_capacity(5usize);
+        serialized.add_value(&self.birthday)?;
+        serialized.add_value(&self.enum_json)?;
+        serialized.add_value(&self.json)?;
+        serialized.add_value(&self.json_nullable)?;
+        serialized.add_value(&timestamp)?;
+        Ok(Insert::new(Qv {
+            query: INSERT_WITH_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert with a write timestamp, replaying it with the same timestamp has no additional effect"]
+    pub async fn insert_with(
+        &self,
+        session: &CachingSession,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!("Insert with timestamp {}, {:#?}", timestamp, self);
+        self.insert_with_qv(timestamp)?.insert(session).await
+    }
     #[doc = r" Performs either an insertion or deletion, depending on the insert parameter"]
     pub async fn insert_or_delete(
         &self,
@@ -271,7 +355,7 @@ impl From<PrimaryKeyRef<'_>> for PrimaryKey {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a unique row selection"]
-    pub fn select_unique_qv(&self) -> Result<SelectUnique<Child>, SerializeValuesError> {
+    pub fn select_unique_qv(&self) -> Result<SelectUnique<Child>, Error> {
         let mut serialized_values = SerializedValues::with_capacity(1usize);
         serialized_values.add_value(&self.birthday)?;
         Ok(SelectUnique::new(Qv {
@@ -283,7 +367,7 @@ impl PrimaryKeyRef<'_> {
     pub async fn select_unique(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRow<Child>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRow<Child>, Error> {
         tracing::debug!(
             "Selecting unique row for table {} with values: {:#?}",
             "child",
@@ -294,9 +378,7 @@ impl PrimaryKeyRef<'_> {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a unique row selection"]
-    pub fn select_unique_expect_qv(
-        &self,
-    ) -> Result<SelectUniqueExpect<Child>, SerializeValuesError> {
+    pub fn select_unique_expect_qv(&self) -> Result<SelectUniqueExpect<Child>, Error> {
         let mut serialized_values = SerializedValues::with_capacity(1usize);
         serialized_values.add_value(&self.birthday)?;
         Ok(SelectUniqueExpect::new(Qv {
@@ -308,7 +390,7 @@ impl PrimaryKeyRef<'_> {
     pub async fn select_unique_expect(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRowExpect<Child>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRowExpect<Child>, Error> {
         tracing::debug!(
             "Selecting unique row for table {} with values: {:#?}",
             "child",
@@ -318,11 +400,60 @@ impl PrimaryKeyRef<'_> {
     }
 }
 impl PrimaryKeyRef<'_> {
-    #[doc = "Returns a struct that can perform an update operation for column enum_json"]
-    pub fn update_enum_json_qv(
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_with_writetime_qv(&self) -> Result<SelectUnique<WithWritetime>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.birthday)?;
+        Ok(SelectUnique::new(Qv {
+            query: SELECT_UNIQUE_WITH_WRITETIME_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_with_writetime(
         &self,
-        val: &crate::MyJsonEnum,
-    ) -> Result<Update, SerializeValuesError> {
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRow<WithWritetime>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "child",
+            self
+        );
+        self.select_unique_with_writetime_qv()?
+            .select(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_with_writetime_expect_qv(
+        &self,
+    ) -> Result<SelectUniqueExpect<WithWritetime>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.birthday)?;
+        Ok(SelectUniqueExpect::new(Qv {
+            query: SELECT_UNIQUE_WITH_WRITETIME_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_with_writetime_expect(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRowExpect<WithWritetime>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "child",
+            self
+        );
+        self.select_unique_with_writetime_expect_qv()?
+            .select(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column enum_json"]
+    pub fn update_enum_json_qv(&self, val: &crate::MyJsonEnum) -> Result<Update, Error> {
         let mut serialized_values = SerializedValues::with_capacity(2usize);
         serialized_values.add_value(&val)?;
         serialized_values.add_value(&self.birthday)?;
@@ -346,9 +477,79 @@ impl PrimaryKeyRef<'_> {
         self.update_enum_json_qv(val)?.update(session).await
     }
 }
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column enum_json, which is only applied if the column has the expected value"]
+    pub fn update_enum_json_if_qv(
+        &self,
+        val: &crate::MyJsonEnum,
+        expected: &crate::MyJsonEnum,
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.birthday)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_ENUM_JSON_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column enum_json if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_enum_json_if(
+        &self,
+        session: &CachingSession,
+        val: &crate::MyJsonEnum,
+        expected: &crate::MyJsonEnum,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "child",
+            val,
+            expected,
+            self
+        );
+        self.update_enum_json_if_qv(val, expected)?
+            .execute(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column enum_json with a write timestamp"]
+    pub fn update_enum_json_with_qv(
+        &self,
+        val: &crate::MyJsonEnum,
+        timestamp: TimestampType,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.birthday)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_ENUM_JSON_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column enum_json with a write timestamp"]
+    pub async fn update_enum_json_with(
+        &self,
+        session: &CachingSession,
+        val: &crate::MyJsonEnum,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "child",
+            val,
+            timestamp,
+            self
+        );
+        self.update_enum_json_with_qv(val, timestamp)?
+            .update(session)
+            .await
+    }
+}
 impl PrimaryKeyRef<'_> {
     #[doc = "Returns a struct that can perform an update operation for column json"]
-    pub fn update_json_qv(&self, val: &crate::MyJsonType) -> Result<Update, SerializeValuesError> {
+    pub fn update_json_qv(&self, val: &crate::MyJsonType) -> Result<Update, Error> {
         let mut serialized_values = SerializedValues::with_capacity(2usize);
         serialized_values.add_value(&val)?;
         serialized_values.add_value(&self.birthday)?;
@@ -372,12 +573,82 @@ impl PrimaryKeyRef<'_> {
         self.update_json_qv(val)?.update(session).await
     }
 }
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column json, which is only applied if the column has the expected value"]
+    pub fn update_json_if_qv(
+        &self,
+        val: &crate::MyJsonType,
+        expected: &crate::MyJsonType,
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.birthday)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_JSON_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column json if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_json_if(
+        &self,
+        session: &CachingSession,
+        val: &crate::MyJsonType,
+        expected: &crate::MyJsonType,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "child",
+            val,
+            expected,
+            self
+        );
+        self.update_json_if_qv(val, expected)?
+            .execute(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column json with a write timestamp"]
+    pub fn update_json_with_qv(
+        &self,
+        val: &crate::MyJsonType,
+        timestamp: TimestampType,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.birthday)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_JSON_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column json with a write timestamp"]
+    pub async fn update_json_with(
+        &self,
+        session: &CachingSession,
+        val: &crate::MyJsonType,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "child",
+            val,
+            timestamp,
+            self
+        );
+        self.update_json_with_qv(val, timestamp)?
+            .update(session)
+            .await
+    }
+}
 impl PrimaryKeyRef<'_> {
     #[doc = "Returns a struct that can perform an update operation for column json_nullable"]
     pub fn update_json_nullable_qv(
         &self,
         val: &std::option::Option<crate::MyJsonType>,
-    ) -> Result<Update, SerializeValuesError> {
+    ) -> Result<Update, Error> {
         let mut serialized_values = SerializedValues::with_capacity(2usize);
         serialized_values.add_value(&val)?;
         serialized_values.add_value(&self.birthday)?;
@@ -402,11 +673,78 @@ impl PrimaryKeyRef<'_> {
     }
 }
 impl PrimaryKeyRef<'_> {
-    #[doc = r" Returns a struct that can perform an update on a dynamic updatable column"]
-    pub fn update_dyn_qv(
+    #[doc = "Returns a struct that can perform an update operation for column json_nullable, which is only applied if the column has the expected value"]
+    pub fn update_json_nullable_if_qv(
         &self,
-        val: UpdatableColumnRef<'_>,
-    ) -> Result<Update, SerializeValuesError> {
+        val: &std::option::Option<crate::MyJsonType>,
+        expected: &std::option::Option<crate::MyJsonType>,
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.birthday)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_JSON_NULLABLE_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column json_nullable if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_json_nullable_if(
+        &self,
+        session: &CachingSession,
+        val: &std::option::Option<crate::MyJsonType>,
+        expected: &std::option::Option<crate::MyJsonType>,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "child",
+            val,
+            expected,
+            self
+        );
+        self.update_json_nullable_if_qv(val, expected)?
+            .execute(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column json_nullable with a write timestamp"]
+    pub fn update_json_nullable_with_qv(
+        &self,
+        val: &std::option::Option<crate::MyJsonType>,
+        timestamp: TimestampType,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.birthday)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_JSON_NULLABLE_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column json_nullable with a write timestamp"]
+    pub async fn update_json_nullable_with(
+        &self,
+        session: &CachingSession,
+        val: &std::option::Option<crate::MyJsonType>,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "child",
+            val,
+            timestamp,
+            self
+        );
+        self.update_json_nullable_with_qv(val, timestamp)?
+            .update(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform an update on a dynamic updatable column"]
+    pub fn update_dyn_qv(&self, val: UpdatableColumnRef<'_>) -> Result<Update, Error> {
         match val {
             UpdatableColumnRef::EnumJson(val) => self.update_enum_json_qv(val),
             UpdatableColumnRef::Json(val) => self.update_json_qv(val),
@@ -427,7 +765,7 @@ impl PrimaryKeyRef<'_> {
     pub fn update_dyn_multiple_qv(
         &self,
         val: &[UpdatableColumnRef<'_>],
-    ) -> Result<Update<String, SerializedValues>, SerializeValuesError> {
+    ) -> Result<Update<String, SerializedValues>, Error> {
         if val.is_empty() {
             panic!("Empty update array")
         }
@@ -477,7 +815,7 @@ impl PrimaryKeyRef<'_> {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a single row deletion"]
-    pub fn delete_qv(&self) -> Result<DeleteUnique, SerializeValuesError> {
+    pub fn delete_qv(&self) -> Result<DeleteUnique, Error> {
         let mut serialized_values = SerializedValues::with_capacity(1usize);
         serialized_values.add_value(&self.birthday)?;
         Ok(DeleteUnique::new(Qv {
@@ -495,6 +833,141 @@ impl PrimaryKeyRef<'_> {
         self.delete_qv()?.delete_unique(session).await
     }
 }
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion, which is only applied if the row exists"]
+    pub fn delete_if_exists_qv(&self) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.birthday)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: DELETE_IF_EXISTS_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion if the row exists, the result tells if the row was deleted"]
+    pub async fn delete_if_exists(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Deleting a row if it exists from table {} with values {:#?}",
+            "child",
+            self
+        );
+        self.delete_if_exists_qv()?.execute(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion with a write timestamp"]
+    pub fn delete_with_qv(&self, timestamp: TimestampType) -> Result<DeleteUnique, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&self.birthday)?;
+        Ok(DeleteUnique::new(Qv {
+            query: DELETE_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion with a write timestamp, data written with a later timestamp is kept"]
+    pub async fn delete_with(
+        &self,
+        session: &CachingSession,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Deleting a row with timestamp {} from table {} with values {:#?}",
+            timestamp,
+            "child",
+            self
+        );
+        self.delete_with_qv(timestamp)?.delete_unique(session).await
+    }
+}
+#[doc = r" The partition key equals the primary key, since the table has no clustering columns"]
+pub type PartitionKey = PrimaryKey;
+#[doc = r" The borrowed partition key equals the borrowed primary key"]
+pub type PartitionKeyRef<'a> = PrimaryKeyRef<'a>;
+impl Child {
+    #[doc = r" Create a borrowed partition key from the struct values"]
+    pub fn partition_key(&self) -> PartitionKeyRef {
+        self.primary_key()
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a selection of the token of the partition"]
+    pub fn token_qv(&self) -> Result<SelectUnique<(TokenType,)>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.birthday)?;
+        Ok(SelectUnique::new(Qv {
+            query: TOKEN_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Selects the token of the partition, which determines the nodes that store the partition"]
+    #[doc = r" None is returned if the partition has no rows"]
+    pub async fn token(&self, session: &CachingSession) -> Result<Option<TokenType>, Error> {
+        tracing::debug!(
+            "Selecting token of partition of table {} with values {:#?}",
+            "child",
+            self
+        );
+        Ok(self
+            .token_qv()?
+            .select(session)
+            .await?
+            .entity
+            .map(|(token,)| token))
+    }
+}
+#[doc = r" Returns a struct that can perform a selection of the rows of which the token of the"]
+#[doc = r" partition key is within the range, the start is exclusive and the end is inclusive"]
+pub fn select_by_token_range_qv(
+    start: TokenType,
+    end: TokenType,
+) -> Result<SelectMultiple<Child, &'static str, SerializedValues>, Error> {
+    let mut serialized_values = SerializedValues::with_capacity(2);
+    serialized_values.add_value(&start)?;
+    serialized_values.add_value(&end)?;
+    Ok(SelectMultiple::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: serialized_values,
+    }))
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range, with a specified page size"]
+pub async fn select_by_token_range(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<Child>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select(session, page_size)
+        .await
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range and accumulates them in memory"]
+pub async fn select_by_token_range_in_memory(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: i32,
+) -> Result<QueryEntityVec<Child>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" Scans the whole table with a query per split of the token ring, of which at most 'concurrency'"]
+#[doc = r" are executed at the same time. The rows are returned in the order in which they arrive"]
+pub fn scan_all_parallel(
+    session: &CachingSession,
+    splits: usize,
+    concurrency: usize,
+) -> Result<impl catalytic::query_transform::Stream<Item = Result<Child, Error>> + '_, Error> {
+    tracing::debug!("Scanning table {} with {} splits", "child", splits);
+    SelectMultiple::<Child, _, _>::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: SerializedValues::new(),
+    })
+    .scan_parallel(session, splits, concurrency)
+}
 #[doc = r" This struct can be converted to a borrowed struct which can be used to update single rows"]
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
@@ -0,0 +1,1220 @@
+// Generated file
+#[allow(unused_imports)]
+use catalytic::query_transform::{
+    ConditionalMutation, CountType, DeleteMultiple, DeleteUnique, Insert, LightweightTransaction,
+    QueryEntityVec, QueryEntityVecResult, QueryResultConditional, QueryResultLwt,
+    QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult, SelectMultiple,
+    SelectUnique, SelectUniqueExpect, TimestampType, TokenType, Truncate, TtlType, Update,
+    UpdateCounter, WithMetadata,
+};
+use catalytic::Error;
+#[allow(unused_imports)]
+use scylla::frame::value::SerializeValuesError;
+use scylla::frame::value::SerializedValues;
+use scylla::transport::iterator::TypedRowIterator;
+use scylla::CachingSession;
+#[doc = r" The query to select all rows in the table"]
+pub const SELECT_ALL_QUERY: &str = "select a, l, m, s from collection_table";
+#[doc = r" The query to count all rows in the table"]
+pub const SELECT_ALL_COUNT_QUERY: &str = "select count(*) from collection_table";
+#[doc = r" The query to insert a unique row in the table"]
+pub const INSERT_QUERY: &str = "insert into collection_table(a, l, m, s) values (?, ?, ?, ?)";
+#[doc = r" The query to insert a unique row in the table with a TTL"]
+pub const INSERT_TTL_QUERY: &str =
+    "insert into collection_table(a, l, m, s) values (?, ?, ?, ?) using ttl ?";
+#[doc = r" The query to insert a unique row in the table if it doesn't exist yet"]
+pub const INSERT_IF_NOT_EXISTS_QUERY: &str =
+    "insert into collection_table(a, l, m, s) values (?, ?, ?, ?) if not exists";
+#[doc = r" The query to insert a unique row in the table with a write timestamp"]
+pub const INSERT_WITH_QUERY: &str =
+    "insert into collection_table(a, l, m, s) values (?, ?, ?, ?) using timestamp ?";
+#[doc = r" The query truncate the whole table"]
+pub const TRUNCATE_QUERY: &str = "truncate collection_table";
+#[doc = r" The query to retrieve a unique row in this table"]
+pub const SELECT_UNIQUE_QUERY: &str = "select a, l, m, s from collection_table where a = ?";
+#[doc = "The query to update column l"]
+pub const UPDATE_L_QUERY: &str = "update collection_table set l = ? where a = ?";
+#[doc = "The query to update column l if it has the expected value"]
+pub const UPDATE_L_IF_QUERY: &str = "update collection_table set l = ? where a = ? if l = ?";
+#[doc = "The query to update column l with a write timestamp"]
+pub const UPDATE_L_WITH_QUERY: &str =
+    "update collection_table using timestamp ? set l = ? where a = ?";
+#[doc = "The query to append to column l"]
+pub const APPEND_L_QUERY: &str = "update collection_table set l = l + ? where a = ?";
+#[doc = "The query to prepend to column l"]
+pub const PREPEND_L_QUERY: &str = "update collection_table set l = ? + l where a = ?";
+#[doc = "The query to remove elements from column l"]
+pub const REMOVE_FROM_L_QUERY: &str = "update collection_table set l = l - ? where a = ?";
+#[doc = "The query to update column m"]
+pub const UPDATE_M_QUERY: &str = "update collection_table set m = ? where a = ?";
+#[doc = "The query to update column m if it has the expected value"]
+pub const UPDATE_M_IF_QUERY: &str = "update collection_table set m = ? where a = ? if m = ?";
+#[doc = "The query to update column m with a write timestamp"]
+pub const UPDATE_M_WITH_QUERY: &str =
+    "update collection_table using timestamp ? set m = ? where a = ?";
+#[doc = "The query to put an entry in column m"]
+pub const PUT_M_QUERY: &str = "update collection_table set m[?] = ? where a = ?";
+#[doc = "The query to remove an entry from column m"]
+pub const REMOVE_KEY_M_QUERY: &str = "update collection_table set m = m - ? where a = ?";
+#[doc = "The query to update column s"]
+pub const UPDATE_S_QUERY: &str = "update collection_table set s = ? where a = ?";
+#[doc = "The query to update column s if it has the expected value"]
+pub const UPDATE_S_IF_QUERY: &str = "update collection_table set s = ? where a = ? if s = ?";
+#[doc = "The query to update column s with a write timestamp"]
+pub const UPDATE_S_WITH_QUERY: &str =
+    "update collection_table using timestamp ? set s = ? where a = ?";
+#[doc = "The query to append to column s"]
+pub const APPEND_S_QUERY: &str = "update collection_table set s = s + ? where a = ?";
+#[doc = "The query to remove elements from column s"]
+pub const REMOVE_FROM_S_QUERY: &str = "update collection_table set s = s - ? where a = ?";
+#[doc = r" The query to delete a unique row in the table"]
+pub const DELETE_QUERY: &str = "delete from collection_table where a = ?";
+#[doc = r" The query to delete a unique row in the table if it exists"]
+pub const DELETE_IF_EXISTS_QUERY: &str = "delete from collection_table where a = ? if exists";
+#[doc = r" The query to delete a unique row in the table with a write timestamp"]
+pub const DELETE_WITH_QUERY: &str = "delete from collection_table using timestamp ? where a = ?";
+#[doc = r" The query to select the token of a partition"]
+pub const TOKEN_QUERY: &str = "select token(a) from collection_table where a = ? limit 1";
+#[doc = r" The query to select the rows of which the token of the partition key is within a range"]
+pub const SELECT_BY_TOKEN_RANGE_QUERY: &str =
+    "select a, l, m, s from collection_table where token(a) > ? and token(a) <= ?";
+#[doc = r" This is the struct which is generated from the table"]
+#[doc = r" If you want to perform CRUD operations, do the following:"]
+#[doc = r"     Create -> convert this struct to a borrowed struct"]
+#[doc = r"     Read, Update, Delete -> convert this struct to a borrowed primary key struct"]
+#[doc = r" When you converted this struct to the specified type, you will have methods available"]
+#[doc = r" for the things you want"]
+#[derive(
+    scylla :: FromRow, scylla :: ValueList, catalytic_macro :: Mirror, Debug, Clone, PartialEq,
+)]
+pub struct CollectionTable {
+    #[partition_key]
+    pub a: i32,
+    pub l: std::vec::Vec<i32>,
+    pub m: std::collections::HashMap<String, (i32, String)>,
+    pub s: std::collections::HashSet<String>,
+}
+impl CollectionTable {
+    #[doc = r" Create an borrowed primary key from the struct values"]
+    #[doc = r" You can use this primary key struct to perform updates, deletions and selects on"]
+    #[doc = r" a unique row"]
+    pub fn primary_key(&self) -> PrimaryKeyRef {
+        PrimaryKeyRef { a: &self.a }
+    }
+    #[doc = r" Create an owned primary key from the struct values"]
+    pub fn primary_key_owned(self) -> PrimaryKey {
+        PrimaryKey { a: self.a }
+    }
+}
+#[doc = r" Returns a struct that can perform a query which counts the rows in this table"]
+pub fn select_all_count_qv(
+) -> SelectUniqueExpect<catalytic::query_transform::Count, &'static str, &'static [u8; 0]> {
+    SelectUniqueExpect::new(Qv {
+        query: SELECT_ALL_COUNT_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Performs the count query"]
+pub async fn select_all_count(
+    session: &CachingSession,
+) -> Result<QueryResultUniqueRowExpect<CountType>, Error> {
+    select_all_count_qv().select_count(session).await
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+pub fn select_all_qv() -> SelectMultiple<CollectionTable, &'static str, &'static [u8; 0]> {
+    SelectMultiple::new(Qv {
+        query: SELECT_ALL_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+#[doc = r" with a specified page size"]
+pub async fn select_all(
+    session: &CachingSession,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<CollectionTable>, Error> {
+    select_all_qv().select(session, page_size).await
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+#[doc = r" It will accumulate all rows in memory by sending paged queries"]
+pub async fn select_all_in_memory(
+    session: &CachingSession,
+    page_size: i32,
+) -> Result<QueryEntityVec<CollectionTable>, Error> {
+    select_all_qv()
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" A struct that contains borrowed values"]
+#[doc = r" This can be used to perform an insertion that is unique identified by the values of this struct"]
+#[doc = r" If you want to perform an update, deletion or select or a unique row, convert this"]
+#[doc = r" struct to the primary key struct"]
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub struct CollectionTableRef<'a> {
+    pub a: &'a i32,
+    pub l: &'a [i32],
+    pub m: &'a std::collections::HashMap<String, (i32, String)>,
+    pub s: &'a std::collections::HashSet<String>,
+}
+impl From<CollectionTableRef<'_>> for CollectionTable {
+    #[doc = r" Conversation method to go from a borrowed struct to an owned struct"]
+    fn from(f: CollectionTableRef<'_>) -> CollectionTable {
+        CollectionTable {
+            a: f.a.clone(),
+            l: f.l.to_vec(),
+            m: f.m.clone(),
+            s: f.s.clone(),
+        }
+    }
+}
+impl CollectionTable {
+    #[doc = r" Conversation method to go from an owned struct to a borrowed struct"]
+    pub fn to_ref(&self) -> CollectionTableRef {
+        CollectionTableRef {
+            a: &self.a,
+            l: &self.l,
+            m: &self.m,
+            s: &self.s,
+        }
+    }
+}
+impl<'a> CollectionTableRef<'a> {
+    #[doc = r" Conversation method to go from a borrowed struct to an owned struct"]
+    pub fn primary_key(&self) -> PrimaryKeyRef {
+        PrimaryKeyRef { a: self.a }
+    }
+}
+#[doc = r" Returns a struct that can perform a truncate operation"]
+pub fn truncate_qv() -> Truncate<&'static str, &'static [u8; 0]> {
+    Truncate::new(Qv {
+        query: TRUNCATE_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Performs a truncate"]
+#[doc = r" !This will delete all rows in the table!"]
+pub async fn truncate(session: &CachingSession) -> ScyllaQueryResult {
+    truncate_qv().truncate(session).await
+}
+impl<'a> CollectionTableRef<'a> {
+    #[doc = r" Returns a struct that can perform an insert operation"]
+    pub fn insert_qv(&self) -> Result<Insert, Error> {
+        let mut serialized = SerializedValues::with_capacity(4usize);
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.l)?;
+        serialized.add_value(&self.m)?;
+        serialized.add_value(&self.s)?;
+        Ok(Insert::new(Qv {
+            query: INSERT_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert"]
+    pub async fn insert(&self, session: &CachingSession) -> ScyllaQueryResult {
+        tracing::debug!("Inserting: {:#?}", self);
+        self.insert_qv()?.insert(session).await
+    }
+    #[doc = r" Returns a struct that can perform an insert operation with a TTL"]
+    pub fn insert_ttl_qv(&self, ttl: TtlType) -> Result<Insert, Error> {
+        let mut serialized = SerializedValues::with_capacity(5usize);
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.l)?;
+        serialized.add_value(&self.m)?;
+        serialized.add_value(&self.s)?;
+        serialized.add_value(&ttl)?;
+        Ok(Insert::new(Qv {
+            query: INSERT_TTL_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert with a TTL"]
+    pub async fn insert_ttl(&self, session: &CachingSession, ttl: TtlType) -> ScyllaQueryResult {
+        tracing::debug!("Insert with ttl {}, {:#?}", ttl, self);
+        self.insert_ttl_qv(ttl)?.insert(session).await
+    }
+    #[doc = r" Returns a struct that can perform an insert operation, which is only applied if the row doesn't exist"]
+    pub fn insert_if_not_exists_qv(
+        &self,
+    ) -> Result<LightweightTransaction<CollectionTable>, Error> {
+        let mut serialized = SerializedValues::with_capacity(4usize);
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.l)?;
+        serialized.add_value(&self.m)?;
+        serialized.add_value(&self.s)?;
+        Ok(LightweightTransaction::new(Qv {
+            query: INSERT_IF_NOT_EXISTS_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert if the row doesn't exist, else the existing row is returned"]
+    pub async fn insert_if_not_exists(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultLwt<CollectionTable>, Error> {
+        tracing::debug!("Inserting if not exists: {:#?}", self);
+        self.insert_if_not_exists_qv()?.execute(session).await
+    }
+    #[doc = r" Returns a struct that can perform an insert operation with a write timestamp"]
+    pub fn insert_with_qv(&self, timestamp: TimestampType) -> Result<Insert, Error> {
+        let mut serialized = SerializedValues::with_capacity(5usize);
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.l)?;
+        serialized.add_value(&self.m)?;
+        serialized.add_value(&self.s)?;
+        serialized.add_value(&timestamp)?;
+        Ok(Insert::new(Qv {
+            query: INSERT_WITH_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert with a write timestamp, replaying it with the same timestamp has no additional effect"]
+    pub async fn insert_with(
+        &self,
+        session: &CachingSession,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!("Insert with timestamp {}, {:#?}", timestamp, self);
+        self.insert_with_qv(timestamp)?.insert(session).await
+    }
+    #[doc = r" Performs either an insertion or deletion, depending on the insert parameter"]
+    pub async fn insert_or_delete(
+        &self,
+        session: &CachingSession,
+        insert: bool,
+    ) -> ScyllaQueryResult {
+        if insert {
+            self.insert(session).await
+        } else {
+            self.primary_key().delete(session).await
+        }
+    }
+}
+impl CollectionTable {
+    #[doc = r" Performs an update on the current struct based on the update parameter"]
+    pub fn in_memory_update(&mut self, update: UpdatableColumn) {
+        match update {
+            UpdatableColumn::L(val) => {
+                self.l = val;
+            }
+            UpdatableColumn::AppendL(val) => {
+                self.l.extend(val);
+            }
+            UpdatableColumn::PrependL(val) => {
+                self.l = val.into_iter().chain(self.l.drain(..)).collect();
+            }
+            UpdatableColumn::RemoveFromL(val) => {
+                self.l.retain(|v| !val.contains(v));
+            }
+            UpdatableColumn::M(val) => {
+                self.m = val;
+            }
+            UpdatableColumn::PutM(key, value) => {
+                self.m.insert(key, value);
+            }
+            UpdatableColumn::RemoveKeyM(key) => {
+                self.m.remove(&key);
+            }
+            UpdatableColumn::S(val) => {
+                self.s = val;
+            }
+            UpdatableColumn::AppendS(val) => {
+                self.s.extend(val);
+            }
+            UpdatableColumn::RemoveFromS(val) => {
+                for v in &val {
+                    self.s.remove(v);
+                }
+            }
+        }
+    }
+    #[doc = r" Performs multiple updates on the current struct"]
+    pub fn in_memory_updates(&mut self, updates: Vec<UpdatableColumn>) {
+        for updatable_column in updates {
+            self.in_memory_update(updatable_column)
+        }
+    }
+}
+#[doc = r" The owned primary key struct"]
+#[doc = r" If you want to perform a read, delete or update, convert it to the borrowed type"]
+#[derive(catalytic_macro :: PrimaryKey, Debug, Clone, PartialEq)]
+pub struct PrimaryKey {
+    #[partition_key]
+    pub a: i32,
+}
+#[doc = r" The borrowed primary key struct"]
+#[doc = r" This struct can be used to perform reads, deletes and updates"]
+#[derive(catalytic_macro :: PrimaryKey, Copy, Debug, Clone, PartialEq)]
+pub struct PrimaryKeyRef<'a> {
+    pub a: &'a i32,
+}
+#[doc = r" Conversation method to go from a borrowed primary key to an owned primary key"]
+impl PrimaryKeyRef<'_> {
+    pub fn into_owned(self) -> PrimaryKey {
+        self.into()
+    }
+}
+#[doc = r" Conversation method to go from an owned primary key to an borrowed primary key"]
+impl PrimaryKey {
+    pub fn to_ref(&self) -> PrimaryKeyRef<'_> {
+        PrimaryKeyRef { a: &self.a }
+    }
+}
+#[doc = r" Conversation method to go from a borrowed primary key to an owned primary key"]
+impl From<PrimaryKeyRef<'_>> for PrimaryKey {
+    fn from(f: PrimaryKeyRef<'_>) -> PrimaryKey {
+        PrimaryKey { a: f.a.clone() }
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_qv(&self) -> Result<SelectUnique<CollectionTable>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUnique::new(Qv {
+            query: SELECT_UNIQUE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRow<CollectionTable>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "collection_table",
+            self
+        );
+        self.select_unique_qv()?.select(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_expect_qv(&self) -> Result<SelectUniqueExpect<CollectionTable>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUniqueExpect::new(Qv {
+            query: SELECT_UNIQUE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_expect(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRowExpect<CollectionTable>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "collection_table",
+            self
+        );
+        self.select_unique_expect_qv()?.select(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column l"]
+    pub fn update_l_qv(&self, val: &[i32]) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_L_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column l"]
+    pub async fn update_l(&self, session: &CachingSession, val: &[i32]) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.update_l_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column l, which is only applied if the column has the expected value"]
+    pub fn update_l_if_qv(
+        &self,
+        val: &[i32],
+        expected: &[i32],
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_L_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column l if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_l_if(
+        &self,
+        session: &CachingSession,
+        val: &[i32],
+        expected: &[i32],
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            expected,
+            self
+        );
+        self.update_l_if_qv(val, expected)?.execute(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column l with a write timestamp"]
+    pub fn update_l_with_qv(&self, val: &[i32], timestamp: TimestampType) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_L_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column l with a write timestamp"]
+    pub async fn update_l_with(
+        &self,
+        session: &CachingSession,
+        val: &[i32],
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "collection_table",
+            val,
+            timestamp,
+            self
+        );
+        self.update_l_with_qv(val, timestamp)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to append to column l"]
+    pub fn append_l_qv(&self, val: &[i32]) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: APPEND_L_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to append to column l"]
+    pub async fn append_l(&self, session: &CachingSession, val: &[i32]) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.append_l_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to prepend to column l"]
+    pub fn prepend_l_qv(&self, val: &[i32]) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: PREPEND_L_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to prepend to column l"]
+    pub async fn prepend_l(&self, session: &CachingSession, val: &[i32]) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.prepend_l_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to remove elements from column l"]
+    pub fn remove_from_l_qv(&self, val: &[i32]) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: REMOVE_FROM_L_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to remove elements from column l"]
+    pub async fn remove_from_l(&self, session: &CachingSession, val: &[i32]) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.remove_from_l_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column m"]
+    pub fn update_m_qv(
+        &self,
+        val: &std::collections::HashMap<String, (i32, String)>,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_M_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column m"]
+    pub async fn update_m(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashMap<String, (i32, String)>,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.update_m_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column m, which is only applied if the column has the expected value"]
+    pub fn update_m_if_qv(
+        &self,
+        val: &std::collections::HashMap<String, (i32, String)>,
+        expected: &std::collections::HashMap<String, (i32, String)>,
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_M_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column m if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_m_if(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashMap<String, (i32, String)>,
+        expected: &std::collections::HashMap<String, (i32, String)>,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            expected,
+            self
+        );
+        self.update_m_if_qv(val, expected)?.execute(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column m with a write timestamp"]
+    pub fn update_m_with_qv(
+        &self,
+        val: &std::collections::HashMap<String, (i32, String)>,
+        timestamp: TimestampType,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_M_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column m with a write timestamp"]
+    pub async fn update_m_with(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashMap<String, (i32, String)>,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "collection_table",
+            val,
+            timestamp,
+            self
+        );
+        self.update_m_with_qv(val, timestamp)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to put an entry in column m"]
+    pub fn put_m_qv(&self, key: &str, value: &(i32, String)) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&key)?;
+        serialized_values.add_value(&value)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: PUT_M_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to put an entry in column m"]
+    pub async fn put_m(
+        &self,
+        session: &CachingSession,
+        key: &str,
+        value: &(i32, String),
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "collection_table",
+            (key, value),
+            self
+        );
+        self.put_m_qv(key, value)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to remove an entry from column m"]
+    pub fn remove_key_m_qv(&self, key: &str) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&std::slice::from_ref(&key))?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: REMOVE_KEY_M_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to remove an entry from column m"]
+    pub async fn remove_key_m(&self, session: &CachingSession, key: &str) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "collection_table",
+            key,
+            self
+        );
+        self.remove_key_m_qv(key)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column s"]
+    pub fn update_s_qv(&self, val: &std::collections::HashSet<String>) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_S_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column s"]
+    pub async fn update_s(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashSet<String>,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.update_s_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column s, which is only applied if the column has the expected value"]
+    pub fn update_s_if_qv(
+        &self,
+        val: &std::collections::HashSet<String>,
+        expected: &std::collections::HashSet<String>,
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_S_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column s if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_s_if(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashSet<String>,
+        expected: &std::collections::HashSet<String>,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            expected,
+            self
+        );
+        self.update_s_if_qv(val, expected)?.execute(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column s with a write timestamp"]
+    pub fn update_s_with_qv(
+        &self,
+        val: &std::collections::HashSet<String>,
+        timestamp: TimestampType,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_S_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column s with a write timestamp"]
+    pub async fn update_s_with(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashSet<String>,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "collection_table",
+            val,
+            timestamp,
+            self
+        );
+        self.update_s_with_qv(val, timestamp)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to append to column s"]
+    pub fn append_s_qv(&self, val: &std::collections::HashSet<String>) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: APPEND_S_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to append to column s"]
+    pub async fn append_s(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashSet<String>,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.append_s_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to remove elements from column s"]
+    pub fn remove_from_s_qv(
+        &self,
+        val: &std::collections::HashSet<String>,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: REMOVE_FROM_S_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to remove elements from column s"]
+    pub async fn remove_from_s(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashSet<String>,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.remove_from_s_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform an update on a dynamic updatable column"]
+    pub fn update_dyn_qv(&self, val: UpdatableColumnRef<'_>) -> Result<Update, Error> {
+        match val {
+            UpdatableColumnRef::L(val) => self.update_l_qv(val),
+            UpdatableColumnRef::AppendL(val) => self.append_l_qv(val),
+            UpdatableColumnRef::PrependL(val) => self.prepend_l_qv(val),
+            UpdatableColumnRef::RemoveFromL(val) => self.remove_from_l_qv(val),
+            UpdatableColumnRef::M(val) => self.update_m_qv(val),
+            UpdatableColumnRef::PutM(key, value) => self.put_m_qv(key, value),
+            UpdatableColumnRef::RemoveKeyM(key) => self.remove_key_m_qv(key),
+            UpdatableColumnRef::S(val) => self.update_s_qv(val),
+            UpdatableColumnRef::AppendS(val) => self.append_s_qv(val),
+            UpdatableColumnRef::RemoveFromS(val) => self.remove_from_s_qv(val),
+        }
+    }
+    #[doc = r" Performs the dynamic update"]
+    pub async fn update_dyn(
+        &self,
+        session: &CachingSession,
+        val: UpdatableColumnRef<'_>,
+    ) -> ScyllaQueryResult {
+        self.update_dyn_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a dynamic amount of column updates"]
+    pub fn update_dyn_multiple_qv(
+        &self,
+        val: &[UpdatableColumnRef<'_>],
+    ) -> Result<Update<String, SerializedValues>, Error> {
+        if val.is_empty() {
+            panic!("Empty update array")
+        }
+        let mut query = vec![];
+        let mut serialized_values = SerializedValues::with_capacity(val.len() + 1usize);
+        for v in val {
+            match v {
+                UpdatableColumnRef::L(v) => {
+                    query.push(concat!(stringify!(l), " = ?"));
+                    serialized_values.add_value(v)?;
+                }
+                UpdatableColumnRef::AppendL(val) => {
+                    query.push("l = l + ?");
+                    serialized_values.add_value(&val)?;
+                }
+                UpdatableColumnRef::PrependL(val) => {
+                    query.push("l = ? + l");
+                    serialized_values.add_value(&val)?;
+                }
+                UpdatableColumnRef::RemoveFromL(val) => {
+                    query.push("l = l - ?");
+                    serialized_values.add_value(&val)?;
+                }
+                UpdatableColumnRef::M(v) => {
+                    query.push(concat!(stringify!(m), " = ?"));
+                    serialized_values.add_value(v)?;
+                }
+                UpdatableColumnRef::PutM(key, value) => {
+                    query.push("m[?] = ?");
+                    serialized_values.add_value(&key)?;
+                    serialized_values.add_value(&value)?;
+                }
+                UpdatableColumnRef::RemoveKeyM(key) => {
+                    query.push("m = m - ?");
+                    serialized_values.add_value(&std::slice::from_ref(&key))?;
+                }
+                UpdatableColumnRef::S(v) => {
+                    query.push(concat!(stringify!(s), " = ?"));
+                    serialized_values.add_value(v)?;
+                }
+                UpdatableColumnRef::AppendS(val) => {
+                    query.push("s = s + ?");
+                    serialized_values.add_value(&val)?;
+                }
+                UpdatableColumnRef::RemoveFromS(val) => {
+                    query.push("s = s - ?");
+                    serialized_values.add_value(&val)?;
+                }
+            }
+        }
+        let columns_to_update: String = query.join(", ");
+        let update_statement = format!(
+            "update {} set {} {}",
+            "collection_table", columns_to_update, "where a = ?"
+        );
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: update_statement,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the dynamic column updates"]
+    pub async fn update_dyn_multiple(
+        &self,
+        session: &CachingSession,
+        val: &[UpdatableColumnRef<'_>],
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with vals {:#?} for row {:#?}",
+            "collection_table",
+            val,
+            self
+        );
+        self.update_dyn_multiple_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion"]
+    pub fn delete_qv(&self) -> Result<DeleteUnique, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(DeleteUnique::new(Qv {
+            query: DELETE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion"]
+    pub async fn delete(&self, session: &CachingSession) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Deleting a row from table {} with values {:#?}",
+            "collection_table",
+            self
+        );
+        self.delete_qv()?.delete_unique(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion, which is only applied if the row exists"]
+    pub fn delete_if_exists_qv(&self) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: DELETE_IF_EXISTS_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion if the row exists, the result tells if the row was deleted"]
+    pub async fn delete_if_exists(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Deleting a row if it exists from table {} with values {:#?}",
+            "collection_table",
+            self
+        );
+        self.delete_if_exists_qv()?.execute(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion with a write timestamp"]
+    pub fn delete_with_qv(&self, timestamp: TimestampType) -> Result<DeleteUnique, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(DeleteUnique::new(Qv {
+            query: DELETE_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion with a write timestamp, data written with a later timestamp is kept"]
+    pub async fn delete_with(
+        &self,
+        session: &CachingSession,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Deleting a row with timestamp {} from table {} with values {:#?}",
+            timestamp,
+            "collection_table",
+            self
+        );
+        self.delete_with_qv(timestamp)?.delete_unique(session).await
+    }
+}
+#[doc = r" The partition key equals the primary key, since the table has no clustering columns"]
+pub type PartitionKey = PrimaryKey;
+#[doc = r" The borrowed partition key equals the borrowed primary key"]
+pub type PartitionKeyRef<'a> = PrimaryKeyRef<'a>;
+impl CollectionTable {
+    #[doc = r" Create a borrowed partition key from the struct values"]
+    pub fn partition_key(&self) -> PartitionKeyRef {
+        self.primary_key()
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a selection of the token of the partition"]
+    pub fn token_qv(&self) -> Result<SelectUnique<(TokenType,)>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUnique::new(Qv {
+            query: TOKEN_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Selects the token of the partition, which determines the nodes that store the partition"]
+    #[doc = r" None is returned if the partition has no rows"]
+    pub async fn token(&self, session: &CachingSession) -> Result<Option<TokenType>, Error> {
+        tracing::debug!(
+            "Selecting token of partition of table {} with values {:#?}",
+            "collection_table",
+            self
+        );
+        Ok(self
+            .token_qv()?
+            .select(session)
+            .await?
+            .entity
+            .map(|(token,)| token))
+    }
+}
+#[doc = r" Returns a struct that can perform a selection of the rows of which the token of the"]
+#[doc = r" partition key is within the range, the start is exclusive and the end is inclusive"]
+pub fn select_by_token_range_qv(
+    start: TokenType,
+    end: TokenType,
+) -> Result<SelectMultiple<CollectionTable, &'static str, SerializedValues>, Error> {
+    let mut serialized_values = SerializedValues::with_capacity(2);
+    serialized_values.add_value(&start)?;
+    serialized_values.add_value(&end)?;
+    Ok(SelectMultiple::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: serialized_values,
+    }))
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range, with a specified page size"]
+pub async fn select_by_token_range(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<CollectionTable>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select(session, page_size)
+        .await
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range and accumulates them in memory"]
+pub async fn select_by_token_range_in_memory(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: i32,
+) -> Result<QueryEntityVec<CollectionTable>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" Scans the whole table with a query per split of the token ring, of which at most 'concurrency'"]
+#[doc = r" are executed at the same time. The rows are returned in the order in which they arrive"]
+pub fn scan_all_parallel(
+    session: &CachingSession,
+    splits: usize,
+    concurrency: usize,
+) -> Result<
+    impl catalytic::query_transform::Stream<Item = Result<CollectionTable, Error>> + '_,
+    Error,
+> {
+    tracing::debug!(
+        "Scanning table {} with {} splits",
+        "collection_table",
+        splits
+    );
+    SelectMultiple::<CollectionTable, _, _>::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: SerializedValues::new(),
+    })
+    .scan_parallel(session, splits, concurrency)
+}
+#[doc = r" This struct can be converted to a borrowed struct which can be used to update single rows"]
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdatableColumn {
+    L(std::vec::Vec<i32>),
+    AppendL(std::vec::Vec<i32>),
+    PrependL(std::vec::Vec<i32>),
+    RemoveFromL(std::vec::Vec<i32>),
+    M(std::collections::HashMap<String, (i32, String)>),
+    PutM(String, (i32, String)),
+    RemoveKeyM(String),
+    S(std::collections::HashSet<String>),
+    AppendS(std::collections::HashSet<String>),
+    RemoveFromS(std::collections::HashSet<String>),
+}
+impl UpdatableColumn {
+    #[doc = r" Conversation method to go from an owned updatable column struct to a borrowed updatable column struct"]
+    pub fn to_ref(&self) -> UpdatableColumnRef<'_> {
+        match &self {
+            UpdatableColumn::L(v) => UpdatableColumnRef::L(v),
+            UpdatableColumn::AppendL(val) => UpdatableColumnRef::AppendL(val),
+            UpdatableColumn::PrependL(val) => UpdatableColumnRef::PrependL(val),
+            UpdatableColumn::RemoveFromL(val) => UpdatableColumnRef::RemoveFromL(val),
+            UpdatableColumn::M(v) => UpdatableColumnRef::M(v),
+            UpdatableColumn::PutM(key, value) => UpdatableColumnRef::PutM(key, value),
+            UpdatableColumn::RemoveKeyM(key) => UpdatableColumnRef::RemoveKeyM(key),
+            UpdatableColumn::S(v) => UpdatableColumnRef::S(v),
+            UpdatableColumn::AppendS(val) => UpdatableColumnRef::AppendS(val),
+            UpdatableColumn::RemoveFromS(val) => UpdatableColumnRef::RemoveFromS(val),
+        }
+    }
+}
+#[doc = r" This struct can be used to update columns"]
+#[doc = r" If you have a borrowed primary key and you want to update a column, you can pass in"]
+#[doc = r" one of the variants"]
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub enum UpdatableColumnRef<'a> {
+    L(&'a [i32]),
+    AppendL(&'a [i32]),
+    PrependL(&'a [i32]),
+    RemoveFromL(&'a [i32]),
+    M(&'a std::collections::HashMap<String, (i32, String)>),
+    PutM(&'a str, &'a (i32, String)),
+    RemoveKeyM(&'a str),
+    S(&'a std::collections::HashSet<String>),
+    AppendS(&'a std::collections::HashSet<String>),
+    RemoveFromS(&'a std::collections::HashSet<String>),
+}
+pub trait UpdatableColumnVec {
+    fn to_ref(&self) -> Vec<UpdatableColumnRef<'_>>;
+}
+impl UpdatableColumnVec for Vec<UpdatableColumn> {
+    #[doc = r" Conversation method to go from a vec of owned updatable column structs to a vec of borrowed updatable column structs"]
+    fn to_ref(&self) -> Vec<UpdatableColumnRef<'_>> {
+        self.iter().map(|v| v.to_ref()).collect()
+    }
+}
+impl From<UpdatableColumnRef<'_>> for UpdatableColumn {
+    #[doc = r" Conversation method to go from a borrowed updatable column struct to an owned updatable column struct"]
+    fn from(f: UpdatableColumnRef<'_>) -> UpdatableColumn {
+        match f {
+            UpdatableColumnRef::L(v) => UpdatableColumn::L(v.to_vec()),
+            UpdatableColumnRef::AppendL(val) => UpdatableColumn::AppendL(val.to_vec()),
+            UpdatableColumnRef::PrependL(val) => UpdatableColumn::PrependL(val.to_vec()),
+            UpdatableColumnRef::RemoveFromL(val) => UpdatableColumn::RemoveFromL(val.to_vec()),
+            UpdatableColumnRef::M(v) => UpdatableColumn::M(v.clone()),
+            UpdatableColumnRef::PutM(key, value) => {
+                UpdatableColumn::PutM(key.to_string(), value.clone())
+            }
+            UpdatableColumnRef::RemoveKeyM(key) => UpdatableColumn::RemoveKeyM(key.to_string()),
+            UpdatableColumnRef::S(v) => UpdatableColumn::S(v.clone()),
+            UpdatableColumnRef::AppendS(val) => UpdatableColumn::AppendS(val.clone()),
+            UpdatableColumnRef::RemoveFromS(val) => UpdatableColumn::RemoveFromS(val.clone()),
+        }
+    }
+}
+impl UpdatableColumnRef<'_> {
+    #[doc = r" Conversation method to go from a borrowed updatable column struct to an owned updatable column struct"]
+    pub fn into_owned(self) -> UpdatableColumn {
+        self.into()
+    }
+}
+impl CollectionTable {
+    #[doc = "Creates the updatable column l which can be used to update it in the database"]
+    pub fn updatable_column_l(&self) -> UpdatableColumnRef {
+        UpdatableColumnRef::L(&self.l)
+    }
+    #[doc = "Creates the updatable column m which can be used to update it in the database"]
+    pub fn updatable_column_m(&self) -> UpdatableColumnRef {
+        UpdatableColumnRef::M(&self.m)
+    }
+    #[doc = "Creates the updatable column s which can be used to update it in the database"]
+    pub fn updatable_column_s(&self) -> UpdatableColumnRef {
+        UpdatableColumnRef::S(&self.s)
+    }
+}
@@ -0,0 +1,454 @@
+// Generated file
+#[allow(unused_imports)]
+use catalytic::query_transform::{
+    ConditionalMutation, CountType, DeleteMultiple, DeleteUnique, Insert, LightweightTransaction,
+    QueryEntityVec, QueryEntityVecResult, QueryResultConditional, QueryResultLwt,
+    QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult, SelectMultiple,
+    SelectUnique, SelectUniqueExpect, TimestampType, TokenType, Truncate, TtlType, Update,
+    UpdateCounter, WithMetadata,
+};
+use catalytic::Error;
+#[allow(unused_imports)]
+use scylla::frame::value::SerializeValuesError;
+use scylla::frame::value::SerializedValues;
+use scylla::transport::iterator::TypedRowIterator;
+use scylla::CachingSession;
+#[doc = r" The query to select all rows in the table"]
+pub const SELECT_ALL_QUERY: &str = "select a, b, c from counter_table";
+#[doc = r" The query to count all rows in the table"]
+pub const SELECT_ALL_COUNT_QUERY: &str = "select count(*) from counter_table";
+#[doc = r" The query truncate the whole table"]
+pub const TRUNCATE_QUERY: &str = "truncate counter_table";
+#[doc = r" The query to retrieve a unique row in this table"]
+pub const SELECT_UNIQUE_QUERY: &str = "select a, b, c from counter_table where a = ?";
+#[doc = "The query to increment counter b"]
+pub const INCREMENT_B_QUERY: &str = "update counter_table set b = b + ? where a = ?";
+#[doc = "The query to decrement counter b"]
+pub const DECREMENT_B_QUERY: &str = "update counter_table set b = b - ? where a = ?";
+#[doc = "The query to increment counter c"]
+pub const INCREMENT_C_QUERY: &str = "update counter_table set c = c + ? where a = ?";
+#[doc = "The query to decrement counter c"]
+pub const DECREMENT_C_QUERY: &str = "update counter_table set c = c - ? where a = ?";
+#[doc = r" The query to add a delta to all counters at once"]
+pub const UPDATE_COUNTERS_QUERY: &str = "update counter_table set b = b + ?, c = c + ? where a = ?";
+#[doc = r" The query to delete a unique row in the table"]
+pub const DELETE_QUERY: &str = "delete from counter_table where a = ?";
+#[doc = r" The query to select the token of a partition"]
+pub const TOKEN_QUERY: &str = "select token(a) from counter_table where a = ? limit 1";
+#[doc = r" The query to select the rows of which the token of the partition key is within a range"]
+pub const SELECT_BY_TOKEN_RANGE_QUERY: &str =
+    "select a, b, c from counter_table where token(a) > ? and token(a) <= ?";
+#[doc = r" This is the struct which is generated from the table"]
+#[doc = r" If you want to perform CRUD operations, do the following:"]
+#[doc = r"     Create -> convert this struct to a borrowed struct"]
+#[doc = r"     Read, Update, Delete -> convert this struct to a borrowed primary key struct"]
+#[doc = r" When you converted this struct to the specified type, you will have methods available"]
+#[doc = r" for the things you want"]
+#[derive(
+    scylla :: FromRow, scylla :: ValueList, catalytic_macro :: Mirror, Debug, Clone, PartialEq,
+)]
+pub struct CounterTable {
+    #[partition_key]
+    pub a: i32,
+    pub b: scylla::frame::value::Counter,
+    pub c: scylla::frame::value::Counter,
+}
+impl CounterTable {
+    #[doc = r" Create an borrowed primary key from the struct values"]
+    #[doc = r" You can use this primary key struct to perform updates, deletions and selects on"]
+    #[doc = r" a unique row"]
+    pub fn primary_key(&self) -> PrimaryKeyRef {
+        PrimaryKeyRef { a: &self.a }
+    }
+    #[doc = r" Create an owned primary key from the struct values"]
+    pub fn primary_key_owned(self) -> PrimaryKey {
+        PrimaryKey { a: self.a }
+    }
+}
+#[doc = r" Returns a struct that can perform a query which counts the rows in this table"]
+pub fn select_all_count_qv(
+) -> SelectUniqueExpect<catalytic::query_transform::Count, &'static str, &'static [u8; 0]> {
+    SelectUniqueExpect::new(Qv {
+        query: SELECT_ALL_COUNT_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Performs the count query"]
+pub async fn select_all_count(
+    session: &CachingSession,
+) -> Result<QueryResultUniqueRowExpect<CountType>, Error> {
+    select_all_count_qv().select_count(session).await
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+pub fn select_all_qv() -> SelectMultiple<CounterTable, &'static str, &'static [u8; 0]> {
+    SelectMultiple::new(Qv {
+        query: SELECT_ALL_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+#[doc = r" with a specified page size"]
+pub async fn select_all(
+    session: &CachingSession,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<CounterTable>, Error> {
+    select_all_qv().select(session, page_size).await
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+#[doc = r" It will accumulate all rows in memory by sending paged queries"]
+pub async fn select_all_in_memory(
+    session: &CachingSession,
+    page_size: i32,
+) -> Result<QueryEntityVec<CounterTable>, Error> {
+    select_all_qv()
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" A struct that contains borrowed values"]
+#[doc = r" This can be used to perform an insertion that is unique identified by the values of this struct"]
+#[doc = r" If you want to perform an update, deletion or select or a unique row, convert this"]
+#[doc = r" struct to the primary key struct"]
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub struct CounterTableRef<'a> {
+    pub a: &'a i32,
+    pub b: &'a scylla::frame::value::Counter,
+    pub c: &'a scylla::frame::value::Counter,
+}
+impl From<CounterTableRef<'_>> for CounterTable {
+    #[doc = r" Conversation method to go from a borrowed struct to an owned struct"]
+    fn from(f: CounterTableRef<'_>) -> CounterTable {
+        CounterTable {
+            a: f.a.clone(),
+            b: f.b.clone(),
+            c: f.c.clone(),
+        }
+    }
+}
+impl CounterTable {
+    #[doc = r" Conversation method to go from an owned struct to a borrowed struct"]
+    pub fn to_ref(&self) -> CounterTableRef {
+        CounterTableRef {
+            a: &self.a,
+            b: &self.b,
+            c: &self.c,
+        }
+    }
+}
+impl<'a> CounterTableRef<'a> {
+    #[doc = r" Conversation method to go from a borrowed struct to an owned struct"]
+    pub fn primary_key(&self) -> PrimaryKeyRef {
+        PrimaryKeyRef { a: self.a }
+    }
+}
+#[doc = r" Returns a struct that can perform a truncate operation"]
+pub fn truncate_qv() -> Truncate<&'static str, &'static [u8; 0]> {
+    Truncate::new(Qv {
+        query: TRUNCATE_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Performs a truncate"]
+#[doc = r" !This will delete all rows in the table!"]
+pub async fn truncate(session: &CachingSession) -> ScyllaQueryResult {
+    truncate_qv().truncate(session).await
+}
+#[doc = r" The owned primary key struct"]
+#[doc = r" If you want to perform a read, delete or update, convert it to the borrowed type"]
+#[derive(catalytic_macro :: PrimaryKey, Debug, Clone, PartialEq)]
+pub struct PrimaryKey {
+    #[partition_key]
+    pub a: i32,
+}
+#[doc = r" The borrowed primary key struct"]
+#[doc = r" This struct can be used to perform reads, deletes and updates"]
+#[derive(catalytic_macro :: PrimaryKey, Copy, Debug, Clone, PartialEq)]
+pub struct PrimaryKeyRef<'a> {
+    pub a: &'a i32,
+}
+#[doc = r" Conversation method to go from a borrowed primary key to an owned primary key"]
+impl PrimaryKeyRef<'_> {
+    pub fn into_owned(self) -> PrimaryKey {
+        self.into()
+    }
+}
+#[doc = r" Conversation method to go from an owned primary key to an borrowed primary key"]
+impl PrimaryKey {
+    pub fn to_ref(&self) -> PrimaryKeyRef<'_> {
+        PrimaryKeyRef { a: &self.a }
+    }
+}
+#[doc = r" Conversation method to go from a borrowed primary key to an owned primary key"]
+impl From<PrimaryKeyRef<'_>> for PrimaryKey {
+    fn from(f: PrimaryKeyRef<'_>) -> PrimaryKey {
+        PrimaryKey { a: f.a.clone() }
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_qv(&self) -> Result<SelectUnique<CounterTable>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUnique::new(Qv {
+            query: SELECT_UNIQUE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRow<CounterTable>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "counter_table",
+            self
+        );
+        self.select_unique_qv()?.select(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_expect_qv(&self) -> Result<SelectUniqueExpect<CounterTable>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUniqueExpect::new(Qv {
+            query: SELECT_UNIQUE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_expect(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRowExpect<CounterTable>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "counter_table",
+            self
+        );
+        self.select_unique_expect_qv()?.select(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to increment counter b"]
+    pub fn increment_b_qv(&self, delta: i64) -> Result<UpdateCounter, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&delta)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(UpdateCounter::new(Qv {
+            query: INCREMENT_B_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to increment counter b"]
+    pub async fn increment_b(&self, session: &CachingSession, delta: i64) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with delta {} for row {:#?}",
+            "counter_table",
+            delta,
+            self
+        );
+        self.increment_b_qv(delta)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to decrement counter b"]
+    pub fn decrement_b_qv(&self, delta: i64) -> Result<UpdateCounter, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&delta)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(UpdateCounter::new(Qv {
+            query: DECREMENT_B_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to decrement counter b"]
+    pub async fn decrement_b(&self, session: &CachingSession, delta: i64) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with delta {} for row {:#?}",
+            "counter_table",
+            delta,
+            self
+        );
+        self.decrement_b_qv(delta)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to increment counter c"]
+    pub fn increment_c_qv(&self, delta: i64) -> Result<UpdateCounter, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&delta)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(UpdateCounter::new(Qv {
+            query: INCREMENT_C_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to increment counter c"]
+    pub async fn increment_c(&self, session: &CachingSession, delta: i64) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with delta {} for row {:#?}",
+            "counter_table",
+            delta,
+            self
+        );
+        self.increment_c_qv(delta)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to decrement counter c"]
+    pub fn decrement_c_qv(&self, delta: i64) -> Result<UpdateCounter, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&delta)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(UpdateCounter::new(Qv {
+            query: DECREMENT_C_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to decrement counter c"]
+    pub async fn decrement_c(&self, session: &CachingSession, delta: i64) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with delta {} for row {:#?}",
+            "counter_table",
+            delta,
+            self
+        );
+        self.decrement_c_qv(delta)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can add a delta to every counter, a negative delta decrements the counter"]
+    pub fn update_counters_qv(&self, b: i64, c: i64) -> Result<UpdateCounter, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&b)?;
+        serialized_values.add_value(&c)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(UpdateCounter::new(Qv {
+            query: UPDATE_COUNTERS_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Adds a delta to every counter, a negative delta decrements the counter"]
+    pub async fn update_counters(
+        &self,
+        session: &CachingSession,
+        b: i64,
+        c: i64,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating counters of table {} with deltas {:#?} for row {:#?}",
+            "counter_table",
+            (b, c),
+            self
+        );
+        self.update_counters_qv(b, c)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion"]
+    pub fn delete_qv(&self) -> Result<DeleteUnique, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(DeleteUnique::new(Qv {
+            query: DELETE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion"]
+    pub async fn delete(&self, session: &CachingSession) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Deleting a row from table {} with values {:#?}",
+            "counter_table",
+            self
+        );
+        self.delete_qv()?.delete_unique(session).await
+    }
+}
+#[doc = r" The partition key equals the primary key, since the table has no clustering columns"]
+pub type PartitionKey = PrimaryKey;
+#[doc = r" The borrowed partition key equals the borrowed primary key"]
+pub type PartitionKeyRef<'a> = PrimaryKeyRef<'a>;
+impl CounterTable {
+    #[doc = r" Create a borrowed partition key from the struct values"]
+    pub fn partition_key(&self) -> PartitionKeyRef {
+        self.primary_key()
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a selection of the token of the partition"]
+    pub fn token_qv(&self) -> Result<SelectUnique<(TokenType,)>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUnique::new(Qv {
+            query: TOKEN_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Selects the token of the partition, which determines the nodes that store the partition"]
+    #[doc = r" None is returned if the partition has no rows"]
+    pub async fn token(&self, session: &CachingSession) -> Result<Option<TokenType>, Error> {
+        tracing::debug!(
+            "Selecting token of partition of table {} with values {:#?}",
+            "counter_table",
+            self
+        );
+        Ok(self
+            .token_qv()?
+            .select(session)
+            .await?
+            .entity
+            .map(|(token,)| token))
+    }
+}
+#[doc = r" Returns a struct that can perform a selection of the rows of which the token of the"]
+#[doc = r" partition key is within the range, the start is exclusive and the end is inclusive"]
+pub fn select_by_token_range_qv(
+    start: TokenType,
+    end: TokenType,
+) -> Result<SelectMultiple<CounterTable, &'static str, SerializedValues>, Error> {
+    let mut serialized_values = SerializedValues::with_capacity(2);
+    serialized_values.add_value(&start)?;
+    serialized_values.add_value(&end)?;
+    Ok(SelectMultiple::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: serialized_values,
+    }))
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range, with a specified page size"]
+pub async fn select_by_token_range(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<CounterTable>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select(session, page_size)
+        .await
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range and accumulates them in memory"]
+pub async fn select_by_token_range_in_memory(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: i32,
+) -> Result<QueryEntityVec<CounterTable>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" Scans the whole table with a query per split of the token ring, of which at most 'concurrency'"]
+#[doc = r" are executed at the same time. The rows are returned in the order in which they arrive"]
+pub fn scan_all_parallel(
+    session: &CachingSession,
+    splits: usize,
+    concurrency: usize,
+) -> Result<impl catalytic::query_transform::Stream<Item = Result<CounterTable, Error>> + '_, Error>
+{
+    tracing::debug!("Scanning table {} with {} splits", "counter_table", splits);
+    SelectMultiple::<CounterTable, _, _>::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: SerializedValues::new(),
+    })
+    .scan_parallel(session, splits, concurrency)
+}
@@ -1,4 +1,6 @@
 // Generated file
+pub mod user_defined_types;
+pub use user_defined_types::{Address, PersonDetails};
 #[allow(dead_code, clippy::clone_on_copy)]
 pub mod another_test_table;
 pub use another_test_table::{AnotherTestTable, AnotherTestTableRef};
@@ -6,12 +8,21 @@ pub use another_test_table::{AnotherTestTable, AnotherTestTableRef};
 pub mod child;
 pub use child::{Child, ChildRef};
 #[allow(dead_code, clippy::clone_on_copy)]
+pub mod collection_table;
+pub use collection_table::{CollectionTable, CollectionTableRef};
+#[allow(dead_code, clippy::clone_on_copy)]
+pub mod counter_table;
+pub use counter_table::{CounterTable, CounterTableRef};
+#[allow(dead_code, clippy::clone_on_copy)]
 pub mod person;
 pub use person::{Person, PersonRef};
 #[allow(dead_code, clippy::clone_on_copy)]
 pub mod test_table;
 pub use test_table::{TestTable, TestTableRef};
 #[allow(dead_code, clippy::clone_on_copy)]
+pub mod udt_table;
+pub use udt_table::{UdtTable, UdtTableRef};
+#[allow(dead_code, clippy::clone_on_copy)]
 pub mod uuidtable;
 pub use uuidtable::{Uuidtable, UuidtableRef};
 #[allow(dead_code, clippy::clone_on_copy)]
@@ -2,15 +2,16 @@
 use super::person::Person;
 #[allow(unused_imports)]
 use catalytic::query_transform::{
-    CountType, DeleteUnique, Insert, MultipleSelectQueryErrorTransform, QueryEntityVec,
-    QueryEntityVecResult, QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult,
-    SelectMultiple, SelectUnique, SelectUniqueExpect, SingleSelectQueryErrorTransform, Truncate,
-    TtlType, Update,
+    ConditionalMutation, CountType, DeleteMultiple, DeleteUnique, Insert, LightweightTransaction,
+    QueryEntityVec, QueryEntityVecResult, QueryResultConditional, QueryResultLwt,
+    QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult, SelectMultiple,
+    SelectUnique, SelectUniqueExpect, TimestampType, TokenType, Truncate, TtlType, Update,
+    UpdateCounter, WithMetadata,
 };
+use catalytic::Error;
 #[allow(unused_imports)]
 use scylla::frame::value::SerializeValuesError;
 use scylla::frame::value::SerializedValues;
-use scylla::transport::errors::QueryError;
 use scylla::transport::iterator::TypedRowIterator;
 use scylla::CachingSession;
 #[doc = r" The query to select all rows in the table"]
@@ -27,6 +28,17 @@ pub const SELECT_UNIQUE_QUERY: &str =
     "select email, name, age from person_by_email where email = ? and name = ? and age = ?";
 pub const SELECT_UNIQUE_QUERY_BASE_TABLE: &str =
     "select name, age, email from person_by_email where email = ? and name = ? and age = ?";
+#[doc = r" The query to select the token of a partition"]
+pub const TOKEN_QUERY: &str = "select token(email) from person_by_email where email = ? limit 1";
+#[doc = r" The query to select the rows of which the token of the partition key is within a range"]
+pub const SELECT_BY_TOKEN_RANGE_QUERY: &str =
+    "select email, name, age from person_by_email where token(email) > ? and token(email) <= ?";
+#[doc = r" The query to select all rows in a partition"]
+pub const SELECT_PARTITION_QUERY: &str =
+    "select email, name, age from person_by_email where email = ?";
+#[doc = r" The query to select all rows in a partition, ordered descending by the first clustering column"]
+pub const SELECT_PARTITION_DESC_QUERY: &str =
+    "select email, name, age from person_by_email where email = ? order by name desc";
 #[doc = r" This is the struct which is generated from the table"]
 #[doc = r" If you want to perform CRUD operations, do the following:"]
 #[doc = r"     Create -> convert this struct to a borrowed struct"]
@@ -75,7 +87,7 @@ pub fn select_all_count_qv(
 #[doc = r" Performs the count query"]
 pub async fn select_all_count(
     session: &CachingSession,
-) -> Result<QueryResultUniqueRowExpect<CountType>, SingleSelectQueryErrorTransform> {
+) -> Result<QueryResultUniqueRowExpect<CountType>, Error> {
     select_all_count_qv().select_count(session).await
 }
 #[doc = r" Returns a struct that can perform a selection of all rows in the database"]
@@ -90,7 +102,7 @@ pub fn select_all_qv() -> SelectMultiple<PersonByEmail, &'static str, &'static [
 pub async fn select_all(
     session: &CachingSession,
     page_size: Option<i32>,
-) -> Result<TypedRowIterator<PersonByEmail>, QueryError> {
+) -> Result<TypedRowIterator<PersonByEmail>, Error> {
     select_all_qv().select(session, page_size).await
 }
 #[doc = r" Returns a struct that can perform a selection of all rows in the database"]
@@ -98,7 +110,7 @@ pub async fn select_all(
 pub async fn select_all_in_memory(
     session: &CachingSession,
     page_size: i32,
-) -> Result<QueryEntityVec<PersonByEmail>, MultipleSelectQueryErrorTransform> {
+) -> Result<QueryEntityVec<PersonByEmail>, Error> {
     select_all_qv()
         .select_all_in_memory(session, page_size)
         .await
@@ -155,7 +167,7 @@ pub fn select_all_base_table_qv() -> SelectMultiple<Person, &'static str, &'stat
 pub async fn select_all_base_table(
     session: &CachingSession,
     page_size: Option<i32>,
-) -> Result<TypedRowIterator<Person>, QueryError> {
+) -> Result<TypedRowIterator<Person>, Error> {
     select_all_base_table_qv().select(session, page_size).await
 }
 #[doc = r" Returns a struct that can perform a selection of all rows in the database"]
@@ -163,7 +175,7 @@ pub async fn select_all_base_table(
 pub async fn select_all_base_table_in_memory(
     session: &CachingSession,
     page_size: i32,
-) -> Result<QueryEntityVec<Person>, MultipleSelectQueryErrorTransform> {
+) -> Result<QueryEntityVec<Person>, Error> {
     select_all_base_table_qv()
         .select_all_in_memory(session, page_size)
         .await
@@ -215,7 +227,7 @@ impl From<PrimaryKeyRef<'_>> for PrimaryKey {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a unique row selection"]
-    pub fn select_unique_qv(&self) -> Result<SelectUnique<PersonByEmail>, SerializeValuesError> {
+    pub fn select_unique_qv(&self) -> Result<SelectUnique<PersonByEmail>, Error> {
         let mut serialized_values = SerializedValues::with_capacity(3usize);
         serialized_values.add_value(&self.email)?;
         serialized_values.add_value(&self.name)?;
@@ -229,7 +241,7 @@ impl PrimaryKeyRef<'_> {
     pub async fn select_unique(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRow<PersonByEmail>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRow<PersonByEmail>, Error> {
         tracing::debug!(
             "Selecting unique row for table {} with values: {:#?}",
             "person_by_email",
@@ -240,9 +252,7 @@ impl PrimaryKeyRef<'_> {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a unique row selection"]
-    pub fn select_unique_expect_qv(
-        &self,
-    ) -> Result<SelectUniqueExpect<PersonByEmail>, SerializeValuesError> {
+    pub fn select_unique_expect_qv(&self) -> Result<SelectUniqueExpect<PersonByEmail>, Error> {
         let mut serialized_values = SerializedValues::with_capacity(3usize);
         serialized_values.add_value(&self.email)?;
         serialized_values.add_value(&self.name)?;
@@ -256,7 +266,7 @@ impl PrimaryKeyRef<'_> {
     pub async fn select_unique_expect(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRowExpect<PersonByEmail>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRowExpect<PersonByEmail>, Error> {
         tracing::debug!(
             "Selecting unique row for table {} with values: {:#?}",
             "person_by_email",
@@ -267,9 +277,7 @@ impl PrimaryKeyRef<'_> {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a unique row selection"]
-    pub fn select_unique_base_table_qv(
-        &self,
-    ) -> Result<SelectUnique<Person>, SerializeValuesError> {
+    pub fn select_unique_base_table_qv(&self) -> Result<SelectUnique<Person>, Error> {
         let mut serialized_values = SerializedValues::with_capacity(3usize);
         serialized_values.add_value(&self.email)?;
         serialized_values.add_value(&self.name)?;
@@ -283,7 +291,7 @@ impl PrimaryKeyRef<'_> {
     pub async fn select_unique_base_table(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRow<Person>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRow<Person>, Error> {
         tracing::debug!(
             "Selecting unique row for table {} with values: {:#?}",
             "person_by_email",
@@ -294,9 +302,7 @@ impl PrimaryKeyRef<'_> {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a unique row selection"]
-    pub fn select_unique_expect_base_table_qv(
-        &self,
-    ) -> Result<SelectUniqueExpect<Person>, SerializeValuesError> {
+    pub fn select_unique_expect_base_table_qv(&self) -> Result<SelectUniqueExpect<Person>, Error> {
         let mut serialized_values = SerializedValues::with_capacity(3usize);
         serialized_values.add_value(&self.email)?;
         serialized_values.add_value(&self.name)?;
@@ -310,7 +316,7 @@ impl PrimaryKeyRef<'_> {
     pub async fn select_unique_expect_base_table(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRowExpect<Person>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRowExpect<Person>, Error> {
         tracing::debug!(
             "Selecting unique row for table {} with values: {:#?}",
             "person_by_email",
@@ -321,3 +327,216 @@ impl PrimaryKeyRef<'_> {
             .await
     }
 }
+#[doc = r" The owned partition key struct"]
+#[doc = r" A partition contains all the rows with the same partition key"]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartitionKey {
+    pub email: String,
+}
+#[doc = r" The borrowed partition key struct"]
+#[doc = r" This struct can be used to perform reads and deletes of a whole partition or of a range within the partition"]
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub struct PartitionKeyRef<'a> {
+    pub email: &'a str,
+}
+#[doc = r" Conversation method to go from a borrowed partition key to an owned partition key"]
+impl PartitionKeyRef<'_> {
+    pub fn into_owned(self) -> PartitionKey {
+        self.into()
+    }
+}
+#[doc = r" Conversation method to go from an owned partition key to a borrowed partition key"]
+impl PartitionKey {
+    pub fn to_ref(&self) -> PartitionKeyRef<'_> {
+        PartitionKeyRef { email: &self.email }
+    }
+}
+#[doc = r" Conversation method to go from a borrowed partition key to an owned partition key"]
+impl From<PartitionKeyRef<'_>> for PartitionKey {
+    fn from(f: PartitionKeyRef<'_>) -> PartitionKey {
+        PartitionKey {
+            email: f.email.to_string(),
+        }
+    }
+}
+impl PersonByEmail {
+    #[doc = r" Create a borrowed partition key from the struct values"]
+    pub fn partition_key(&self) -> PartitionKeyRef {
+        PartitionKeyRef { email: &self.email }
+    }
+}
+impl PrimaryKey {
+    #[doc = r" Create a borrowed partition key from the primary key"]
+    pub fn partition_key(&self) -> PartitionKeyRef {
+        PartitionKeyRef { email: &self.email }
+    }
+}
+impl<'a> PrimaryKeyRef<'a> {
+    #[doc = r" Create a borrowed partition key from the primary key"]
+    pub fn partition_key(&self) -> PartitionKeyRef<'a> {
+        PartitionKeyRef { email: self.email }
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a selection of the token of the partition"]
+    pub fn token_qv(&self) -> Result<SelectUnique<(TokenType,)>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.email)?;
+        Ok(SelectUnique::new(Qv {
+            query: TOKEN_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Selects the token of the partition, which determines the nodes that store the partition"]
+    #[doc = r" None is returned if the partition has no rows"]
+    pub async fn token(&self, session: &CachingSession) -> Result<Option<TokenType>, Error> {
+        tracing::debug!(
+            "Selecting token of partition of table {} with values {:#?}",
+            "person_by_email",
+            self
+        );
+        Ok(self
+            .token_qv()?
+            .select(session)
+            .await?
+            .entity
+            .map(|(token,)| token))
+    }
+}
+#[doc = r" Returns a struct that can perform a selection of the rows of which the token of the"]
+#[doc = r" partition key is within the range, the start is exclusive and the end is inclusive"]
+pub fn select_by_token_range_qv(
+    start: TokenType,
+    end: TokenType,
+) -> Result<SelectMultiple<PersonByEmail, &'static str, SerializedValues>, Error> {
+    let mut serialized_values = SerializedValues::with_capacity(2);
+    serialized_values.add_value(&start)?;
+    serialized_values.add_value(&end)?;
+    Ok(SelectMultiple::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: serialized_values,
+    }))
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range, with a specified page size"]
+pub async fn select_by_token_range(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<PersonByEmail>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select(session, page_size)
+        .await
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range and accumulates them in memory"]
+pub async fn select_by_token_range_in_memory(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: i32,
+) -> Result<QueryEntityVec<PersonByEmail>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" Scans the whole table with a query per split of the token ring, of which at most 'concurrency'"]
+#[doc = r" are executed at the same time. The rows are returned in the order in which they arrive"]
+pub fn scan_all_parallel(
+    session: &CachingSession,
+    splits: usize,
+    concurrency: usize,
+) -> Result<impl catalytic::query_transform::Stream<Item = Result<PersonByEmail, Error>> + '_, Error>
+{
+    tracing::debug!(
+        "Scanning table {} with {} splits",
+        "person_by_email",
+        splits
+    );
+    SelectMultiple::<PersonByEmail, _, _>::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: SerializedValues::new(),
+    })
+    .scan_parallel(session, splits, concurrency)
+}
+#[doc = r" A prefix of the clustering columns, used as a bound of a range within a partition"]
+#[doc = r" The variant is named after the last column of the prefix"]
+#[derive(Copy, Clone, Debug)]
+pub enum ClusteringPrefix<'a> {
+    #[doc = "Restricts the columns: name"]
+    Name(&'a str),
+    #[doc = "Restricts the columns: name, age"]
+    Age(&'a str, &'a i32),
+}
+impl catalytic::clustering_range::ClusteringColumns for ClusteringPrefix<'_> {
+    fn columns(&self) -> &'static [&'static str] {
+        match self {
+            ClusteringPrefix::Name(..) => &["name"],
+            ClusteringPrefix::Age(..) => &["name", "age"],
+        }
+    }
+    fn add_values(&self, values: &mut SerializedValues) -> Result<(), SerializeValuesError> {
+        match self {
+            ClusteringPrefix::Name(name) => {
+                values.add_value(name)?;
+            }
+            ClusteringPrefix::Age(name, age) => {
+                values.add_value(name)?;
+                values.add_value(age)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a selection of all rows in the partition"]
+    #[doc = r" If descending is true, the rows are ordered descending by the first clustering column"]
+    pub fn select_partition(
+        &self,
+        descending: bool,
+    ) -> Result<SelectMultiple<PersonByEmail>, Error> {
+        tracing::debug!(
+            "Selecting partition of table {} with values {:#?}",
+            "person_by_email",
+            self
+        );
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.email)?;
+        Ok(SelectMultiple::new(Qv {
+            query: if descending {
+                SELECT_PARTITION_DESC_QUERY
+            } else {
+                SELECT_PARTITION_QUERY
+            },
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Returns a struct that can perform a selection of the rows in the partition within a range of the clustering columns"]
+    #[doc = r" Both bounds can restrict a different prefix of the clustering columns, e.g."]
+    #[doc = r" ClusteringPrefix::A(&a)..=ClusteringPrefix::B(&a, &b)"]
+    #[doc = r" If descending is true, the rows are ordered descending by the first clustering column"]
+    pub fn select_partition_range<'b>(
+        &self,
+        range: impl std::ops::RangeBounds<ClusteringPrefix<'b>>,
+        descending: bool,
+    ) -> Result<SelectMultiple<PersonByEmail, String>, Error> {
+        tracing::debug!(
+            "Selecting range of partition of table {} with values {:#?}",
+            "person_by_email",
+            self
+        );
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.email)?;
+        let mut query = catalytic::clustering_range::append_range(
+            SELECT_PARTITION_QUERY,
+            &range,
+            &mut serialized_values,
+        )?;
+        if descending {
+            query.push_str(" order by name desc");
+        }
+        Ok(SelectMultiple::new(Qv {
+            query,
+            values: serialized_values,
+        }))
+    }
+}
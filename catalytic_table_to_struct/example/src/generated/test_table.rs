@@ -1,15 +1,16 @@
 // Generated file
 #[allow(unused_imports)]
 use catalytic::query_transform::{
-    CountType, DeleteUnique, Insert, MultipleSelectQueryErrorTransform, QueryEntityVec,
-    QueryEntityVecResult, QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult,
-    SelectMultiple, SelectUnique, SelectUniqueExpect, SingleSelectQueryErrorTransform, Truncate,
-    TtlType, Update,
+    ConditionalMutation, CountType, DeleteMultiple, DeleteUnique, Insert, LightweightTransaction,
+    QueryEntityVec, QueryEntityVecResult, QueryResultConditional, QueryResultLwt,
+    QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult, SelectMultiple,
+    SelectUnique, SelectUniqueExpect, TimestampType, TokenType, Truncate, TtlType, Update,
+    UpdateCounter, WithMetadata,
 };
+use catalytic::Error;
 #[allow(unused_imports)]
 use scylla::frame::value::SerializeValuesError;
 use scylla::frame::value::SerializedValues;
-use scylla::transport::errors::QueryError;
 use scylla::transport::iterator::TypedRowIterator;
 use scylla::CachingSession;
 #[doc = r" The query to select all rows in the table"]
@@ -21,16 +22,52 @@ pub const INSERT_QUERY: &str = "insert into test_table(b, c, d, a, e) values (?,
 #[doc = r" The query to insert a unique row in the table with a TTL"]
 pub const INSERT_TTL_QUERY: &str =
     "insert into test_table(b, c, d, a, e) values (?, ?, ?, ?, ?) using ttl ?";
+#[doc = r" The query to insert a unique row in the table if it doesn't exist yet"]
+pub const INSERT_IF_NOT_EXISTS_QUERY: &str =
+    "insert into test_table(b, c, d, a, e) values (?, ?, ?, ?, ?) if not exists";
+#[doc = r" The query to insert a unique row in the table with a write timestamp"]
+pub const INSERT_WITH_QUERY: &str =
+    "insert into test_table(b, c, d, a, e) values (?, ?, ?, ?, ?) using timestamp ?";
 #[doc = r" The query truncate the whole table"]
 pub const TRUNCATE_QUERY: &str = "truncate test_table";
 #[doc = r" The query to retrieve a unique row in this table"]
 pub const SELECT_UNIQUE_QUERY: &str =
     "select b, c, d, a, e from test_table where b = ? and c = ? and d = ? and a = ?";
+#[doc = r" The query to retrieve a unique row in this table, including the writetime of the columns"]
+pub const SELECT_UNIQUE_WITH_WRITETIME_QUERY: &str =
+    "select b, c, d, a, e, writetime(e) from test_table where b = ? and c = ? and d = ? and a = ?";
+#[doc = "The row together with the writetime of the columns: e"]
+pub type WithWritetime = WithMetadata<TestTable, (Option<TimestampType>,)>;
 #[doc = "The query to update column e"]
 pub const UPDATE_E_QUERY: &str =
     "update test_table set e = ? where b = ? and c = ? and d = ? and a = ?";
+#[doc = "The query to update column e if it has the expected value"]
+pub const UPDATE_E_IF_QUERY: &str =
+    "update test_table set e = ? where b = ? and c = ? and d = ? and a = ? if e = ?";
+#[doc = "The query to update column e with a write timestamp"]
+pub const UPDATE_E_WITH_QUERY: &str =
+    "update test_table using timestamp ? set e = ? where b = ? and c = ? and d = ? and a = ?";
 #[doc = r" The query to delete a unique row in the table"]
 pub const DELETE_QUERY: &str = "delete from test_table where b = ? and c = ? and d = ? and a = ?";
+#[doc = r" The query to delete a unique row in the table if it exists"]
+pub const DELETE_IF_EXISTS_QUERY: &str =
+    "delete from test_table where b = ? and c = ? and d = ? and a = ? if exists";
+#[doc = r" The query to delete a unique row in the table with a write timestamp"]
+pub const DELETE_WITH_QUERY: &str =
+    "delete from test_table using timestamp ? where b = ? and c = ? and d = ? and a = ?";
+#[doc = r" The query to select the token of a partition"]
+pub const TOKEN_QUERY: &str = "select token(b, c) from test_table where b = ? and c = ? limit 1";
+#[doc = r" The query to select the rows of which the token of the partition key is within a range"]
+pub const SELECT_BY_TOKEN_RANGE_QUERY: &str =
+    "select b, c, d, a, e from test_table where token(b, c) > ? and token(b, c) <= ?";
+#[doc = r" The query to select all rows in a partition"]
+pub const SELECT_PARTITION_QUERY: &str =
+    "select b, c, d, a, e from test_table where b = ? and c = ?";
+#[doc = r" The query to select all rows in a partition, ordered descending by the first clustering column"]
+pub const SELECT_PARTITION_DESC_QUERY: &str =
+    "select b, c, d, a, e from test_table where b = ? and c = ? order by d desc";
+#[doc = r" The query to delete all rows in a partition"]
+pub const DELETE_PARTITION_QUERY: &str = "delete from test_table where b = ? and c = ?";
 #[doc = r" This is the struct which is generated from the table"]
 #[doc = r" If you want to perform CRUD operations, do the following:"]
 #[doc = r"     Create -> convert this struct to a borrowed struct"]
@@ -84,7 +121,7 @@ pub fn select_all_count_qv(
 #[doc = r" Performs the count query"]
 pub async fn select_all_count(
     session: &CachingSession,
-) -> Result<QueryResultUniqueRowExpect<CountType>, SingleSelectQueryErrorTransform> {
+) -> Result<QueryResultUniqueRowExpect<CountType>, Error> {
     select_all_count_qv().select_count(session).await
 }
 #[doc = r" Returns a struct that can perform a selection of all rows in the database"]
@@ -99,7 +136,7 @@ pub fn select_all_qv() -> SelectMultiple<TestTable, &'static str, &'static [u8;
 pub async fn select_all(
     session: &CachingSession,
     page_size: Option<i32>,
-) -> Result<TypedRowIterator<TestTable>, QueryError> {
+) -> Result<TypedRowIterator<TestTable>, Error> {
     select_all_qv().select(session, page_size).await
 }
 #[doc = r" Returns a struct that can perform a selection of all rows in the database"]
@@ -107,7 +144,7 @@ pub async fn select_all(
 pub async fn select_all_in_memory(
     session: &CachingSession,
     page_size: i32,
-) -> Result<QueryEntityVec<TestTable>, MultipleSelectQueryErrorTransform> {
+) -> Result<QueryEntityVec<TestTable>, Error> {
     select_all_qv()
         .select_all_in_memory(session, page_size)
         .await
@@ -173,7 +210,7 @@ pub async fn truncate(session: &CachingSession) -> ScyllaQueryResult {
 }
 impl<'a> TestTableRef<'a> {
     #[doc = r" Returns a struct that can perform an insert operation"]
-    pub fn insert_qv(&self) -> Result<Insert, SerializeValuesError> {
+    pub fn insert_qv(&self) -> Result<Insert, Error> {
         let mut serialized = SerializedValues::with_capacity(5usize);
         serialized.add_value(&self.b)?;
         serialized.add_value(&self.c)?;
@@ -191,7 +228,7 @@ impl<'a> TestTableRef<'a> {
         self.insert_qv()?.insert(session).await
     }
     #[doc = r" Returns a struct that can perform an insert operation with a TTL"]
-    pub fn insert_ttl_qv(&self, ttl: TtlType) -> Result<Insert, SerializeValuesError> {
+    pub fn insert_ttl_qv(&self, ttl: TtlType) -> Result<Insert, Error> {
         let mut serialized = SerializedValues::with_capacity(6usize);
         serialized.add_value(&self.b)?;
         serialized.add_value(&self.c)?;
@@ -209,6 +246,50 @@ impl<'a> TestTableRef<'a> {
         tracing::debug!("Insert with ttl {}, {:#?}", ttl, self);
         self.insert_ttl_qv(ttl)?.insert(session).await
     }
+    #[doc = r" Returns a struct that can perform an insert operation, which is only applied if the row doesn't exist"]
+    pub fn insert_if_not_exists_qv(&self) -> Result<LightweightTransaction<TestTable>, Error> {
+        let mut serialized = SerializedValues::with_capacity(5usize);
+        serialized.add_value(&self.b)?;
+        serialized.add_value(&self.c)?;
+        serialized.add_value(&self.d)?;
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.e)?;
+        Ok(LightweightTransaction::new(Qv {
+            query: INSERT_IF_NOT_EXISTS_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert if the row doesn't exist, else the existing row is returned"]
+    pub async fn insert_if_not_exists(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultLwt<TestTable>, Error> {
+        tracing::debug!("Inserting if not exists: {:#?}", self);
+        self.insert_if_not_exists_qv()?.execute(session).await
+    }
+    #[doc = r" Returns a struct that can perform an insert operation with a write timestamp"]
+    pub fn insert_with_qv(&self, timestamp: TimestampType) -> Result<Insert, Error> {
+        let mut serialized = SerializedValues::with_capacity(6usize);
+        serialized.add_value(&self.b)?;
+        serialized.add_value(&self.c)?;
+        serialized.add_value(&self.d)?;
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.e)?;
+        serialized.add_value(&timestamp)?;
+        Ok(Insert::new(Qv {
+            query: INSERT_WITH_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert with a write timestamp, replaying it with the same timestamp has no additional effect"]
+    pub async fn insert_with(
+        &self,
+        session: &CachingSession,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!("Insert with timestamp {}, {:#?}", timestamp, self);
+        self.insert_with_qv(timestamp)?.insert(session).await
+    }
     #[doc = r" Performs either an insertion or deletion, depending on the insert parameter"]
     pub async fn insert_or_delete(
         &self,
@@ -290,7 +371,7 @@ impl From<PrimaryKeyRef<'_>> for PrimaryKey {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a unique row selection"]
-    pub fn select_unique_qv(&self) -> Result<SelectUnique<TestTable>, SerializeValuesError> {
+    pub fn select_unique_qv(&self) -> Result<SelectUnique<TestTable>, Error> {
         let mut serialized_values = SerializedValues::with_capacity(4usize);
         serialized_values.add_value(&self.b)?;
         serialized_values.add_value(&self.c)?;
@@ -305,7 +386,7 @@ impl PrimaryKeyRef<'_> {
     pub async fn select_unique(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRow<TestTable>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRow<TestTable>, Error> {
         tracing::debug!(
             "Selecting unique row for table {} with values: {:#?}",
             "test_table",
@@ -316,9 +397,7 @@ impl PrimaryKeyRef<'_> {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a unique row selection"]
-    pub fn select_unique_expect_qv(
-        &self,
-    ) -> Result<SelectUniqueExpect<TestTable>, SerializeValuesError> {
+    pub fn select_unique_expect_qv(&self) -> Result<SelectUniqueExpect<TestTable>, Error> {
         let mut serialized_values = SerializedValues::with_capacity(4usize);
         serialized_values.add_value(&self.b)?;
         serialized_values.add_value(&self.c)?;
@@ -333,7 +412,7 @@ impl PrimaryKeyRef<'_> {
     pub async fn select_unique_expect(
         &self,
         session: &CachingSession,
-    ) -> Result<QueryResultUniqueRowExpect<TestTable>, SingleSelectQueryErrorTransform> {
+    ) -> Result<QueryResultUniqueRowExpect<TestTable>, Error> {
         tracing::debug!(
             "Selecting unique row for table {} with values: {:#?}",
             "test_table",
@@ -342,9 +421,67 @@ impl PrimaryKeyRef<'_> {
         self.select_unique_expect_qv()?.select(session).await
     }
 }
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_with_writetime_qv(&self) -> Result<SelectUnique<WithWritetime>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(4usize);
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        serialized_values.add_value(&self.d)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUnique::new(Qv {
+            query: SELECT_UNIQUE_WITH_WRITETIME_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_with_writetime(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRow<WithWritetime>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "test_table",
+            self
+        );
+        self.select_unique_with_writetime_qv()?
+            .select(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_with_writetime_expect_qv(
+        &self,
+    ) -> Result<SelectUniqueExpect<WithWritetime>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(4usize);
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        serialized_values.add_value(&self.d)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUniqueExpect::new(Qv {
+            query: SELECT_UNIQUE_WITH_WRITETIME_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_with_writetime_expect(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRowExpect<WithWritetime>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "test_table",
+            self
+        );
+        self.select_unique_with_writetime_expect_qv()?
+            .select(session)
+            .await
+    }
+}
 impl PrimaryKeyRef<'_> {
     #[doc = "Returns a struct that can perform an update operation for column e"]
-    pub fn update_e_qv(&self, val: &i32) -> Result<Update, SerializeValuesError> {
+    pub fn update_e_qv(&self, val: &i32) -> Result<Update, Error> {
         let mut serialized_values = SerializedValues::with_capacity(5usize);
         serialized_values.add_value(&val)?;
         serialized_values.add_value(&self.b)?;
@@ -368,11 +505,72 @@ impl PrimaryKeyRef<'_> {
     }
 }
 impl PrimaryKeyRef<'_> {
-    #[doc = r" Returns a struct that can perform an update on a dynamic updatable column"]
-    pub fn update_dyn_qv(
+    #[doc = "Returns a struct that can perform an update operation for column e, which is only applied if the column has the expected value"]
+    pub fn update_e_if_qv(&self, val: &i32, expected: &i32) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(6usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        serialized_values.add_value(&self.d)?;
+        serialized_values.add_value(&self.a)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_E_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column e if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_e_if(
         &self,
-        val: UpdatableColumnRef<'_>,
-    ) -> Result<Update, SerializeValuesError> {
+        session: &CachingSession,
+        val: &i32,
+        expected: &i32,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "test_table",
+            val,
+            expected,
+            self
+        );
+        self.update_e_if_qv(val, expected)?.execute(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column e with a write timestamp"]
+    pub fn update_e_with_qv(&self, val: &i32, timestamp: TimestampType) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(6usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        serialized_values.add_value(&self.d)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_E_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column e with a write timestamp"]
+    pub async fn update_e_with(
+        &self,
+        session: &CachingSession,
+        val: &i32,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "test_table",
+            val,
+            timestamp,
+            self
+        );
+        self.update_e_with_qv(val, timestamp)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform an update on a dynamic updatable column"]
+    pub fn update_dyn_qv(&self, val: UpdatableColumnRef<'_>) -> Result<Update, Error> {
         match val {
             UpdatableColumnRef::E(val) => self.update_e_qv(val),
         }
@@ -391,7 +589,7 @@ impl PrimaryKeyRef<'_> {
     pub fn update_dyn_multiple_qv(
         &self,
         val: &[UpdatableColumnRef<'_>],
-    ) -> Result<Update<String, SerializedValues>, SerializeValuesError> {
+    ) -> Result<Update<String, SerializedValues>, Error> {
         if val.is_empty() {
             panic!("Empty update array")
         }
@@ -436,7 +634,7 @@ impl PrimaryKeyRef<'_> {
 }
 impl PrimaryKeyRef<'_> {
     #[doc = r" Returns a struct that can perform a single row deletion"]
-    pub fn delete_qv(&self) -> Result<DeleteUnique, SerializeValuesError> {
+    pub fn delete_qv(&self) -> Result<DeleteUnique, Error> {
         let mut serialized_values = SerializedValues::with_capacity(4usize);
         serialized_values.add_value(&self.b)?;
         serialized_values.add_value(&self.c)?;
@@ -457,6 +655,324 @@ impl PrimaryKeyRef<'_> {
         self.delete_qv()?.delete_unique(session).await
     }
 }
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion, which is only applied if the row exists"]
+    pub fn delete_if_exists_qv(&self) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(4usize);
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        serialized_values.add_value(&self.d)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: DELETE_IF_EXISTS_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion if the row exists, the result tells if the row was deleted"]
+    pub async fn delete_if_exists(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Deleting a row if it exists from table {} with values {:#?}",
+            "test_table",
+            self
+        );
+        self.delete_if_exists_qv()?.execute(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion with a write timestamp"]
+    pub fn delete_with_qv(&self, timestamp: TimestampType) -> Result<DeleteUnique, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(5usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        serialized_values.add_value(&self.d)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(DeleteUnique::new(Qv {
+            query: DELETE_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion with a write timestamp, data written with a later timestamp is kept"]
+    pub async fn delete_with(
+        &self,
+        session: &CachingSession,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Deleting a row with timestamp {} from table {} with values {:#?}",
+            timestamp,
+            "test_table",
+            self
+        );
+        self.delete_with_qv(timestamp)?.delete_unique(session).await
+    }
+}
+#[doc = r" The owned partition key struct"]
+#[doc = r" A partition contains all the rows with the same partition key"]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartitionKey {
+    pub b: i32,
+    pub c: i32,
+}
+#[doc = r" The borrowed partition key struct"]
+#[doc = r" This struct can be used to perform reads and deletes of a whole partition or of a range within the partition"]
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub struct PartitionKeyRef<'a> {
+    pub b: &'a i32,
+    pub c: &'a i32,
+}
+#[doc = r" Conversation method to go from a borrowed partition key to an owned partition key"]
+impl PartitionKeyRef<'_> {
+    pub fn into_owned(self) -> PartitionKey {
+        self.into()
+    }
+}
+#[doc = r" Conversation method to go from an owned partition key to a borrowed partition key"]
+impl PartitionKey {
+    pub fn to_ref(&self) -> PartitionKeyRef<'_> {
+        PartitionKeyRef {
+            b: &self.b,
+            c: &self.c,
+        }
+    }
+}
+#[doc = r" Conversation method to go from a borrowed partition key to an owned partition key"]
+impl From<PartitionKeyRef<'_>> for PartitionKey {
+    fn from(f: PartitionKeyRef<'_>) -> PartitionKey {
+        PartitionKey {
+            b: f.b.clone(),
+            c: f.c.clone(),
+        }
+    }
+}
+impl TestTable {
+    #[doc = r" Create a borrowed partition key from the struct values"]
+    pub fn partition_key(&self) -> PartitionKeyRef {
+        PartitionKeyRef {
+            b: &self.b,
+            c: &self.c,
+        }
+    }
+}
+impl PrimaryKey {
+    #[doc = r" Create a borrowed partition key from the primary key"]
+    pub fn partition_key(&self) -> PartitionKeyRef {
+        PartitionKeyRef {
+            b: &self.b,
+            c: &self.c,
+        }
+    }
+}
+impl<'a> PrimaryKeyRef<'a> {
+    #[doc = r" Create a borrowed partition key from the primary key"]
+    pub fn partition_key(&self) -> PartitionKeyRef<'a> {
+        PartitionKeyRef {
+            b: self.b,
+            c: self.c,
+        }
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a selection of the token of the partition"]
+    pub fn token_qv(&self) -> Result<SelectUnique<(TokenType,)>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        Ok(SelectUnique::new(Qv {
+            query: TOKEN_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Selects the token of the partition, which determines the nodes that store the partition"]
+    #[doc = r" None is returned if the partition has no rows"]
+    pub async fn token(&self, session: &CachingSession) -> Result<Option<TokenType>, Error> {
+        tracing::debug!(
+            "Selecting token of partition of table {} with values {:#?}",
+            "test_table",
+            self
+        );
+        Ok(self
+            .token_qv()?
+            .select(session)
+            .await?
+            .entity
+            .map(|(token,)| token))
+    }
+}
+#[doc = r" Returns a struct that can perform a selection of the rows of which the token of the"]
+#[doc = r" partition key is within the range, the start is exclusive and the end is inclusive"]
+pub fn select_by_token_range_qv(
+    start: TokenType,
+    end: TokenType,
+) -> Result<SelectMultiple<TestTable, &'static str, SerializedValues>, Error> {
+    let mut serialized_values = SerializedValues::with_capacity(2);
+    serialized_values.add_value(&start)?;
+    serialized_values.add_value(&end)?;
+    Ok(SelectMultiple::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: serialized_values,
+    }))
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range, with a specified page size"]
+pub async fn select_by_token_range(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<TestTable>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select(session, page_size)
+        .await
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range and accumulates them in memory"]
+pub async fn select_by_token_range_in_memory(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: i32,
+) -> Result<QueryEntityVec<TestTable>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" Scans the whole table with a query per split of the token ring, of which at most 'concurrency'"]
+#[doc = r" are executed at the same time. The rows are returned in the order in which they arrive"]
+pub fn scan_all_parallel(
+    session: &CachingSession,
+    splits: usize,
+    concurrency: usize,
+) -> Result<impl catalytic::query_transform::Stream<Item = Result<TestTable, Error>> + '_, Error> {
+    tracing::debug!("Scanning table {} with {} splits", "test_table", splits);
+    SelectMultiple::<TestTable, _, _>::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: SerializedValues::new(),
+    })
+    .scan_parallel(session, splits, concurrency)
+}
+#[doc = r" A prefix of the clustering columns, used as a bound of a range within a partition"]
+#[doc = r" The variant is named after the last column of the prefix"]
+#[derive(Copy, Clone, Debug)]
+pub enum ClusteringPrefix<'a> {
+    #[doc = "Restricts the columns: d"]
+    D(&'a i32),
+    #[doc = "Restricts the columns: d, a"]
+    A(&'a i32, &'a i32),
+}
+impl catalytic::clustering_range::ClusteringColumns for ClusteringPrefix<'_> {
+    fn columns(&self) -> &'static [&'static str] {
+        match self {
+            ClusteringPrefix::D(..) => &["d"],
+            ClusteringPrefix::A(..) => &["d", "a"],
+        }
+    }
+    fn add_values(&self, values: &mut SerializedValues) -> Result<(), SerializeValuesError> {
+        match self {
+            ClusteringPrefix::D(d) => {
+                values.add_value(d)?;
+            }
+            ClusteringPrefix::A(d, a) => {
+                values.add_value(d)?;
+                values.add_value(a)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a selection of all rows in the partition"]
+    #[doc = r" If descending is true, the rows are ordered descending by the first clustering column"]
+    pub fn select_partition(&self, descending: bool) -> Result<SelectMultiple<TestTable>, Error> {
+        tracing::debug!(
+            "Selecting partition of table {} with values {:#?}",
+            "test_table",
+            self
+        );
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        Ok(SelectMultiple::new(Qv {
+            query: if descending {
+                SELECT_PARTITION_DESC_QUERY
+            } else {
+                SELECT_PARTITION_QUERY
+            },
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Returns a struct that can perform a selection of the rows in the partition within a range of the clustering columns"]
+    #[doc = r" Both bounds can restrict a different prefix of the clustering columns, e.g."]
+    #[doc = r" ClusteringPrefix::A(&a)..=ClusteringPrefix::B(&a, &b)"]
+    #[doc = r" If descending is true, the rows are ordered descending by the first clustering column"]
+    pub fn select_partition_range<'b>(
+        &self,
+        range: impl std::ops::RangeBounds<ClusteringPrefix<'b>>,
+        descending: bool,
+    ) -> Result<SelectMultiple<TestTable, String>, Error> {
+        tracing::debug!(
+            "Selecting range of partition of table {} with values {:#?}",
+            "test_table",
+            self
+        );
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        let mut query = catalytic::clustering_range::append_range(
+            SELECT_PARTITION_QUERY,
+            &range,
+            &mut serialized_values,
+        )?;
+        if descending {
+            query.push_str(" order by d desc");
+        }
+        Ok(SelectMultiple::new(Qv {
+            query,
+            values: serialized_values,
+        }))
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a deletion of all rows in the partition"]
+    pub fn delete_partition(&self) -> Result<DeleteMultiple, Error> {
+        tracing::debug!(
+            "Deleting partition of table {} with values {:#?}",
+            "test_table",
+            self
+        );
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        Ok(DeleteMultiple::new(Qv {
+            query: DELETE_PARTITION_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Returns a struct that can perform a deletion of the rows in the partition within a range of the clustering columns"]
+    pub fn delete_range<'b>(
+        &self,
+        range: impl std::ops::RangeBounds<ClusteringPrefix<'b>>,
+    ) -> Result<DeleteMultiple<String>, Error> {
+        tracing::debug!(
+            "Deleting range of partition of table {} with values {:#?}",
+            "test_table",
+            self
+        );
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&self.b)?;
+        serialized_values.add_value(&self.c)?;
+        let query = catalytic::clustering_range::append_range(
+            DELETE_PARTITION_QUERY,
+            &range,
+            &mut serialized_values,
+        )?;
+        Ok(DeleteMultiple::new(Qv {
+            query,
+            values: serialized_values,
+        }))
+    }
+}
 #[doc = r" This struct can be converted to a borrowed struct which can be used to update single rows"]
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
@@ -0,0 +1,1118 @@
+// Generated file
+#[allow(unused_imports)]
+use catalytic::query_transform::{
+    ConditionalMutation, CountType, DeleteMultiple, DeleteUnique, Insert, LightweightTransaction,
+    QueryEntityVec, QueryEntityVecResult, QueryResultConditional, QueryResultLwt,
+    QueryResultUniqueRow, QueryResultUniqueRowExpect, Qv, ScyllaQueryResult, SelectMultiple,
+    SelectUnique, SelectUniqueExpect, TimestampType, TokenType, Truncate, TtlType, Update,
+    UpdateCounter, WithMetadata,
+};
+use catalytic::Error;
+#[allow(unused_imports)]
+use scylla::frame::value::SerializeValuesError;
+use scylla::frame::value::SerializedValues;
+use scylla::transport::iterator::TypedRowIterator;
+use scylla::CachingSession;
+#[doc = r" The query to select all rows in the table"]
+pub const SELECT_ALL_QUERY: &str = "select a, address, addresses, details from udt_table";
+#[doc = r" The query to count all rows in the table"]
+pub const SELECT_ALL_COUNT_QUERY: &str = "select count(*) from udt_table";
+#[doc = r" The query to insert a unique row in the table"]
+pub const INSERT_QUERY: &str =
+    "insert into udt_table(a, address, addresses, details) values (?, ?, ?, ?)";
+#[doc = r" The query to insert a unique row in the table with a TTL"]
+pub const INSERT_TTL_QUERY: &str =
+    "insert into udt_table(a, address, addresses, details) values (?, ?, ?, ?) using ttl ?";
+#[doc = r" The query to insert a unique row in the table if it doesn't exist yet"]
+pub const INSERT_IF_NOT_EXISTS_QUERY: &str =
+    "insert into udt_table(a, address, addresses, details) values (?, ?, ?, ?) if not exists";
+#[doc = r" The query to insert a unique row in the table with a write timestamp"]
+pub const INSERT_WITH_QUERY: &str =
+    "insert into udt_table(a, address, addresses, details) values (?, ?, ?, ?) using timestamp ?";
+#[doc = r" The query truncate the whole table"]
+pub const TRUNCATE_QUERY: &str = "truncate udt_table";
+#[doc = r" The query to retrieve a unique row in this table"]
+pub const SELECT_UNIQUE_QUERY: &str =
+    "select a, address, addresses, details from udt_table where a = ?";
+#[doc = r" The query to retrieve a unique row in this table, including the writetime of the columns"]
+pub const SELECT_UNIQUE_WITH_WRITETIME_QUERY: &str = "select a, address, addresses, details, writetime(address), writetime(details) from udt_table where a = ?";
+#[doc = "The row together with the writetime of the columns: address, details"]
+pub type WithWritetime = WithMetadata<UdtTable, (Option<TimestampType>, Option<TimestampType>)>;
+#[doc = "The query to update column address"]
+pub const UPDATE_ADDRESS_QUERY: &str = "update udt_table set address = ? where a = ?";
+#[doc = "The query to update column address if it has the expected value"]
+pub const UPDATE_ADDRESS_IF_QUERY: &str =
+    "update udt_table set address = ? where a = ? if address = ?";
+#[doc = "The query to update column address with a write timestamp"]
+pub const UPDATE_ADDRESS_WITH_QUERY: &str =
+    "update udt_table using timestamp ? set address = ? where a = ?";
+#[doc = "The query to update column addresses"]
+pub const UPDATE_ADDRESSES_QUERY: &str = "update udt_table set addresses = ? where a = ?";
+#[doc = "The query to update column addresses if it has the expected value"]
+pub const UPDATE_ADDRESSES_IF_QUERY: &str =
+    "update udt_table set addresses = ? where a = ? if addresses = ?";
+#[doc = "The query to update column addresses with a write timestamp"]
+pub const UPDATE_ADDRESSES_WITH_QUERY: &str =
+    "update udt_table using timestamp ? set addresses = ? where a = ?";
+#[doc = "The query to put an entry in column addresses"]
+pub const PUT_ADDRESSES_QUERY: &str = "update udt_table set addresses[?] = ? where a = ?";
+#[doc = "The query to remove an entry from column addresses"]
+pub const REMOVE_KEY_ADDRESSES_QUERY: &str =
+    "update udt_table set addresses = addresses - ? where a = ?";
+#[doc = "The query to update column details"]
+pub const UPDATE_DETAILS_QUERY: &str = "update udt_table set details = ? where a = ?";
+#[doc = "The query to update column details if it has the expected value"]
+pub const UPDATE_DETAILS_IF_QUERY: &str =
+    "update udt_table set details = ? where a = ? if details = ?";
+#[doc = "The query to update column details with a write timestamp"]
+pub const UPDATE_DETAILS_WITH_QUERY: &str =
+    "update udt_table using timestamp ? set details = ? where a = ?";
+#[doc = r" The query to delete a unique row in the table"]
+pub const DELETE_QUERY: &str = "delete from udt_table where a = ?";
+#[doc = r" The query to delete a unique row in the table if it exists"]
+pub const DELETE_IF_EXISTS_QUERY: &str = "delete from udt_table where a = ? if exists";
+#[doc = r" The query to delete a unique row in the table with a write timestamp"]
+pub const DELETE_WITH_QUERY: &str = "delete from udt_table using timestamp ? where a = ?";
+#[doc = r" The query to select the token of a partition"]
+pub const TOKEN_QUERY: &str = "select token(a) from udt_table where a = ? limit 1";
+#[doc = r" The query to select the rows of which the token of the partition key is within a range"]
+pub const SELECT_BY_TOKEN_RANGE_QUERY: &str =
+    "select a, address, addresses, details from udt_table where token(a) > ? and token(a) <= ?";
+#[doc = r" This is the struct which is generated from the table"]
+#[doc = r" If you want to perform CRUD operations, do the following:"]
+#[doc = r"     Create -> convert this struct to a borrowed struct"]
+#[doc = r"     Read, Update, Delete -> convert this struct to a borrowed primary key struct"]
+#[doc = r" When you converted this struct to the specified type, you will have methods available"]
+#[doc = r" for the things you want"]
+#[derive(
+    scylla :: FromRow, scylla :: ValueList, catalytic_macro :: Mirror, Debug, Clone, PartialEq,
+)]
+pub struct UdtTable {
+    #[partition_key]
+    pub a: i32,
+    pub address: super::user_defined_types::Address,
+    pub addresses: std::collections::HashMap<String, super::user_defined_types::Address>,
+    pub details: super::user_defined_types::PersonDetails,
+}
+impl UdtTable {
+    #[doc = r" Create an borrowed primary key from the struct values"]
+    #[doc = r" You can use this primary key struct to perform updates, deletions and selects on"]
+    #[doc = r" a unique row"]
+    pub fn primary_key(&self) -> PrimaryKeyRef {
+        PrimaryKeyRef { a: &self.a }
+    }
+    #[doc = r" Create an owned primary key from the struct values"]
+    pub fn primary_key_owned(self) -> PrimaryKey {
+        PrimaryKey { a: self.a }
+    }
+}
+#[doc = r" Returns a struct that can perform a query which counts the rows in this table"]
+pub fn select_all_count_qv(
+) -> SelectUniqueExpect<catalytic::query_transform::Count, &'static str, &'static [u8; 0]> {
+    SelectUniqueExpect::new(Qv {
+        query: SELECT_ALL_COUNT_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Performs the count query"]
+pub async fn select_all_count(
+    session: &CachingSession,
+) -> Result<QueryResultUniqueRowExpect<CountType>, Error> {
+    select_all_count_qv().select_count(session).await
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+pub fn select_all_qv() -> SelectMultiple<UdtTable, &'static str, &'static [u8; 0]> {
+    SelectMultiple::new(Qv {
+        query: SELECT_ALL_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+#[doc = r" with a specified page size"]
+pub async fn select_all(
+    session: &CachingSession,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<UdtTable>, Error> {
+    select_all_qv().select(session, page_size).await
+}
+#[doc = r" Returns a struct that can perform a selection of all rows in the database"]
+#[doc = r" It will accumulate all rows in memory by sending paged queries"]
+pub async fn select_all_in_memory(
+    session: &CachingSession,
+    page_size: i32,
+) -> Result<QueryEntityVec<UdtTable>, Error> {
+    select_all_qv()
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" A struct that contains borrowed values"]
+#[doc = r" This can be used to perform an insertion that is unique identified by the values of this struct"]
+#[doc = r" If you want to perform an update, deletion or select or a unique row, convert this"]
+#[doc = r" struct to the primary key struct"]
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub struct UdtTableRef<'a> {
+    pub a: &'a i32,
+    pub address: &'a super::user_defined_types::Address,
+    pub addresses: &'a std::collections::HashMap<String, super::user_defined_types::Address>,
+    pub details: &'a super::user_defined_types::PersonDetails,
+}
+impl From<UdtTableRef<'_>> for UdtTable {
+    #[doc = r" Conversation method to go from a borrowed struct to an owned struct"]
+    fn from(f: UdtTableRef<'_>) -> UdtTable {
+        UdtTable {
+            a: f.a.clone(),
+            address: f.address.clone(),
+            addresses: f.addresses.clone(),
+            details: f.details.clone(),
+        }
+    }
+}
+impl UdtTable {
+    #[doc = r" Conversation method to go from an owned struct to a borrowed struct"]
+    pub fn to_ref(&self) -> UdtTableRef {
+        UdtTableRef {
+            a: &self.a,
+            address: &self.address,
+            addresses: &self.addresses,
+            details: &self.details,
+        }
+    }
+}
+impl<'a> UdtTableRef<'a> {
+    #[doc = r" Conversation method to go from a borrowed struct to an owned struct"]
+    pub fn primary_key(&self) -> PrimaryKeyRef {
+        PrimaryKeyRef { a: self.a }
+    }
+}
+#[doc = r" Returns a struct that can perform a truncate operation"]
+pub fn truncate_qv() -> Truncate<&'static str, &'static [u8; 0]> {
+    Truncate::new(Qv {
+        query: TRUNCATE_QUERY,
+        values: &[],
+    })
+}
+#[doc = r" Performs a truncate"]
+#[doc = r" !This will delete all rows in the table!"]
+pub async fn truncate(session: &CachingSession) -> ScyllaQueryResult {
+    truncate_qv().truncate(session).await
+}
+impl<'a> UdtTableRef<'a> {
+    #[doc = r" Returns a struct that can perform an insert operation"]
+    pub fn insert_qv(&self) -> Result<Insert, Error> {
+        let mut serialized = SerializedValues::with_capacity(4usize);
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.address)?;
+        serialized.add_value(&self.addresses)?;
+        serialized.add_value(&self.details)?;
+        Ok(Insert::new(Qv {
+            query: INSERT_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert"]
+    pub async fn insert(&self, session: &CachingSession) -> ScyllaQueryResult {
+        tracing::debug!("Inserting: {:#?}", self);
+        self.insert_qv()?.insert(session).await
+    }
+    #[doc = r" Returns a struct that can perform an insert operation with a TTL"]
+    pub fn insert_ttl_qv(&self, ttl: TtlType) -> Result<Insert, Error> {
+        let mut serialized = SerializedValues::with_capacity(5usize);
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.address)?;
+        serialized.add_value(&self.addresses)?;
+        serialized.add_value(&self.details)?;
+        serialized.add_value(&ttl)?;
+        Ok(Insert::new(Qv {
+            query: INSERT_TTL_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert with a TTL"]
+    pub async fn insert_ttl(&self, session: &CachingSession, ttl: TtlType) -> ScyllaQueryResult {
+        tracing::debug!("Insert with ttl {}, {:#?}", ttl, self);
+        self.insert_ttl_qv(ttl)?.insert(session).await
+    }
+    #[doc = r" Returns a struct that can perform an insert operation, which is only applied if the row doesn't exist"]
+    pub fn insert_if_not_exists_qv(&self) -> Result<LightweightTransaction<UdtTable>, Error> {
+        let mut serialized = SerializedValues::with_capacity(4usize);
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.address)?;
+        serialized.add_value(&self.addresses)?;
+        serialized.add_value(&self.details)?;
+        Ok(LightweightTransaction::new(Qv {
+            query: INSERT_IF_NOT_EXISTS_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert if the row doesn't exist, else the existing row is returned"]
+    pub async fn insert_if_not_exists(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultLwt<UdtTable>, Error> {
+        tracing::debug!("Inserting if not exists: {:#?}", self);
+        self.insert_if_not_exists_qv()?.execute(session).await
+    }
+    #[doc = r" Returns a struct that can perform an insert operation with a write timestamp"]
+    pub fn insert_with_qv(&self, timestamp: TimestampType) -> Result<Insert, Error> {
+        let mut serialized = SerializedValues::with_capacity(5usize);
+        serialized.add_value(&self.a)?;
+        serialized.add_value(&self.address)?;
+        serialized.add_value(&self.addresses)?;
+        serialized.add_value(&self.details)?;
+        serialized.add_value(&timestamp)?;
+        Ok(Insert::new(Qv {
+            query: INSERT_WITH_QUERY,
+            values: serialized,
+        }))
+    }
+    #[doc = r" Performs an insert with a write timestamp, replaying it with the same timestamp has no additional effect"]
+    pub async fn insert_with(
+        &self,
+        session: &CachingSession,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!("Insert with timestamp {}, {:#?}", timestamp, self);
+        self.insert_with_qv(timestamp)?.insert(session).await
+    }
+    #[doc = r" Performs either an insertion or deletion, depending on the insert parameter"]
+    pub async fn insert_or_delete(
+        &self,
+        session: &CachingSession,
+        insert: bool,
+    ) -> ScyllaQueryResult {
+        if insert {
+            self.insert(session).await
+        } else {
+            self.primary_key().delete(session).await
+        }
+    }
+}
+impl UdtTable {
+    #[doc = r" Performs an update on the current struct based on the update parameter"]
+    pub fn in_memory_update(&mut self, update: UpdatableColumn) {
+        match update {
+            UpdatableColumn::Address(val) => {
+                self.address = val;
+            }
+            UpdatableColumn::Addresses(val) => {
+                self.addresses = val;
+            }
+            UpdatableColumn::PutAddresses(key, value) => {
+                self.addresses.insert(key, value);
+            }
+            UpdatableColumn::RemoveKeyAddresses(key) => {
+                self.addresses.remove(&key);
+            }
+            UpdatableColumn::Details(val) => {
+                self.details = val;
+            }
+        }
+    }
+    #[doc = r" Performs multiple updates on the current struct"]
+    pub fn in_memory_updates(&mut self, updates: Vec<UpdatableColumn>) {
+        for updatable_column in updates {
+            self.in_memory_update(updatable_column)
+        }
+    }
+}
+#[doc = r" The owned primary key struct"]
+#[doc = r" If you want to perform a read, delete or update, convert it to the borrowed type"]
+#[derive(catalytic_macro :: PrimaryKey, Debug, Clone, PartialEq)]
+pub struct PrimaryKey {
+    #[partition_key]
+    pub a: i32,
+}
+#[doc = r" The borrowed primary key struct"]
+#[doc = r" This struct can be used to perform reads, deletes and updates"]
+#[derive(catalytic_macro :: PrimaryKey, Copy, Debug, Clone, PartialEq)]
+pub struct PrimaryKeyRef<'a> {
+    pub a: &'a i32,
+}
+#[doc = r" Conversation method to go from a borrowed primary key to an owned primary key"]
+impl PrimaryKeyRef<'_> {
+    pub fn into_owned(self) -> PrimaryKey {
+        self.into()
+    }
+}
+#[doc = r" Conversation method to go from an owned primary key to an borrowed primary key"]
+impl PrimaryKey {
+    pub fn to_ref(&self) -> PrimaryKeyRef<'_> {
+        PrimaryKeyRef { a: &self.a }
+    }
+}
+#[doc = r" Conversation method to go from a borrowed primary key to an owned primary key"]
+impl From<PrimaryKeyRef<'_>> for PrimaryKey {
+    fn from(f: PrimaryKeyRef<'_>) -> PrimaryKey {
+        PrimaryKey { a: f.a.clone() }
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_qv(&self) -> Result<SelectUnique<UdtTable>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUnique::new(Qv {
+            query: SELECT_UNIQUE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRow<UdtTable>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "udt_table",
+            self
+        );
+        self.select_unique_qv()?.select(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_expect_qv(&self) -> Result<SelectUniqueExpect<UdtTable>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUniqueExpect::new(Qv {
+            query: SELECT_UNIQUE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_expect(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRowExpect<UdtTable>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "udt_table",
+            self
+        );
+        self.select_unique_expect_qv()?.select(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_with_writetime_qv(&self) -> Result<SelectUnique<WithWritetime>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUnique::new(Qv {
+            query: SELECT_UNIQUE_WITH_WRITETIME_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_with_writetime(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRow<WithWritetime>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "udt_table",
+            self
+        );
+        self.select_unique_with_writetime_qv()?
+            .select(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a unique row selection"]
+    pub fn select_unique_with_writetime_expect_qv(
+        &self,
+    ) -> Result<SelectUniqueExpect<WithWritetime>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUniqueExpect::new(Qv {
+            query: SELECT_UNIQUE_WITH_WRITETIME_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the unique row selection"]
+    pub async fn select_unique_with_writetime_expect(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultUniqueRowExpect<WithWritetime>, Error> {
+        tracing::debug!(
+            "Selecting unique row for table {} with values: {:#?}",
+            "udt_table",
+            self
+        );
+        self.select_unique_with_writetime_expect_qv()?
+            .select(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column address"]
+    pub fn update_address_qv(
+        &self,
+        val: &super::user_defined_types::Address,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_ADDRESS_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column address"]
+    pub async fn update_address(
+        &self,
+        session: &CachingSession,
+        val: &super::user_defined_types::Address,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} for row {:#?}",
+            "udt_table",
+            val,
+            self
+        );
+        self.update_address_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column address, which is only applied if the column has the expected value"]
+    pub fn update_address_if_qv(
+        &self,
+        val: &super::user_defined_types::Address,
+        expected: &super::user_defined_types::Address,
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_ADDRESS_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column address if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_address_if(
+        &self,
+        session: &CachingSession,
+        val: &super::user_defined_types::Address,
+        expected: &super::user_defined_types::Address,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "udt_table",
+            val,
+            expected,
+            self
+        );
+        self.update_address_if_qv(val, expected)?
+            .execute(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column address with a write timestamp"]
+    pub fn update_address_with_qv(
+        &self,
+        val: &super::user_defined_types::Address,
+        timestamp: TimestampType,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_ADDRESS_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column address with a write timestamp"]
+    pub async fn update_address_with(
+        &self,
+        session: &CachingSession,
+        val: &super::user_defined_types::Address,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "udt_table",
+            val,
+            timestamp,
+            self
+        );
+        self.update_address_with_qv(val, timestamp)?
+            .update(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column addresses"]
+    pub fn update_addresses_qv(
+        &self,
+        val: &std::collections::HashMap<String, super::user_defined_types::Address>,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_ADDRESSES_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column addresses"]
+    pub async fn update_addresses(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashMap<String, super::user_defined_types::Address>,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} for row {:#?}",
+            "udt_table",
+            val,
+            self
+        );
+        self.update_addresses_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column addresses, which is only applied if the column has the expected value"]
+    pub fn update_addresses_if_qv(
+        &self,
+        val: &std::collections::HashMap<String, super::user_defined_types::Address>,
+        expected: &std::collections::HashMap<String, super::user_defined_types::Address>,
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_ADDRESSES_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column addresses if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_addresses_if(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashMap<String, super::user_defined_types::Address>,
+        expected: &std::collections::HashMap<String, super::user_defined_types::Address>,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "udt_table",
+            val,
+            expected,
+            self
+        );
+        self.update_addresses_if_qv(val, expected)?
+            .execute(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column addresses with a write timestamp"]
+    pub fn update_addresses_with_qv(
+        &self,
+        val: &std::collections::HashMap<String, super::user_defined_types::Address>,
+        timestamp: TimestampType,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_ADDRESSES_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column addresses with a write timestamp"]
+    pub async fn update_addresses_with(
+        &self,
+        session: &CachingSession,
+        val: &std::collections::HashMap<String, super::user_defined_types::Address>,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "udt_table",
+            val,
+            timestamp,
+            self
+        );
+        self.update_addresses_with_qv(val, timestamp)?
+            .update(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to put an entry in column addresses"]
+    pub fn put_addresses_qv(
+        &self,
+        key: &str,
+        value: &super::user_defined_types::Address,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&key)?;
+        serialized_values.add_value(&value)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: PUT_ADDRESSES_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to put an entry in column addresses"]
+    pub async fn put_addresses(
+        &self,
+        session: &CachingSession,
+        key: &str,
+        value: &super::user_defined_types::Address,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "udt_table",
+            (key, value),
+            self
+        );
+        self.put_addresses_qv(key, value)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation to remove an entry from column addresses"]
+    pub fn remove_key_addresses_qv(&self, key: &str) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&std::slice::from_ref(&key))?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: REMOVE_KEY_ADDRESSES_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation to remove an entry from column addresses"]
+    pub async fn remove_key_addresses(
+        &self,
+        session: &CachingSession,
+        key: &str,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with {:#?} for row {:#?}",
+            "udt_table",
+            key,
+            self
+        );
+        self.remove_key_addresses_qv(key)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column details"]
+    pub fn update_details_qv(
+        &self,
+        val: &super::user_defined_types::PersonDetails,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_DETAILS_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column details"]
+    pub async fn update_details(
+        &self,
+        session: &CachingSession,
+        val: &super::user_defined_types::PersonDetails,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} for row {:#?}",
+            "udt_table",
+            val,
+            self
+        );
+        self.update_details_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column details, which is only applied if the column has the expected value"]
+    pub fn update_details_if_qv(
+        &self,
+        val: &super::user_defined_types::PersonDetails,
+        expected: &super::user_defined_types::PersonDetails,
+    ) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        serialized_values.add_value(&expected)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: UPDATE_DETAILS_IF_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column details if the column has the expected value, else the existing columns are returned"]
+    pub async fn update_details_if(
+        &self,
+        session: &CachingSession,
+        val: &super::user_defined_types::PersonDetails,
+        expected: &super::user_defined_types::PersonDetails,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Updating table {} with val {:#?} if the value is {:#?} for row {:#?}",
+            "udt_table",
+            val,
+            expected,
+            self
+        );
+        self.update_details_if_qv(val, expected)?
+            .execute(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = "Returns a struct that can perform an update operation for column details with a write timestamp"]
+    pub fn update_details_with_qv(
+        &self,
+        val: &super::user_defined_types::PersonDetails,
+        timestamp: TimestampType,
+    ) -> Result<Update, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(3usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&val)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: UPDATE_DETAILS_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = "Performs an update operation for column details with a write timestamp"]
+    pub async fn update_details_with(
+        &self,
+        session: &CachingSession,
+        val: &super::user_defined_types::PersonDetails,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with val {:#?} and timestamp {} for row {:#?}",
+            "udt_table",
+            val,
+            timestamp,
+            self
+        );
+        self.update_details_with_qv(val, timestamp)?
+            .update(session)
+            .await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform an update on a dynamic updatable column"]
+    pub fn update_dyn_qv(&self, val: UpdatableColumnRef<'_>) -> Result<Update, Error> {
+        match val {
+            UpdatableColumnRef::Address(val) => self.update_address_qv(val),
+            UpdatableColumnRef::Addresses(val) => self.update_addresses_qv(val),
+            UpdatableColumnRef::PutAddresses(key, value) => self.put_addresses_qv(key, value),
+            UpdatableColumnRef::RemoveKeyAddresses(key) => self.remove_key_addresses_qv(key),
+            UpdatableColumnRef::Details(val) => self.update_details_qv(val),
+        }
+    }
+    #[doc = r" Performs the dynamic update"]
+    pub async fn update_dyn(
+        &self,
+        session: &CachingSession,
+        val: UpdatableColumnRef<'_>,
+    ) -> ScyllaQueryResult {
+        self.update_dyn_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a dynamic amount of column updates"]
+    pub fn update_dyn_multiple_qv(
+        &self,
+        val: &[UpdatableColumnRef<'_>],
+    ) -> Result<Update<String, SerializedValues>, Error> {
+        if val.is_empty() {
+            panic!("Empty update array")
+        }
+        let mut query = vec![];
+        let mut serialized_values = SerializedValues::with_capacity(val.len() + 1usize);
+        for v in val {
+            match v {
+                UpdatableColumnRef::Address(v) => {
+                    query.push(concat!(stringify!(address), " = ?"));
+                    serialized_values.add_value(v)?;
+                }
+                UpdatableColumnRef::Addresses(v) => {
+                    query.push(concat!(stringify!(addresses), " = ?"));
+                    serialized_values.add_value(v)?;
+                }
+                UpdatableColumnRef::PutAddresses(key, value) => {
+                    query.push("addresses[?] = ?");
+                    serialized_values.add_value(&key)?;
+                    serialized_values.add_value(&value)?;
+                }
+                UpdatableColumnRef::RemoveKeyAddresses(key) => {
+                    query.push("addresses = addresses - ?");
+                    serialized_values.add_value(&std::slice::from_ref(&key))?;
+                }
+                UpdatableColumnRef::Details(v) => {
+                    query.push(concat!(stringify!(details), " = ?"));
+                    serialized_values.add_value(v)?;
+                }
+            }
+        }
+        let columns_to_update: String = query.join(", ");
+        let update_detailstatement = format!(
+            "update {} set {} {}",
+            "udt_table", columns_to_update, "where a = ?"
+        );
+        serialized_values.add_value(&self.a)?;
+        Ok(Update::new(Qv {
+            query: update_detailstatement,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs the dynamic column updates"]
+    pub async fn update_dyn_multiple(
+        &self,
+        session: &CachingSession,
+        val: &[UpdatableColumnRef<'_>],
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Updating table {} with vals {:#?} for row {:#?}",
+            "udt_table",
+            val,
+            self
+        );
+        self.update_dyn_multiple_qv(val)?.update(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion"]
+    pub fn delete_qv(&self) -> Result<DeleteUnique, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(DeleteUnique::new(Qv {
+            query: DELETE_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion"]
+    pub async fn delete(&self, session: &CachingSession) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Deleting a row from table {} with values {:#?}",
+            "udt_table",
+            self
+        );
+        self.delete_qv()?.delete_unique(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion, which is only applied if the row exists"]
+    pub fn delete_if_exists_qv(&self) -> Result<ConditionalMutation, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(ConditionalMutation::new(Qv {
+            query: DELETE_IF_EXISTS_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion if the row exists, the result tells if the row was deleted"]
+    pub async fn delete_if_exists(
+        &self,
+        session: &CachingSession,
+    ) -> Result<QueryResultConditional, Error> {
+        tracing::debug!(
+            "Deleting a row if it exists from table {} with values {:#?}",
+            "udt_table",
+            self
+        );
+        self.delete_if_exists_qv()?.execute(session).await
+    }
+}
+impl PrimaryKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a single row deletion with a write timestamp"]
+    pub fn delete_with_qv(&self, timestamp: TimestampType) -> Result<DeleteUnique, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(2usize);
+        serialized_values.add_value(&timestamp)?;
+        serialized_values.add_value(&self.a)?;
+        Ok(DeleteUnique::new(Qv {
+            query: DELETE_WITH_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Performs a single row deletion with a write timestamp, data written with a later timestamp is kept"]
+    pub async fn delete_with(
+        &self,
+        session: &CachingSession,
+        timestamp: TimestampType,
+    ) -> ScyllaQueryResult {
+        tracing::debug!(
+            "Deleting a row with timestamp {} from table {} with values {:#?}",
+            timestamp,
+            "udt_table",
+            self
+        );
+        self.delete_with_qv(timestamp)?.delete_unique(session).await
+    }
+}
+#[doc = r" The partition key equals the primary key, since the table has no clustering columns"]
+pub type PartitionKey = PrimaryKey;
+#[doc = r" The borrowed partition key equals the borrowed primary key"]
+pub type PartitionKeyRef<'a> = PrimaryKeyRef<'a>;
+impl UdtTable {
+    #[doc = r" Create a borrowed partition key from the struct values"]
+    pub fn partition_key(&self) -> PartitionKeyRef {
+        self.primary_key()
+    }
+}
+impl PartitionKeyRef<'_> {
+    #[doc = r" Returns a struct that can perform a selection of the token of the partition"]
+    pub fn token_qv(&self) -> Result<SelectUnique<(TokenType,)>, Error> {
+        let mut serialized_values = SerializedValues::with_capacity(1usize);
+        serialized_values.add_value(&self.a)?;
+        Ok(SelectUnique::new(Qv {
+            query: TOKEN_QUERY,
+            values: serialized_values,
+        }))
+    }
+    #[doc = r" Selects the token of the partition, which determines the nodes that store the partition"]
+    #[doc = r" None is returned if the partition has no rows"]
+    pub async fn token(&self, session: &CachingSession) -> Result<Option<TokenType>, Error> {
+        tracing::debug!(
+            "Selecting token of partition of table {} with values {:#?}",
+            "udt_table",
+            self
+        );
+        Ok(self
+            .token_qv()?
+            .select(session)
+            .await?
+            .entity
+            .map(|(token,)| token))
+    }
+}
+#[doc = r" Returns a struct that can perform a selection of the rows of which the token of the"]
+#[doc = r" partition key is within the range, the start is exclusive and the end is inclusive"]
+pub fn select_by_token_range_qv(
+    start: TokenType,
+    end: TokenType,
+) -> Result<SelectMultiple<UdtTable, &'static str, SerializedValues>, Error> {
+    let mut serialized_values = SerializedValues::with_capacity(2);
+    serialized_values.add_value(&start)?;
+    serialized_values.add_value(&end)?;
+    Ok(SelectMultiple::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: serialized_values,
+    }))
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range, with a specified page size"]
+pub async fn select_by_token_range(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: Option<i32>,
+) -> Result<TypedRowIterator<UdtTable>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select(session, page_size)
+        .await
+}
+#[doc = r" Selects the rows of which the token of the partition key is within the range and accumulates them in memory"]
+pub async fn select_by_token_range_in_memory(
+    session: &CachingSession,
+    start: TokenType,
+    end: TokenType,
+    page_size: i32,
+) -> Result<QueryEntityVec<UdtTable>, Error> {
+    select_by_token_range_qv(start, end)?
+        .select_all_in_memory(session, page_size)
+        .await
+}
+#[doc = r" Scans the whole table with a query per split of the token ring, of which at most 'concurrency'"]
+#[doc = r" are executed at the same time. The rows are returned in the order in which they arrive"]
+pub fn scan_all_parallel(
+    session: &CachingSession,
+    splits: usize,
+    concurrency: usize,
+) -> Result<impl catalytic::query_transform::Stream<Item = Result<UdtTable, Error>> + '_, Error> {
+    tracing::debug!("Scanning table {} with {} splits", "udt_table", splits);
+    SelectMultiple::<UdtTable, _, _>::new(Qv {
+        query: SELECT_BY_TOKEN_RANGE_QUERY,
+        values: SerializedValues::new(),
+    })
+    .scan_parallel(session, splits, concurrency)
+}
+#[doc = r" This struct can be converted to a borrowed struct which can be used to update single rows"]
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdatableColumn {
+    Address(super::user_defined_types::Address),
+    Addresses(std::collections::HashMap<String, super::user_defined_types::Address>),
+    PutAddresses(String, super::user_defined_types::Address),
+    RemoveKeyAddresses(String),
+    Details(super::user_defined_types::PersonDetails),
+}
+impl UpdatableColumn {
+    #[doc = r" Conversation method to go from an owned updatable column struct to a borrowed updatable column struct"]
+    pub fn to_ref(&self) -> UpdatableColumnRef<'_> {
+        match &self {
+            UpdatableColumn::Address(v) => UpdatableColumnRef::Address(v),
+            UpdatableColumn::Addresses(v) => UpdatableColumnRef::Addresses(v),
+            UpdatableColumn::PutAddresses(key, value) => {
+                UpdatableColumnRef::PutAddresses(key, value)
+            }
+            UpdatableColumn::RemoveKeyAddresses(key) => UpdatableColumnRef::RemoveKeyAddresses(key),
+            UpdatableColumn::Details(v) => UpdatableColumnRef::Details(v),
+        }
+    }
+}
+#[doc = r" This struct can be used to update columns"]
+#[doc = r" If you have a borrowed primary key and you want to update a column, you can pass in"]
+#[doc = r" one of the variants"]
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub enum UpdatableColumnRef<'a> {
+    Address(&'a super::user_defined_types::Address),
+    Addresses(&'a std::collections::HashMap<String, super::user_defined_types::Address>),
+    PutAddresses(&'a str, &'a super::user_defined_types::Address),
+    RemoveKeyAddresses(&'a str),
+    Details(&'a super::user_defined_types::PersonDetails),
+}
+pub trait UpdatableColumnVec {
+    fn to_ref(&self) -> Vec<UpdatableColumnRef<'_>>;
+}
+impl UpdatableColumnVec for Vec<UpdatableColumn> {
+    #[doc = r" Conversation method to go from a vec of owned updatable column structs to a vec of borrowed updatable column structs"]
+    fn to_ref(&self) -> Vec<UpdatableColumnRef<'_>> {
+        self.iter().map(|v| v.to_ref()).collect()
+    }
+}
+impl From<UpdatableColumnRef<'_>> for UpdatableColumn {
+    #[doc = r" Conversation method to go from a borrowed updatable column struct to an owned updatable column struct"]
+    fn from(f: UpdatableColumnRef<'_>) -> UpdatableColumn {
+        match f {
+            UpdatableColumnRef::Address(v) => UpdatableColumn::Address(v.clone()),
+            UpdatableColumnRef::Addresses(v) => UpdatableColumn::Addresses(v.clone()),
+            UpdatableColumnRef::PutAddresses(key, value) => {
+                UpdatableColumn::PutAddresses(key.to_string(), value.clone())
+            }
+            UpdatableColumnRef::RemoveKeyAddresses(key) => {
+                UpdatableColumn::RemoveKeyAddresses(key.to_string())
+            }
+            UpdatableColumnRef::Details(v) => UpdatableColumn::Details(v.clone()),
+        }
+    }
+}
+impl UpdatableColumnRef<'_> {
+    #[doc = r" Conversation method to go from a borrowed updatable column struct to an owned updatable column struct"]
+    pub fn into_owned(self) -> UpdatableColumn {
+        self.into()
+    }
+}
+impl UdtTable {
+    #[doc = "Creates the updatable column address which can be used to update it in the database"]
+    pub fn updatable_column_address(&self) -> UpdatableColumnRef {
+        UpdatableColumnRef::Address(&self.address)
+    }
+    #[doc = "Creates the updatable column addresses which can be used to update it in the database"]
+    pub fn updatable_column_addresses(&self) -> UpdatableColumnRef {
+        UpdatableColumnRef::Addresses(&self.addresses)
+    }
+    #[doc = "Creates the updatable column details which can be used to update it in the database"]
+    pub fn updatable_column_details(&self) -> UpdatableColumnRef {
+        UpdatableColumnRef::Details(&self.details)
+    }
+}
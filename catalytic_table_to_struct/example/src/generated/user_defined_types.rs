@@ -0,0 +1,58 @@
+// Generated file
+#[doc = "The user defined type 'address'"]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Address {
+    pub street: String,
+    pub number: i32,
+}
+impl scylla::cql_to_rust::FromCqlVal<scylla::frame::response::result::CqlValue> for Address {
+    fn from_cql(
+        cql_val: scylla::frame::response::result::CqlValue,
+    ) -> Result<Self, scylla::cql_to_rust::FromCqlValError> {
+        let mut fields = catalytic::user_defined_type::user_defined_type_fields(cql_val)?;
+        Ok(Address {
+            street: scylla::cql_to_rust::FromCqlVal::from_cql(fields.next().flatten())?,
+            number: scylla::cql_to_rust::FromCqlVal::from_cql(fields.next().flatten())?,
+        })
+    }
+}
+impl scylla::frame::value::Value for Address {
+    fn serialize(&self, buf: &mut Vec<u8>) -> Result<(), scylla::frame::value::ValueTooBig> {
+        catalytic::user_defined_type::serialize_user_defined_type(buf, |buf| {
+            scylla::frame::value::Value::serialize(&self.street, buf)?;
+            scylla::frame::value::Value::serialize(&self.number, buf)?;
+            Ok(())
+        })
+    }
+}
+impl catalytic::cql_type::CqlTypeOf<catalytic::cql_type::UserDefinedType<Address>> for Address {}
+#[doc = "The user defined type 'person_details'"]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonDetails {
+    pub address: super::user_defined_types::Address,
+    pub previous_addresses: std::vec::Vec<super::user_defined_types::Address>,
+}
+impl scylla::cql_to_rust::FromCqlVal<scylla::frame::response::result::CqlValue> for PersonDetails {
+    fn from_cql(
+        cql_val: scylla::frame::response::result::CqlValue,
+    ) -> Result<Self, scylla::cql_to_rust::FromCqlValError> {
+        let mut fields = catalytic::user_defined_type::user_defined_type_fields(cql_val)?;
+        Ok(PersonDetails {
+            address: scylla::cql_to_rust::FromCqlVal::from_cql(fields.next().flatten())?,
+            previous_addresses: scylla::cql_to_rust::FromCqlVal::from_cql(fields.next().flatten())?,
+        })
+    }
+}
+impl scylla::frame::value::Value for PersonDetails {
+    fn serialize(&self, buf: &mut Vec<u8>) -> Result<(), scylla::frame::value::ValueTooBig> {
+        catalytic::user_defined_type::serialize_user_defined_type(buf, |buf| {
+            scylla::frame::value::Value::serialize(&self.address, buf)?;
+            scylla::frame::value::Value::serialize(&self.previous_addresses, buf)?;
+            Ok(())
+        })
+    }
+}
+impl catalytic::cql_type::CqlTypeOf<catalytic::cql_type::UserDefinedType<PersonDetails>>
+    for PersonDetails
+{
+}
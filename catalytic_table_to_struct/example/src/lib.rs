@@ -13,15 +13,34 @@ pub enum MyJsonEnum {
 
 #[cfg(test)]
 mod test {
+    use crate::generated::another_test_table::{
+        scan_all_parallel, select_by_token_range_in_memory, AnotherTestTable, ClusteringPrefix,
+        PartitionKeyRef,
+    };
     use crate::generated::child::{truncate, Child};
-    use crate::generated::person::PersonRef;
-    use crate::generated::Person;
+    use crate::generated::collection_table::{CollectionTable, UpdatableColumn};
+    use crate::generated::counter_table::CounterTable;
+    use crate::generated::person::{select_by_email_in_memory, PersonRef};
+    use crate::generated::udt_table::UdtTable;
+    use crate::generated::{Address, Person, PersonDetails};
     use crate::{MyJsonEnum, MyJsonType};
+    use catalytic::batch::{CounterBatch, LoggedBatch, UnloggedBatch};
+    use catalytic::page_cursor::PageCursorError;
+    use catalytic::query_transform::{QueryOptions, TimestampType};
+    use catalytic::retry_policy::{ExponentialBackoff, RetryPolicy};
     use catalytic::runtime::create_connection;
-    use catalytic_macro::{query, query_base_table};
-    use futures_util::StreamExt;
-    use scylla::frame::value::{SerializeValuesError, SerializedValues};
+    use catalytic::Error;
+    use catalytic_macro::{query, query_as, query_base_table};
+    use futures_util::{StreamExt, TryStreamExt};
+    use scylla::frame::response::result::CqlValue;
+    use scylla::frame::types::SerialConsistency;
+    use scylla::frame::value::{Counter, SerializedValues};
     use scylla::CachingSession;
+    use std::collections::HashSet;
+    use std::ops::Bound;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
 
     #[tokio::test]
     async fn crud() {
@@ -212,7 +231,8 @@ mod test {
                     .primary_key()
                     .select_unique_expect(&session)
                     .await
-                    .is_err());
+                    .unwrap_err()
+                    .is_not_found());
             };
         }
 
@@ -239,7 +259,1148 @@ mod test {
     }
 
     #[tokio::test]
-    async fn paging() -> Result<(), SerializeValuesError> {
+    async fn collections() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        crate::generated::collection_table::truncate(&session)
+            .await
+            .unwrap();
+
+        let mut collection_table = CollectionTable {
+            a: 1,
+            l: vec![1, 2, 3],
+            m: vec![("key".to_string(), (1, "value".to_string()))]
+                .into_iter()
+                .collect(),
+            s: vec!["a".to_string(), "b".to_string()].into_iter().collect(),
+        };
+
+        collection_table.to_ref().insert(&session).await.unwrap();
+
+        macro_rules! eq {
+            () => {
+                assert_eq!(
+                    collection_table
+                        .primary_key()
+                        .select_unique_expect(&session)
+                        .await
+                        .unwrap()
+                        .entity,
+                    collection_table
+                );
+            };
+        }
+
+        eq!();
+
+        // A list is borrowed as a slice
+        let l = vec![4, 5];
+
+        collection_table
+            .primary_key()
+            .update_l(&session, &l)
+            .await
+            .unwrap();
+        collection_table.l = l;
+
+        eq!();
+
+        collection_table.s.insert("c".to_string());
+        collection_table
+            .m
+            .insert("other_key".to_string(), (2, "other_value".to_string()));
+
+        collection_table
+            .primary_key()
+            .update_dyn_multiple(
+                &session,
+                &[
+                    collection_table.updatable_column_s(),
+                    collection_table.updatable_column_m(),
+                ],
+            )
+            .await
+            .unwrap();
+
+        eq!();
+
+        let pk = collection_table.primary_key().into_owned();
+        let pk = pk.to_ref();
+
+        pk.append_l(&session, &[4, 5]).await.unwrap();
+        pk.prepend_l(&session, &[0]).await.unwrap();
+        pk.remove_from_l(&session, &[2, 4]).await.unwrap();
+        collection_table.in_memory_updates(vec![
+            UpdatableColumn::AppendL(vec![4, 5]),
+            UpdatableColumn::PrependL(vec![0]),
+            UpdatableColumn::RemoveFromL(vec![2, 4]),
+        ]);
+
+        assert_eq!(vec![0, 5, 5], collection_table.l);
+        eq!();
+
+        pk.put_m(&session, "other_key", &(2, "other_value".to_string()))
+            .await
+            .unwrap();
+        pk.remove_key_m(&session, "key").await.unwrap();
+        collection_table.in_memory_updates(vec![
+            UpdatableColumn::PutM("other_key".to_string(), (2, "other_value".to_string())),
+            UpdatableColumn::RemoveKeyM("key".to_string()),
+        ]);
+
+        eq!();
+
+        let c: HashSet<_> = vec!["c".to_string()].into_iter().collect();
+        let a: HashSet<_> = vec!["a".to_string()].into_iter().collect();
+
+        pk.append_s(&session, &c).await.unwrap();
+        pk.remove_from_s(&session, &a).await.unwrap();
+        collection_table.in_memory_updates(vec![
+            UpdatableColumn::AppendS(c),
+            UpdatableColumn::RemoveFromS(a),
+        ]);
+
+        eq!();
+
+        // Collection operations can be mixed with replacing other columns
+        let s: HashSet<_> = vec!["d".to_string()].into_iter().collect();
+        let updates = vec![UpdatableColumn::AppendL(vec![6]), UpdatableColumn::S(s)];
+
+        pk.update_dyn_multiple(
+            &session,
+            &updates.iter().map(|u| u.to_ref()).collect::<Vec<_>>(),
+        )
+        .await
+        .unwrap();
+        collection_table.in_memory_updates(updates);
+
+        eq!();
+
+        let a = 1;
+        let l = vec![7];
+        let s = collection_table.s.clone();
+        let transformed_type = query!(
+            "update collection_table set l = ?, s = ? where a = ?",
+            l,
+            s,
+            a
+        );
+
+        assert_serialized_values!(transformed_type, l, s, a);
+
+        // The keys removed from a map are bound as a set
+        let keys: HashSet<_> = vec!["other_key".to_string()].into_iter().collect();
+
+        query!(
+            "update collection_table set l = ? + l, m = m - ? where a = ?",
+            l,
+            keys,
+            a
+        )
+        .execute(&session)
+        .await?;
+        collection_table.in_memory_updates(vec![
+            UpdatableColumn::PrependL(l),
+            UpdatableColumn::RemoveKeyM("other_key".to_string()),
+        ]);
+
+        eq!();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn counters() {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        crate::generated::counter_table::truncate(&session)
+            .await
+            .unwrap();
+
+        let pk = crate::generated::counter_table::PrimaryKey { a: 1 };
+        let pk = pk.to_ref();
+
+        // The row is created by the first counter update
+        pk.increment_b(&session, 5).await.unwrap();
+        pk.decrement_b(&session, 2).await.unwrap();
+        pk.increment_c(&session, 1).await.unwrap();
+
+        let counters = |b, c| CounterTable {
+            a: 1,
+            b: Counter(b),
+            c: Counter(c),
+        };
+
+        assert_eq!(
+            counters(3, 1),
+            pk.select_unique_expect(&session).await.unwrap().entity
+        );
+
+        pk.update_counters(&session, 10, -3).await.unwrap();
+
+        assert_eq!(
+            counters(13, -2),
+            pk.select_unique_expect(&session).await.unwrap().entity
+        );
+
+        let mut batch = CounterBatch::new();
+
+        batch
+            .append(&pk.increment_b_qv(1).unwrap())
+            .unwrap()
+            .append(&pk.decrement_c_qv(2).unwrap())
+            .unwrap();
+        batch.execute(&session).await.unwrap();
+
+        assert_eq!(
+            counters(14, -4),
+            pk.select_unique_expect(&session).await.unwrap().entity
+        );
+
+        let a = 1;
+
+        query!(
+            "update counter_table set b = b - ?, c = c + ? where a = ?",
+            Counter(4),
+            Counter(6),
+            a
+        )
+        .execute(&session)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            counters(10, 2),
+            pk.select_unique_expect(&session).await.unwrap().entity
+        );
+    }
+
+    #[tokio::test]
+    async fn batches() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let row = |b: &str, d| AnotherTestTable {
+            a: 20,
+            b: b.to_string(),
+            c: "c".to_string(),
+            d,
+        };
+        let first = row("1", 1);
+        let second = row("2", 2);
+        let mut batch = LoggedBatch::new();
+
+        batch
+            .append(&first.to_ref().insert_qv()?)?
+            .append(&second.to_ref().insert_qv()?)?
+            .append(&first.primary_key().update_d_qv(&3)?)?;
+
+        assert_eq!(3, batch.len());
+
+        batch.execute(&session).await.unwrap();
+
+        let first_pk = first.primary_key().into_owned();
+        let first_pk = first_pk.to_ref();
+        let second_pk = second.primary_key().into_owned();
+        let second_pk = second_pk.to_ref();
+
+        assert_eq!(
+            Some(row("1", 3)),
+            first_pk.select_unique(&session).await.unwrap().entity
+        );
+        assert_eq!(
+            Some(second),
+            second_pk.select_unique(&session).await.unwrap().entity
+        );
+
+        let a = 20;
+        let mut batch = UnloggedBatch::new();
+
+        batch
+            .append(&second_pk.delete_qv()?)?
+            .append(&query!("delete from another_test_table where a = ?", a))?;
+        batch.execute(&session).await.unwrap();
+
+        assert_eq!(None, first_pk.select_unique(&session).await.unwrap().entity);
+        assert_eq!(
+            None,
+            second_pk.select_unique(&session).await.unwrap().entity
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lightweight_transactions() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let mut row = AnotherTestTable {
+            a: 10,
+            b: "b".to_string(),
+            c: "c".to_string(),
+            d: 1,
+        };
+        let pk = row.primary_key().into_owned();
+        let pk = pk.to_ref();
+
+        pk.delete(&session).await.unwrap();
+
+        let result = row.to_ref().insert_if_not_exists(&session).await.unwrap();
+
+        assert!(result.applied);
+        assert!(result.existing.is_none());
+
+        // The existing row is returned if the transaction is not applied
+        let other = AnotherTestTable {
+            d: 2,
+            ..row.clone()
+        };
+        let result = other.to_ref().insert_if_not_exists(&session).await.unwrap();
+
+        assert!(!result.applied);
+        assert_eq!(Some(row.clone()), result.existing);
+
+        // A conditional update or delete returns the columns of the condition (Cassandra)
+        // or all the columns (ScyllaDB), so only check that the existing value of d is returned
+        let result = pk.update_d_if(&session, &3, &2).await.unwrap();
+
+        assert!(!result.applied);
+        assert!(result
+            .existing
+            .unwrap()
+            .columns
+            .contains(&Some(CqlValue::Int(1))));
+
+        let options = QueryOptions::default().serial_consistency(SerialConsistency::LocalSerial);
+        let result = pk
+            .update_d_if_qv(&3, &1)?
+            .with_options(options)
+            .execute(&session)
+            .await
+            .unwrap();
+
+        assert!(result.applied);
+        row.d = 3;
+
+        let (a, b, c, d) = (row.a, row.b.clone(), row.c.clone(), 4);
+        let transformed_type = query!(
+            "update another_test_table set d = ? where a = ? and b = ? and c = ? if exists",
+            d,
+            a,
+            b,
+            c
+        );
+
+        assert!(transformed_type.execute(&session).await.unwrap().applied);
+        row.d = d;
+
+        let expected_d = 5;
+        let transformed_type = query!(
+            "delete from another_test_table where a = ? and b = ? and c = ? if d = ?",
+            a,
+            b,
+            c,
+            expected_d
+        );
+        let result = transformed_type.execute(&session).await.unwrap();
+
+        assert!(!result.applied);
+        assert!(result
+            .existing
+            .unwrap()
+            .columns
+            .contains(&Some(CqlValue::Int(row.d))));
+        assert!(pk.delete_if_exists(&session).await.unwrap().applied);
+        assert!(!pk.delete_if_exists(&session).await.unwrap().applied);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_timestamps() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let mut row = AnotherTestTable {
+            a: 30,
+            b: "b".to_string(),
+            c: "c".to_string(),
+            d: 1,
+        };
+        let pk = row.primary_key().into_owned();
+        let pk = pk.to_ref();
+        let select = || async { pk.select_unique(&session).await.unwrap().entity };
+
+        pk.delete(&session).await.unwrap();
+        row.to_ref().insert_with(&session, 100).await.unwrap();
+
+        // Writes with an older timestamp than the current value are ignored
+        pk.update_d_with(&session, &2, 50).await.unwrap();
+
+        assert_eq!(Some(row.clone()), select().await);
+
+        pk.update_d_with(&session, &3, 200).await.unwrap();
+        row.d = 3;
+
+        // Only the values written before the timestamp of the deletion are deleted
+        pk.delete_with(&session, 150).await.unwrap();
+
+        assert_eq!(Some(row.clone()), select().await);
+
+        let a = row.a;
+        let timestamp: TimestampType = 300;
+
+        query!(
+            "delete from another_test_table using timestamp ? where a = ?",
+            timestamp,
+            a
+        )
+        .delete_multiple(&session)
+        .await
+        .unwrap();
+
+        assert_eq!(None, select().await);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn writetime_and_ttl() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let row = AnotherTestTable {
+            a: 40,
+            b: "b".to_string(),
+            c: "c".to_string(),
+            d: 1,
+        };
+        let pk = row.primary_key().into_owned();
+        let pk = pk.to_ref();
+
+        pk.delete(&session).await.unwrap();
+        row.to_ref().insert_with(&session, 100).await.unwrap();
+
+        let with_writetime = pk
+            .select_unique_with_writetime_expect(&session)
+            .await
+            .unwrap()
+            .entity;
+
+        assert_eq!(row, with_writetime.entity);
+        assert_eq!((Some(100),), with_writetime.metadata);
+        // WithMetadata derefs to the entity
+        assert_eq!(row.d, with_writetime.d);
+
+        row.to_ref().insert_ttl(&session, 1000).await.unwrap();
+
+        let (a, b, c) = (row.a, row.b.clone(), row.c.clone());
+        let with_ttl = query!(
+            "select a, b, c, d, ttl(d) from another_test_table where a = ? and b = ? and c = ?",
+            a,
+            b,
+            c
+        )
+        .select(&session)
+        .await
+        .unwrap()
+        .entity
+        .unwrap();
+        let ttl = with_ttl.metadata.0.unwrap();
+
+        assert_eq!(row, with_ttl.entity);
+        assert!(ttl > 0 && ttl <= 1000);
+
+        pk.delete(&session).await.unwrap();
+
+        Ok(())
+    }
+
+    // The fields are mapped by name, not in the order of the selected columns
+    #[derive(Debug, PartialEq)]
+    struct DWithWritetime {
+        written: Option<TimestampType>,
+        b: String,
+        value: i32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct BAndC {
+        b: String,
+        c: String,
+    }
+
+    #[tokio::test]
+    async fn projections() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let row = |b: &str, d| AnotherTestTable {
+            a: 50,
+            b: b.to_string(),
+            c: "c".to_string(),
+            d,
+        };
+        let first = row("1", 1);
+        let second = row("2", 2);
+
+        first.to_ref().insert_with(&session, 100).await.unwrap();
+        second.to_ref().insert_with(&session, 100).await.unwrap();
+
+        let a = first.a;
+        let rows = query!(
+            "select b, d as value from another_test_table where a = ?",
+            a
+        )
+        .select_all_in_memory(&session, 10)
+        .await
+        .unwrap()
+        .entities;
+
+        assert_eq!(2, rows.len());
+        assert_eq!(first.b, rows[0].b);
+        assert_eq!(first.d, rows[0].value);
+        assert_eq!(second.d, rows[1].value);
+
+        let (b, c) = (first.b.clone(), first.c.clone());
+        let unique = query!(
+            "select d, writetime(d) from another_test_table where a = ? and b = ? and c = ?",
+            a,
+            b,
+            c
+        )
+        .select(&session)
+        .await
+        .unwrap()
+        .entity
+        .unwrap();
+
+        assert_eq!(first.d, unique.d);
+        assert_eq!(Some(100), unique.writetime_d);
+
+        let rows = query_as!(
+            DWithWritetime,
+            "select b, d as value, writetime(d) as written from another_test_table where a = ?",
+            a
+        )
+        .select_all_in_memory(&session, 10)
+        .await
+        .unwrap()
+        .entities;
+
+        assert_eq!(
+            DWithWritetime {
+                b: second.b.clone(),
+                value: second.d,
+                written: Some(100),
+            },
+            rows[1]
+        );
+
+        // Both columns are text, so mapping by position would swap them
+        let unique = query_as!(
+            BAndC,
+            "select c, b from another_test_table where a = ? and b = ? limit 1",
+            a,
+            b
+        )
+        .select(&session)
+        .await?
+        .entity
+        .unwrap();
+
+        assert_eq!(
+            BAndC {
+                b: first.b.clone(),
+                c: first.c.clone(),
+            },
+            unique
+        );
+
+        query!("delete from another_test_table where a = ?", a)
+            .delete_multiple(&session)
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn query_arguments() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let mut row = AnotherTestTable {
+            a: 60,
+            b: "b".to_string(),
+            c: "c".to_string(),
+            d: 1,
+        };
+
+        row.to_ref().insert(&session).await.unwrap();
+
+        // Arguments can be expressions
+        let rows = query!(
+            "select * from another_test_table where a = ? and b = ?",
+            row.a,
+            row.b.as_str()
+        )
+        .select_all_in_memory(&session, 10)
+        .await
+        .unwrap()
+        .entities;
+
+        assert_eq!(vec![row.clone()], rows);
+
+        // Named bind markers use the argument with the same name, or else the variable
+        let b = &row.b;
+        let rows = query!(
+            "select * from another_test_table where a = :a and b = :b",
+            a = row.a
+        )
+        .select_all_in_memory(&session, 10)
+        .await
+        .unwrap()
+        .entities;
+
+        assert_eq!(vec![row.clone()], rows);
+
+        let result = query!(
+            "update another_test_table set d = :d where a = :a and b = :b and c = ? if exists",
+            &row.c,
+            a = row.a,
+            d = row.d + 1
+        )
+        .execute(&session)
+        .await
+        .unwrap();
+
+        assert!(result.applied);
+        row.d += 1;
+
+        assert_eq!(
+            Some(row.clone()),
+            row.primary_key()
+                .select_unique(&session)
+                .await
+                .unwrap()
+                .entity
+        );
+
+        row.primary_key().delete(&session).await.unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    fn argument_types() -> Result<(), Error> {
+        // Json mapped columns accept the mapped type, nullable columns an option
+        let json_nullable = Some(MyJsonType { age: 1 });
+        let birthday = 1;
+        let transformed_type = query!(
+            "update child set json_nullable = ? where birthday = ?",
+            json_nullable,
+            birthday
+        );
+        assert_serialized_values!(transformed_type, json_nullable, birthday);
+
+        let d: Option<i32> = None;
+        let transformed_type = query!(
+            "update another_test_table set d = ? where a = 1 and b = 'b' and c = 'c'",
+            d
+        );
+        assert_serialized_values!(transformed_type, d);
+
+        // An 'in' restriction accepts a slice as well
+        let c = [1, 2];
+        let transformed_type = query!("select * from test_table where b = 1 and c in ?", &c[..]);
+        assert_serialized_values!(transformed_type, &c[..]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn secondary_indexes() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let person = Person {
+            name: "indexed".to_string(),
+            age: 70,
+            email: Some("indexed@example.com".to_string()),
+        };
+
+        person.to_ref().insert(&session).await.unwrap();
+
+        let email = "indexed@example.com";
+        let rows = select_by_email_in_memory(&session, email, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert_eq!(vec![person.clone()], rows);
+
+        // The indexed column can be used in the query macro without 'allow filtering'
+        let rows = query!("select * from person where email = ?", email)
+            .select_all_in_memory(&session, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert_eq!(vec![person.clone()], rows);
+
+        person.primary_key().delete(&session).await.unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn allow_filtering() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let row = AnotherTestTable {
+            a: 80,
+            b: "b".to_string(),
+            c: "c".to_string(),
+            d: 81,
+        };
+
+        row.to_ref().insert(&session).await.unwrap();
+
+        let rows = query!(
+            allow_filtering,
+            "select * from another_test_table where a = ? and d = ? allow filtering",
+            row.a,
+            row.d
+        )
+        .select_all_in_memory(&session, 10)
+        .await
+        .unwrap()
+        .entities;
+
+        assert_eq!(vec![row.clone()], rows);
+
+        row.primary_key().delete(&session).await.unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn partitions() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let rows = ["a", "b", "c"]
+            .iter()
+            .map(|b| AnotherTestTable {
+                a: 90,
+                b: b.to_string(),
+                c: "c".to_string(),
+                d: 1,
+            })
+            .collect::<Vec<_>>();
+
+        for row in &rows {
+            row.to_ref().insert(&session).await.unwrap();
+        }
+
+        let partition_key = rows[0].partition_key();
+
+        assert_eq!(PartitionKeyRef { a: &90 }, partition_key);
+        assert_eq!(partition_key, rows[2].primary_key().partition_key());
+
+        let partition = partition_key
+            .select_partition(false)?
+            .select_all_in_memory(&session, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert_eq!(rows, partition);
+
+        let partition = partition_key
+            .select_partition(true)?
+            .select_all_in_memory(&session, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert_eq!(rows.iter().rev().cloned().collect::<Vec<_>>(), partition);
+
+        let range = (
+            Bound::Excluded(ClusteringPrefix::B("a")),
+            Bound::Included(ClusteringPrefix::C("c", "c")),
+        );
+
+        let partition = partition_key
+            .select_partition_range(range, false)?
+            .select_all_in_memory(&session, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert_eq!(rows[1..].to_vec(), partition);
+
+        let range = (
+            Bound::Included(ClusteringPrefix::B("a")),
+            Bound::Excluded(ClusteringPrefix::B("c")),
+        );
+
+        let partition = partition_key
+            .select_partition_range(range, true)?
+            .select_all_in_memory(&session, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert_eq!(vec![rows[1].clone(), rows[0].clone()], partition);
+
+        partition_key
+            .delete_range(..=ClusteringPrefix::B("a"))?
+            .delete_multiple(&session)
+            .await
+            .unwrap();
+
+        let partition = partition_key
+            .select_partition(false)?
+            .select_all_in_memory(&session, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert_eq!(rows[1..].to_vec(), partition);
+
+        partition_key
+            .delete_partition()?
+            .delete_multiple(&session)
+            .await
+            .unwrap();
+
+        let partition = partition_key
+            .select_partition(false)?
+            .select_all_in_memory(&session, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert!(partition.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tokens() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let rows = ["a", "b"]
+            .iter()
+            .map(|b| AnotherTestTable {
+                a: 91,
+                b: b.to_string(),
+                c: "c".to_string(),
+                d: 1,
+            })
+            .collect::<Vec<_>>();
+
+        for row in &rows {
+            row.to_ref().insert(&session).await.unwrap();
+        }
+
+        let token = rows[0]
+            .partition_key()
+            .token(&session)
+            .await
+            .unwrap()
+            .unwrap();
+        let partition = select_by_token_range_in_memory(&session, token - 1, token, 10)
+            .await
+            .unwrap()
+            .entities;
+
+        assert_eq!(rows, partition);
+        assert_eq!(
+            None,
+            PartitionKeyRef { a: &92 }.token(&session).await.unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_policy() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+        let options = QueryOptions::default()
+            .idempotent(true)
+            .retry_policy(ExponentialBackoff::default().max_retries(5));
+        let row = AnotherTestTable {
+            a: 93,
+            b: "b".to_string(),
+            c: "c".to_string(),
+            d: 1,
+        };
+
+        row.to_ref()
+            .insert_qv()?
+            .with_options(options.clone())
+            .insert(&session)
+            .await?;
+
+        let selected = row
+            .primary_key()
+            .select_unique_qv()?
+            .with_options(options)
+            .select(&session)
+            .await?
+            .entity;
+
+        assert_eq!(Some(row), selected);
+
+        // A select is retried without marking it as idempotent, every attempt times out
+        let retries = CountRetries::default();
+        let error = row
+            .primary_key()
+            .select_unique_qv()?
+            .with_options(
+                QueryOptions::default()
+                    .timeout(Duration::from_nanos(1))
+                    .retry_policy(retries.clone()),
+            )
+            .select(&session)
+            .await
+            .unwrap_err();
+
+        assert!(error.is_timeout());
+        assert_eq!(3, retries.0.load(Ordering::SeqCst));
+
+        // The options of a batch apply to the batch as a whole
+        let retries = CountRetries::default();
+        let mut batch = LoggedBatch::new().with_options(
+            QueryOptions::default()
+                .idempotent(true)
+                .timeout(Duration::from_nanos(1))
+                .retry_policy(retries.clone()),
+        );
+
+        batch.append(&row.primary_key().update_d_qv(&2)?)?;
+
+        let error = batch.execute(&session).await.unwrap_err();
+
+        assert!(error.is_timeout());
+        assert_eq!(3, retries.0.load(Ordering::SeqCst));
+
+        Ok(())
+    }
+
+    /// Counts the retries of the default exponential backoff
+    #[derive(Debug, Clone, Default)]
+    struct CountRetries(Arc<AtomicU32>);
+
+    impl RetryPolicy for CountRetries {
+        fn retry_delay(&self, error: &Error, attempt: u32, idempotent: bool) -> Option<Duration> {
+            let delay = ExponentialBackoff::default().retry_delay(error, attempt, idempotent);
+
+            if delay.is_some() {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+
+            delay
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_parallel() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let rows = (94..98)
+            .map(|a| AnotherTestTable {
+                a,
+                b: "b".to_string(),
+                c: "c".to_string(),
+                d: 1,
+            })
+            .collect::<Vec<_>>();
+
+        for row in &rows {
+            row.to_ref().insert(&session).await.unwrap();
+        }
+
+        let mut scanned = scan_all_parallel(&session, 8, 3)?
+            .map(Result::unwrap)
+            .filter(|row| futures_util::future::ready(rows.contains(row)))
+            .collect::<Vec<_>>()
+            .await;
+
+        scanned.sort_unstable_by_key(|row| row.a);
+
+        assert_eq!(rows, scanned);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn select_stream() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let rows = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|b| AnotherTestTable {
+                a: 99,
+                b: b.to_string(),
+                c: "c".to_string(),
+                d: 1,
+            })
+            .collect::<Vec<_>>();
+
+        for row in &rows {
+            row.to_ref().insert(&session).await.unwrap();
+        }
+
+        let partition = rows[0].partition_key().select_partition(false).unwrap();
+        let mut stream = partition.select_stream(&session, Some(2), None)?;
+
+        assert_eq!(rows[..3].to_vec(), stream.next_chunk(3).await?);
+        assert_eq!(1, stream.buffered());
+
+        let chunks = stream
+            .map(Result::unwrap)
+            .chunks(2)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(vec![rows[3..].to_vec()], chunks);
+
+        // Resume a stream from the paging state after the first page
+        let mut stream = partition.select_stream(&session, Some(2), None)?;
+
+        assert_eq!(rows[..2].to_vec(), stream.next_chunk(2).await?);
+        assert_eq!(0, stream.buffered());
+
+        let remaining = partition
+            .select_stream_transform(&session, Some(2), stream.paging_state(), |row| row.b)?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        assert_eq!(vec!["c", "d", "e"], remaining);
+
+        let mut stream = partition.select_stream(&session, Some(10), None)?;
+
+        assert_eq!(5, stream.next_chunk(10).await?.len());
+        assert!(stream.is_finished());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn select_page() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        let rows = ["a", "b", "c"]
+            .iter()
+            .map(|b| AnotherTestTable {
+                a: 98,
+                b: b.to_string(),
+                c: "c".to_string(),
+                d: 1,
+            })
+            .collect::<Vec<_>>();
+
+        for row in &rows {
+            row.to_ref().insert(&session).await.unwrap();
+        }
+
+        let partition = rows[0].partition_key().select_partition(false).unwrap();
+        let page = partition.select_page(&session, 2, None).await?;
+
+        assert_eq!(rows[..2].to_vec(), page.entities);
+
+        // The cursor can be handed out as a string and decoded when the next page is requested
+        let encoded = page.next_cursor.unwrap().encode();
+        let cursor = partition.decode_cursor(&encoded)?;
+        let page = partition.select_page(&session, 2, Some(cursor)).await?;
+
+        assert_eq!(rows[2..].to_vec(), page.entities);
+        assert!(page.next_cursor.is_none());
+
+        let other_partition = PartitionKeyRef { a: &97 }.select_partition(false).unwrap();
+
+        assert!(matches!(
+            other_partition.decode_cursor(&encoded),
+            Err(Error::PageCursorError(PageCursorError::OtherQuery))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_defined_types() -> Result<(), Error> {
+        let session = CachingSession::from(create_connection().await, 1);
+
+        crate::generated::udt_table::truncate(&session)
+            .await
+            .unwrap();
+
+        let address = |street: &str, number| Address {
+            street: street.to_string(),
+            number,
+        };
+        let mut udt_table = UdtTable {
+            a: 1,
+            address: address("street", 1),
+            addresses: vec![("work".to_string(), address("work_street", 2))]
+                .into_iter()
+                .collect(),
+            details: PersonDetails {
+                address: address("street", 1),
+                previous_addresses: vec![address("old_street", 3)],
+            },
+        };
+
+        udt_table.to_ref().insert(&session).await.unwrap();
+
+        macro_rules! eq {
+            () => {
+                assert_eq!(
+                    udt_table
+                        .primary_key()
+                        .select_unique_expect(&session)
+                        .await
+                        .unwrap()
+                        .entity,
+                    udt_table
+                );
+            };
+        }
+
+        eq!();
+
+        udt_table
+            .details
+            .previous_addresses
+            .push(address("street", 1));
+        udt_table.details.address = address("new_street", 4);
+
+        udt_table
+            .primary_key()
+            .update_details(&session, &udt_table.details)
+            .await
+            .unwrap();
+
+        eq!();
+
+        // The types of user defined types are not checked by the macro
+        let a = 1;
+        let address = address("other_street", 5);
+        let details = udt_table.details.clone();
+        let transformed_type = query!(
+            "update udt_table set address = ?, details = ? where a = ?",
+            address,
+            details,
+            a
+        );
+
+        transformed_type.update(&session).await.unwrap();
+        udt_table.address = address;
+
+        eq!();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn paging() -> Result<(), Error> {
         let session = CachingSession::from(create_connection().await, 1);
         let rows_to_generate = 100;
         let page_size = 7;
@@ -293,7 +1454,7 @@ mod test {
     }
 
     #[tokio::test]
-    async fn qmd() -> Result<(), SerializeValuesError> {
+    async fn qmd() -> Result<(), Error> {
         let session = CachingSession::from(create_connection().await, 1);
 
         crate::generated::person::truncate(&session).await.unwrap();
@@ -341,6 +1502,21 @@ mod test {
             val
         );
 
+        // Keywords are case insensitive and the query can span multiple lines
+        let transformed_type = query!(
+            "SELECT *
+            FROM test_table
+            WHERE b = 1 AND c = ?",
+            val
+        );
+
+        assert_eq!(
+            "SELECT b, c, d, a, e
+            FROM test_table
+            WHERE b = 1 AND c = ?",
+            transformed_type.query
+        );
+
         let transformed_type = query!("select * from test_table limit 1");
 
         assert_eq!(
@@ -421,11 +1597,26 @@ mod test {
         };
     }
 
+    write_failing!(allow_filtering_flag_without_clause);
+    write_failing!(allow_filtering_without_flag);
+    write_failing!(batch_counter_in_logged_batch);
+    write_failing!(batch_insert_in_counter_batch);
+    write_failing!(count_unique_row);
+    write_failing!(count_with_limit);
+    write_failing!(failing_wrong_integer_type);
+    write_failing!(filtering_non_indexed_column);
     write_failing!(failing_wrong_type_primitive);
     write_failing!(failing_wrong_type_vec);
+    write_failing!(invalid_syntax);
+    write_failing!(no_param_but_question_mark);
     write_failing!(non_complete_insert);
     write_failing!(non_existing_column);
     write_failing!(non_existing_table);
-    write_failing!(no_param_but_question_mark);
+    write_failing!(not_a_materialized_view);
     write_failing!(param_but_no_question_mark);
+    write_failing!(query_as_not_a_select);
+    write_failing!(query_not_a_literal);
+    write_failing!(unknown_named_argument);
+    write_failing!(update_without_full_pk);
+    write_failing!(use_predefined_method);
 }
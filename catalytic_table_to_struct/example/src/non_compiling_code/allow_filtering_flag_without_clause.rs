@@ -0,0 +1,9 @@
+use catalytic_macro::query;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    let b = 1;
+
+    query!(allow_filtering, "select * from test_table where b = ? and c = 1", b);
+
+    Ok(())
+}
\ No newline at end of file
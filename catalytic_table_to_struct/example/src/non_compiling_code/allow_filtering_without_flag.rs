@@ -0,0 +1,9 @@
+use catalytic_macro::query;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    let e = 1;
+
+    query!("select * from test_table where e = ? allow filtering", e);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use catalytic::batch::LoggedBatch;
+use example_project::generated::counter_table::PrimaryKeyRef;
+
+fn main() -> Result<(), catalytic::Error> {
+    let increment = PrimaryKeyRef { a: &1 }.increment_b_qv(1)?;
+
+    LoggedBatch::new().append(&increment)?;
+
+    Ok(())
+}
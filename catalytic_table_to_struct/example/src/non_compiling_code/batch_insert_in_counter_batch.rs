@@ -0,0 +1,17 @@
+use catalytic::batch::CounterBatch;
+use example_project::generated::TestTable;
+
+fn main() -> Result<(), catalytic::Error> {
+    let row = TestTable {
+        b: 1,
+        c: 2,
+        d: 3,
+        a: 4,
+        e: 5,
+    };
+    let insert = row.to_ref().insert_qv()?;
+
+    CounterBatch::new().append(&insert)?;
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+use catalytic_macro::query;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    query!("select count(*) from test_table where b = 1 limit 1");
+
+    Ok(())
+}
\ No newline at end of file
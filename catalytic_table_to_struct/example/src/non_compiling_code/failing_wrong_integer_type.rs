@@ -0,0 +1,13 @@
+use catalytic_macro::query;
+
+mod generated {
+    pub use example_project::generated::TestTable;
+}
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    let c: i8 = 1;
+
+    query!("select * from test_table where b = 1 and c = ?", c);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+use catalytic_macro::query;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    query!("select * form test_table");
+
+    Ok(())
+}
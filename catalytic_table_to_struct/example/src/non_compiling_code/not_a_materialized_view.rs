@@ -0,0 +1,7 @@
+use catalytic_macro::query_base_table;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    query_base_table!("select * from test_table");
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+use catalytic_macro::query_as;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    query_as!(Row, "delete from test_table where b = 1");
+
+    Ok(())
+}
\ No newline at end of file
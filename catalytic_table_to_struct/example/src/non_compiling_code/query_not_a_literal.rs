@@ -0,0 +1,7 @@
+use catalytic_macro::query;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    query!(1);
+
+    Ok(())
+}
\ No newline at end of file
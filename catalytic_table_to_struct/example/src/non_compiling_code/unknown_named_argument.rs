@@ -0,0 +1,9 @@
+use catalytic_macro::query;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    let b = 1;
+
+    query!("select * from test_table where b = :b", b = b, c = 1);
+
+    Ok(())
+}
\ No newline at end of file
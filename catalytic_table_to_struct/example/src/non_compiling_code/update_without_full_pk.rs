@@ -0,0 +1,7 @@
+use catalytic_macro::query;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    query!("update test_table set e = 1 where b = 1 and c = 2");
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+use catalytic_macro::query;
+
+fn main() -> Result<(), scylla::frame::value::SerializeValuesError> {
+    query!("select * from test_table where b = 1 and c = 2 and d = 3 and a = 4");
+
+    Ok(())
+}
\ No newline at end of file
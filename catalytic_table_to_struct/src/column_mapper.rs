@@ -9,13 +9,16 @@ pub struct Field {
     pub ident: Ident,
     pub ident_ty: TokenStream,
     pub ty: TokenStream,
-    /// When 'ty' is String, this will be 'str'
+    /// When 'ty' is String, this will be 'str', when it's a Vec it will be a slice
     pub borrow_ty: TokenStream,
-    /// When 'ty' is String, this will be 'to_string()', else 'clone()'
+    /// When 'ty' is String, this will be 'to_string()', for a Vec 'to_vec()', else 'clone()'
     pub from_borrow_to_owned: TokenStream,
     pub attributes: TokenStream,
     /// Contains only the primary key attributes
     pub pk_attributes: TokenStream,
+    /// The type of the column, None when the column is mapped to json
+    pub column_type: Option<ColumnType>,
+    pub is_nullable: bool,
 }
 
 pub struct StructFieldMetadata {
@@ -73,26 +76,31 @@ pub(crate) fn column_to_property(
 
         let clone = "clone()".to_string();
 
-        let (mut ty, mut borrow_ty, from_borrow_to_owned) = if let Some(json) = struct_field.json {
-            // Only text columns can be mapped to json
-            assert_eq!("text", &column.data_type);
+        let (mut ty, mut borrow_ty, from_borrow_to_owned, column_type) =
+            if let Some(json) = struct_field.json {
+                // Only text columns can be mapped to json
+                assert_eq!("text", &column.data_type);
 
-            field_ts.extend(quote! {
-                #[json]
-            });
+                field_ts.extend(quote! {
+                    #[json]
+                });
 
-            (json.clone(), json, clone)
-        } else {
-            let ty = ColumnType::new(column.data_type.as_str()).to_ty();
-            let (borrow_ty, from_borrow_to_owned) = if column.data_type.as_str() == "text" {
-                ("str".to_string(), "to_string()".to_string())
+                (json.clone(), json, clone, None)
             } else {
-                (ty.clone(), clone)
+                let column_type = ColumnType::new(column.data_type.as_str());
+                let ty = column_type.to_ty();
+                let (borrow_ty, from_borrow_to_owned) = match &column_type {
+                    _ if struct_field.is_nullable => (ty.clone(), clone),
+                    ColumnType::Text => ("str".to_string(), "to_string()".to_string()),
+                    ColumnType::List(element) => {
+                        (format!("[{}]", element.to_ty()), "to_vec()".to_string())
+                    }
+                    _ => (ty.clone(), clone),
+                };
+
+                (ty, borrow_ty, from_borrow_to_owned, Some(column_type))
             };
 
-            (ty, borrow_ty, from_borrow_to_owned)
-        };
-
         if struct_field.is_nullable {
             let make_nullable = |t| format!("std::option::Option<{}>", t);
 
@@ -116,6 +124,8 @@ pub(crate) fn column_to_property(
             from_borrow_to_owned: from_borrow_to_owned.parse().unwrap(),
             attributes,
             pk_attributes,
+            column_type,
+            is_nullable: struct_field.is_nullable,
         };
 
         fields.push(field.clone());
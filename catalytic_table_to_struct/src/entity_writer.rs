@@ -1,13 +1,16 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
-use crate::column_mapper::StructFieldMetadata;
+use crate::column_mapper::{Field, StructFieldMetadata};
 use crate::transformer::{StructTable, Transformer};
 use crate::Table;
 
-use catalytic::query_metadata::query_columns;
-use catalytic::table_metadata::ColumnInTable;
+use catalytic::schema_provider::SchemaProvider;
+use catalytic::table_metadata::{ColumnInTable, ColumnKind, ColumnType};
 
+mod collection_operation;
+mod write_counter;
+mod write_partition;
 mod write_primary_key;
 mod write_struct;
 mod write_updatable_column;
@@ -18,6 +21,7 @@ pub struct EntityWriter<'a, T: Transformer> {
     pub struct_field_metadata: StructFieldMetadata,
     pub transformer: &'a T,
     pub columns: &'a Vec<ColumnInTable>,
+    pub schema: &'a dyn SchemaProvider,
 }
 
 macro_rules! create_transformer {
@@ -34,17 +38,14 @@ impl<T: Transformer> EntityWriter<'_, T> {
         let mut tokens = quote! {
             use scylla::CachingSession;
             use scylla::transport::iterator::TypedRowIterator;
-            use scylla::transport::errors::QueryError;
             use scylla::frame::value::SerializedValues;
             #[allow(unused_imports)]
             use scylla::frame::value::SerializeValuesError;
             #[allow(unused_imports)]
             use catalytic::query_transform::{
                 ScyllaQueryResult,
-                SingleSelectQueryErrorTransform,
                 QueryEntityVecResult,
                 QueryEntityVec,
-                MultipleSelectQueryErrorTransform,
                 QueryResultUniqueRow,
                 QueryResultUniqueRowExpect,
                 CountType,
@@ -55,9 +56,19 @@ impl<T: Transformer> EntityWriter<'_, T> {
                 SelectUniqueExpect,
                 Insert,
                 Update,
+                UpdateCounter,
                 DeleteUnique,
-                Truncate
+                DeleteMultiple,
+                Truncate,
+                LightweightTransaction,
+                QueryResultLwt,
+                ConditionalMutation,
+                QueryResultConditional,
+                TimestampType,
+                TokenType,
+                WithMetadata
             };
+            use catalytic::Error;
         };
 
         if let Some(mv) = &self.table.materialized_view {
@@ -74,12 +85,15 @@ impl<T: Transformer> EntityWriter<'_, T> {
         let (tokens_constant_struct, tokens_type_struct) = write_struct::write(&self);
         let (tokens_constant_primary_key, tokens_type_primary_key) =
             write_primary_key::write(&self);
+        let (tokens_constant_partition, tokens_type_partition) = write_partition::write(&self);
         let tokens_type_updatable_columns = write_updatable_column::write(&self);
 
         tokens.extend(tokens_constant_struct);
         tokens.extend(tokens_constant_primary_key);
+        tokens.extend(tokens_constant_partition);
         tokens.extend(tokens_type_struct);
         tokens.extend(tokens_type_primary_key);
+        tokens.extend(tokens_type_partition);
         tokens.extend(tokens_type_updatable_columns);
 
         tokens
@@ -110,10 +124,13 @@ impl<T: Transformer> EntityWriter<'_, T> {
     create_transformer!(select_unique, "SelectUnique");
     create_transformer!(select_unique_expect, "SelectUniqueExpect");
     create_transformer!(update, "Update");
+    create_transformer!(update_counter, "UpdateCounter");
     create_transformer!(truncate, "Truncate");
     create_transformer!(insert, "Insert");
     create_transformer!(delete_multiple, "DeleteMultiple");
     create_transformer!(delete_unique, "DeleteUnique");
+    create_transformer!(lightweight_transaction, "LightweightTransaction");
+    create_transformer!(conditional_mutation, "ConditionalMutation");
 
     pub(crate) fn comma_separated_question_marks(&self, amount: usize) -> String {
         (0..amount)
@@ -130,7 +147,7 @@ impl<T: Transformer> EntityWriter<'_, T> {
     pub(crate) fn create_select_clause_table_table(&self, base_table: &str) -> String {
         // Create the select queries for the base table
         // Make sure the order of the columns equals the order of the struct fields of the base table
-        let base_table_columns = query_columns(base_table);
+        let base_table_columns = self.schema.columns(base_table);
         let comma_separated = self.comma_separated_column_names_columns(&base_table_columns);
 
         format!("select {} from {}", comma_separated, self.table.table_name)
@@ -156,6 +173,57 @@ impl<T: Transformer> EntityWriter<'_, T> {
         format!("where {}", pk_fields)
     }
 
+    /// Counter tables can not be inserted into and their columns can not be set,
+    /// they can only be incremented and decremented
+    pub(crate) fn is_counter_table(&self) -> bool {
+        let non_primary_key_fields = &self.struct_field_metadata.non_primary_key_fields;
+
+        !non_primary_key_fields.is_empty()
+            && non_primary_key_fields
+                .iter()
+                .all(|f| matches!(f.column_type, Some(ColumnType::Counter)))
+    }
+
+    /// The non primary key fields of which the writetime can be selected
+    /// Counters, collections and user defined types which are not frozen don't have a single writetime
+    pub(crate) fn writetime_fields(&self) -> Vec<&Field> {
+        self.struct_field_metadata
+            .non_primary_key_fields
+            .iter()
+            .filter(|f| {
+                let data_type = &self
+                    .columns
+                    .iter()
+                    .find(|c| f.ident == c.column_name)
+                    .unwrap()
+                    .data_type;
+                let multi_cell = !data_type.starts_with("frozen<")
+                    && matches!(
+                        f.column_type,
+                        Some(ColumnType::List(_))
+                            | Some(ColumnType::Set(_))
+                            | Some(ColumnType::Map(_, _))
+                            | Some(ColumnType::UserDefinedType(_))
+                    );
+
+                !multi_cell && f.column_type != Some(ColumnType::Counter)
+            })
+            .collect()
+    }
+
+    /// The primary key fields of the given kind, in the order of the primary key
+    pub(crate) fn primary_key_fields_of_kind(&self, kind: ColumnKind) -> Vec<&Field> {
+        self.struct_field_metadata
+            .primary_key_fields
+            .iter()
+            .filter(|f| {
+                self.columns
+                    .iter()
+                    .any(|c| f.ident == c.column_name && c.kind() == kind)
+            })
+            .collect()
+    }
+
     pub(crate) fn struct_ident(&self) -> Ident {
         format_ident!("{}", self.struct_name)
     }
@@ -0,0 +1,254 @@
+use crate::column_mapper::Field;
+use catalytic::table_metadata::ColumnType;
+use heck::CamelCase;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// An update that changes a part of a collection column, instead of replacing the whole collection
+pub(crate) struct CollectionOperation<'a> {
+    pub field: &'a Field,
+    pub kind: CollectionOperationKind,
+    element: &'a ColumnType,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum CollectionOperationKind {
+    /// Adds elements to the end of a list or adds elements to a set
+    Append,
+    /// Adds elements to the start of a list
+    Prepend,
+    /// Removes all occurrences of the elements from a list or set
+    RemoveFrom,
+    /// Adds or replaces a single entry in a map
+    Put,
+    /// Removes a single entry from a map
+    RemoveKey,
+}
+
+/// A parameter of a collection operation
+pub(crate) struct Parameter {
+    pub ident: Ident,
+    /// The owned type, used in the UpdatableColumn variant
+    pub ty: TokenStream,
+    /// The borrowed type, used in the update method and the UpdatableColumnRef variant
+    pub borrow_ty: TokenStream,
+    pub from_borrow_to_owned: TokenStream,
+}
+
+impl CollectionOperationKind {
+    /// Used in the doc comments, e.g. 'Performs an update operation to append to column a'
+    pub fn description(&self) -> &'static str {
+        match self {
+            CollectionOperationKind::Append => "append to",
+            CollectionOperationKind::Prepend => "prepend to",
+            CollectionOperationKind::RemoveFrom => "remove elements from",
+            CollectionOperationKind::Put => "put an entry in",
+            CollectionOperationKind::RemoveKey => "remove an entry from",
+        }
+    }
+
+    fn prefix(&self) -> &'static str {
+        match self {
+            CollectionOperationKind::Append => "append",
+            CollectionOperationKind::Prepend => "prepend",
+            CollectionOperationKind::RemoveFrom => "remove_from",
+            CollectionOperationKind::Put => "put",
+            CollectionOperationKind::RemoveKey => "remove_key",
+        }
+    }
+}
+
+/// The collection operations for a field, nullable fields and non-collection fields have none
+pub(crate) fn collection_operations(field: &Field) -> Vec<CollectionOperation> {
+    if field.is_nullable {
+        return vec![];
+    }
+
+    let (kinds, element) = match &field.column_type {
+        Some(ColumnType::List(element)) => (
+            vec![
+                CollectionOperationKind::Append,
+                CollectionOperationKind::Prepend,
+                CollectionOperationKind::RemoveFrom,
+            ],
+            element,
+        ),
+        Some(ColumnType::Set(element)) => (
+            vec![
+                CollectionOperationKind::Append,
+                CollectionOperationKind::RemoveFrom,
+            ],
+            element,
+        ),
+        Some(ColumnType::Map(key, _)) => (
+            vec![
+                CollectionOperationKind::Put,
+                CollectionOperationKind::RemoveKey,
+            ],
+            key,
+        ),
+        _ => return vec![],
+    };
+
+    kinds
+        .into_iter()
+        .map(|kind| CollectionOperation {
+            field,
+            kind,
+            element,
+        })
+        .collect()
+}
+
+impl CollectionOperation<'_> {
+    /// E.g. append_my_list
+    pub fn fn_name(&self) -> Ident {
+        format_ident!("{}_{}", self.kind.prefix(), self.field.ident)
+    }
+
+    /// E.g. APPEND_MY_LIST_QUERY
+    pub fn constant(&self) -> Ident {
+        format_ident!("{}_QUERY", self.fn_name().to_string().to_uppercase())
+    }
+
+    /// E.g. AppendMyList
+    pub fn variant(&self) -> Ident {
+        format_ident!("{}", self.fn_name().to_string().to_camel_case())
+    }
+
+    /// The part of the query after 'set'
+    pub fn set_clause(&self) -> String {
+        let column = &self.field.ident;
+
+        match self.kind {
+            CollectionOperationKind::Append => format!("{c} = {c} + ?", c = column),
+            CollectionOperationKind::Prepend => format!("{c} = ? + {c}", c = column),
+            CollectionOperationKind::RemoveFrom | CollectionOperationKind::RemoveKey => {
+                format!("{c} = {c} - ?", c = column)
+            }
+            CollectionOperationKind::Put => format!("{}[?] = ?", column),
+        }
+    }
+
+    pub fn parameters(&self) -> Vec<Parameter> {
+        let parameter = |ident: &str, column_type: &ColumnType, hashable: bool| {
+            let (ty, borrow_ty, from_borrow_to_owned) = borrow(column_type, hashable);
+
+            Parameter {
+                ident: format_ident!("{}", ident),
+                ty,
+                borrow_ty,
+                from_borrow_to_owned,
+            }
+        };
+
+        match (self.kind, &self.field.column_type) {
+            (CollectionOperationKind::Put, Some(ColumnType::Map(key, value))) => vec![
+                parameter("key", key, true),
+                parameter("value", value, false),
+            ],
+            (CollectionOperationKind::RemoveKey, _) => vec![parameter("key", self.element, true)],
+            // The elements of a list are passed in as a slice, just like the list itself
+            (_, Some(ColumnType::List(_))) => {
+                let element_ty: TokenStream = self.element.to_ty().parse().unwrap();
+
+                vec![Parameter {
+                    ident: format_ident!("val"),
+                    ty: quote! { std::vec::Vec<#element_ty> },
+                    borrow_ty: quote! { [#element_ty] },
+                    from_borrow_to_owned: quote! { to_vec() },
+                }]
+            }
+            _ => {
+                let ty = &self.field.ty;
+
+                vec![Parameter {
+                    ident: format_ident!("val"),
+                    ty: ty.clone(),
+                    borrow_ty: ty.clone(),
+                    from_borrow_to_owned: quote! { clone() },
+                }]
+            }
+        }
+    }
+
+    /// Adds the parameters to 'serialized_values'
+    pub fn serialize(&self) -> TokenStream {
+        match self.kind {
+            // The keys to remove are a set, which is serialized the same way as a slice
+            CollectionOperationKind::RemoveKey => quote! {
+                serialized_values.add_value(&std::slice::from_ref(&key))?;
+            },
+            _ => {
+                let idents = self.parameters().into_iter().map(|p| p.ident);
+
+                quote! {
+                    #(serialized_values.add_value(&#idents)?;)*
+                }
+            }
+        }
+    }
+
+    /// Applies the operation on the field of the owned struct, the parameters are owned
+    pub fn in_memory(&self) -> TokenStream {
+        let ident = &self.field.ident;
+        let is_list = matches!(self.field.column_type, Some(ColumnType::List(_)));
+        // Sets of types that are not hashable are mapped to a vec
+        let is_vec = is_list || !self.element.is_hashable();
+
+        match self.kind {
+            CollectionOperationKind::Append if is_vec && !is_list => quote! {
+                for v in val {
+                    if !self.#ident.contains(&v) {
+                        self.#ident.push(v);
+                    }
+                }
+            },
+            CollectionOperationKind::Append => quote! {
+                self.#ident.extend(val);
+            },
+            CollectionOperationKind::Prepend => quote! {
+                self.#ident = val.into_iter().chain(self.#ident.drain(..)).collect();
+            },
+            CollectionOperationKind::RemoveFrom if is_vec => quote! {
+                self.#ident.retain(|v| !val.contains(v));
+            },
+            CollectionOperationKind::RemoveFrom => quote! {
+                for v in &val {
+                    self.#ident.remove(v);
+                }
+            },
+            CollectionOperationKind::Put => quote! {
+                self.#ident.insert(key, value);
+            },
+            CollectionOperationKind::RemoveKey => quote! {
+                self.#ident.remove(&key);
+            },
+        }
+    }
+}
+
+/// The owned type, the borrowed type and the method to go from the borrowed to the owned type,
+/// just like the borrowed fields of the Ref struct
+fn borrow(column_type: &ColumnType, hashable: bool) -> (TokenStream, TokenStream, TokenStream) {
+    let to_ty = |t: &ColumnType| -> TokenStream {
+        if hashable {
+            t.to_ty_hashable().parse().unwrap()
+        } else {
+            t.to_ty().parse().unwrap()
+        }
+    };
+    let ty = to_ty(column_type);
+
+    match column_type {
+        ColumnType::Text | ColumnType::Ascii | ColumnType::Varchar => {
+            (ty, quote! { str }, quote! { to_string() })
+        }
+        ColumnType::List(element) => {
+            let element_ty = to_ty(element);
+
+            (ty, quote! { [#element_ty] }, quote! { to_vec() })
+        }
+        _ => (ty.clone(), ty, quote! { clone() }),
+    }
+}
@@ -0,0 +1,129 @@
+use crate::entity_writer::EntityWriter;
+use crate::query_ident::{
+    decrement_field, increment_field, primary_key_struct_ref, qv, update_counters_constant,
+    update_counters_fn_name,
+};
+use crate::transformer::Transformer;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Writes the increment and decrement methods of the counter columns, these replace the update methods
+pub(crate) fn write<T: Transformer>(
+    entity_writer: &'_ EntityWriter<T>,
+    add_to_serialized_values: &[TokenStream],
+) -> (TokenStream, TokenStream) {
+    let primary_key_struct_ref = primary_key_struct_ref();
+    let table_name = &entity_writer.table.table_name;
+    let where_clause = entity_writer.create_where_clause();
+    let log_library = entity_writer.log_library();
+    let update_counter = entity_writer.update_counter();
+    let primary_key_len = entity_writer.struct_field_metadata.primary_key_fields.len();
+    let counter_fields = &entity_writer.struct_field_metadata.non_primary_key_fields;
+    let mut tokens_constants = TokenStream::new();
+    let mut tokens_type = TokenStream::new();
+
+    for field in counter_fields {
+        for (description, operator, (method_name, constant)) in [
+            ("increment", "+", increment_field(&field.ident)),
+            ("decrement", "-", decrement_field(&field.ident)),
+        ] {
+            let query = format!(
+                "update {} set {c} = {c} {} ? {}",
+                table_name,
+                operator,
+                where_clause,
+                c = field.ident
+            );
+            let method_name_qv = qv(&method_name);
+            let values_len = primary_key_len + 1;
+            let message_query = format!("The query to {} counter {}", description, field.ident);
+            let message_return = format!(
+                "Returns a struct that can perform an update operation to {} counter {}",
+                description, field.ident
+            );
+            let message_perform = format!(
+                "Performs an update operation to {} counter {}",
+                description, field.ident
+            );
+
+            tokens_constants.extend(quote! {
+                #[doc = #message_query]
+                pub const #constant: &str = #query;
+            });
+
+            tokens_type.extend(quote! {
+                impl #primary_key_struct_ref<'_> {
+                    #[doc = #message_return]
+                    pub fn #method_name_qv(&self, delta: i64) -> Result<#update_counter, Error> {
+                        let mut serialized_values = SerializedValues::with_capacity(#values_len);
+
+                        serialized_values.add_value(&delta)?;
+
+                        #(#add_to_serialized_values)*;
+
+                        Ok(#update_counter::new(Qv {
+                            query: #constant,
+                            values: serialized_values
+                        }))
+                    }
+
+                    #[doc = #message_perform]
+                    pub async fn #method_name(&self, session: &CachingSession, delta: i64) -> ScyllaQueryResult {
+                        #log_library::debug!("Updating table {} with delta {} for row {:#?}", #table_name, delta, self);
+
+                        self.#method_name_qv(delta)?.update(session).await
+                    }
+                }
+            });
+        }
+    }
+
+    let update_counters = update_counters_fn_name();
+    let update_counters_qv = qv(&update_counters);
+    let update_counters_constant = update_counters_constant();
+    let set_clause = counter_fields
+        .iter()
+        .map(|f| format!("{c} = {c} + ?", c = f.ident))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let query = format!("update {} set {} {}", table_name, set_clause, where_clause);
+    let idents = counter_fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let values_len = primary_key_len + idents.len();
+    let debug_value = if idents.len() == 1 {
+        quote! { #(#idents)* }
+    } else {
+        quote! { (#(#idents),*) }
+    };
+
+    tokens_constants.extend(quote! {
+        /// The query to add a delta to all counters at once
+        pub const #update_counters_constant: &str = #query;
+    });
+
+    tokens_type.extend(quote! {
+        impl #primary_key_struct_ref<'_> {
+            /// Returns a struct that can add a delta to every counter, a negative delta decrements the counter
+            pub fn #update_counters_qv(&self, #(#idents: i64),*) -> Result<#update_counter, Error> {
+                let mut serialized_values = SerializedValues::with_capacity(#values_len);
+
+                #(serialized_values.add_value(&#idents)?;)*
+
+                #(#add_to_serialized_values)*;
+
+                Ok(#update_counter::new(Qv {
+                    query: #update_counters_constant,
+                    values: serialized_values
+                }))
+            }
+
+            /// Adds a delta to every counter, a negative delta decrements the counter
+            pub async fn #update_counters(&self, session: &CachingSession, #(#idents: i64),*) -> ScyllaQueryResult {
+                #log_library::debug!("Updating counters of table {} with deltas {:#?} for row {:#?}", #table_name, #debug_value, self);
+
+                self.#update_counters_qv(#(#idents),*)?.update(session).await
+            }
+        }
+    });
+
+    (tokens_constants, tokens_type)
+}
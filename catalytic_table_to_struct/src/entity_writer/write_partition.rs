@@ -0,0 +1,442 @@
+use crate::entity_writer::EntityWriter;
+use crate::query_ident::{
+    all_in_memory, clustering_prefix, create_variant, delete_partition_constant,
+    delete_partition_fn_name, delete_range_fn_name, partition_key_struct,
+    partition_key_struct_parameter, partition_key_struct_ref, primary_key_struct,
+    primary_key_struct_parameter, primary_key_struct_ref, qv, scan_all_parallel_fn_name,
+    select_by_token_range_constant, select_by_token_range_fn_name, select_partition_constant,
+    select_partition_desc_constant, select_partition_fn_name, select_partition_range_fn_name,
+    to_ref, token_constant, token_fn_name,
+};
+use crate::transformer::Transformer;
+use catalytic::table_metadata::ColumnKind;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Writes the partition key structs with the queries on a whole partition, on a range of rows
+/// within a partition and on a range of tokens
+pub(crate) fn write<T: Transformer>(
+    entity_writer: &'_ EntityWriter<T>,
+) -> (TokenStream, TokenStream) {
+    let partition_key_fields = entity_writer.primary_key_fields_of_kind(ColumnKind::PartitionKey);
+    let clustering_fields = entity_writer.primary_key_fields_of_kind(ColumnKind::Clustering);
+    let table_name = &entity_writer.table.table_name;
+    let struct_ident = entity_writer.struct_ident();
+    let log_library = entity_writer.log_library();
+    let select_multiple = entity_writer.select_multiple();
+    let select_unique = entity_writer.select_unique();
+    let column_names = entity_writer.comma_separated_column_names();
+    let partition_key_struct = partition_key_struct();
+    let partition_key_struct_ref = partition_key_struct_ref();
+    let partition_key_struct_parameter = partition_key_struct_parameter();
+    let partition_key_idents = partition_key_fields
+        .iter()
+        .map(|f| &f.ident)
+        .collect::<Vec<_>>();
+    let partition_key_names = partition_key_idents
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let partition_where_clause = format!(
+        "where {}",
+        partition_key_idents
+            .iter()
+            .map(|i| format!("{} = ?", i))
+            .collect::<Vec<_>>()
+            .join(" and ")
+    );
+    let partition_key_len = partition_key_fields.len();
+    let serialize = quote! {
+        let mut serialized_values = SerializedValues::with_capacity(#partition_key_len);
+
+        #(serialized_values.add_value(&self.#partition_key_idents)?;)*
+    };
+
+    let token_constant = token_constant();
+    let token_fn_name = token_fn_name();
+    let token_fn_name_qv = qv(&token_fn_name);
+    let token_query = format!(
+        "select token({}) from {} {} limit 1",
+        partition_key_names, table_name, partition_where_clause
+    );
+    let select_by_token_range_constant = select_by_token_range_constant();
+    let select_by_token_range_fn_name = select_by_token_range_fn_name();
+    let select_by_token_range_fn_name_qv = qv(&select_by_token_range_fn_name);
+    let select_by_token_range_in_memory = all_in_memory(&select_by_token_range_fn_name);
+    let scan_all_parallel_fn_name = scan_all_parallel_fn_name();
+    let select_by_token_range_query = format!(
+        "select {} from {} where token({p}) > ? and token({p}) <= ?",
+        column_names,
+        table_name,
+        p = partition_key_names
+    );
+
+    let mut tokens_constants = quote! {
+        /// The query to select the token of a partition
+        pub const #token_constant: &str = #token_query;
+        /// The query to select the rows of which the token of the partition key is within a range
+        pub const #select_by_token_range_constant: &str = #select_by_token_range_query;
+    };
+
+    let mut tokens_type = if clustering_fields.is_empty() {
+        let primary_key_struct = primary_key_struct();
+        let primary_key_struct_ref = primary_key_struct_ref();
+        let primary_key_struct_parameter = primary_key_struct_parameter();
+
+        quote! {
+            /// The partition key equals the primary key, since the table has no clustering columns
+            pub type #partition_key_struct = #primary_key_struct;
+
+            /// The borrowed partition key equals the borrowed primary key
+            pub type #partition_key_struct_ref<'a> = #primary_key_struct_ref<'a>;
+
+            impl #struct_ident {
+                /// Create a borrowed partition key from the struct values
+                pub fn #partition_key_struct_parameter(&self) -> #partition_key_struct_ref {
+                    self.#primary_key_struct_parameter()
+                }
+            }
+        }
+    } else {
+        write_partition_key(entity_writer)
+    };
+
+    tokens_type.extend(quote! {
+        impl #partition_key_struct_ref<'_> {
+            /// Returns a struct that can perform a selection of the token of the partition
+            pub fn #token_fn_name_qv(&self) -> Result<#select_unique<(TokenType,)>, Error> {
+                #serialize
+
+                Ok(#select_unique::new(Qv {
+                    query: #token_constant,
+                    values: serialized_values
+                }))
+            }
+
+            /// Selects the token of the partition, which determines the nodes that store the partition
+            /// None is returned if the partition has no rows
+            pub async fn #token_fn_name(&self, session: &CachingSession) -> Result<Option<TokenType>, Error> {
+                #log_library::debug!("Selecting token of partition of table {} with values {:#?}", #table_name, self);
+
+                Ok(self.#token_fn_name_qv()?.select(session).await?.entity.map(|(token,)| token))
+            }
+        }
+
+        /// Returns a struct that can perform a selection of the rows of which the token of the
+        /// partition key is within the range, the start is exclusive and the end is inclusive
+        pub fn #select_by_token_range_fn_name_qv(start: TokenType, end: TokenType) -> Result<#select_multiple<#struct_ident, &'static str, SerializedValues>, Error> {
+            let mut serialized_values = SerializedValues::with_capacity(2);
+
+            serialized_values.add_value(&start)?;
+            serialized_values.add_value(&end)?;
+
+            Ok(#select_multiple::new(Qv {
+                query: #select_by_token_range_constant,
+                values: serialized_values
+            }))
+        }
+
+        /// Selects the rows of which the token of the partition key is within the range, with a specified page size
+        pub async fn #select_by_token_range_fn_name(session: &CachingSession, start: TokenType, end: TokenType, page_size: Option<i32>) -> Result<TypedRowIterator<#struct_ident>, Error> {
+            #select_by_token_range_fn_name_qv(start, end)?.select(session, page_size).await
+        }
+
+        /// Selects the rows of which the token of the partition key is within the range and accumulates them in memory
+        pub async fn #select_by_token_range_in_memory(session: &CachingSession, start: TokenType, end: TokenType, page_size: i32) -> Result<QueryEntityVec<#struct_ident>, Error> {
+            #select_by_token_range_fn_name_qv(start, end)?.select_all_in_memory(session, page_size).await
+        }
+
+        /// Scans the whole table with a query per split of the token ring, of which at most 'concurrency'
+        /// are executed at the same time. The rows are returned in the order in which they arrive
+        pub fn #scan_all_parallel_fn_name(session: &CachingSession, splits: usize, concurrency: usize) -> Result<impl catalytic::query_transform::Stream<Item = Result<#struct_ident, Error>> + '_, Error> {
+            #log_library::debug!("Scanning table {} with {} splits", #table_name, splits);
+
+            #select_multiple::<#struct_ident, _, _>::new(Qv {
+                query: #select_by_token_range_constant,
+                values: SerializedValues::new()
+            }).scan_parallel(session, splits, concurrency)
+        }
+    });
+
+    // Only tables with clustering columns have multiple rows in a partition
+    if clustering_fields.is_empty() {
+        return (tokens_constants, tokens_type);
+    }
+
+    // Only the first clustering column is needed to order the rows of the partition
+    let order_by_desc = format!(" order by {} desc", clustering_fields[0].ident);
+    let clustering_prefix = clustering_prefix();
+    let mut prefix_variants = vec![];
+    let mut prefix_columns = vec![];
+    let mut prefix_add_values = vec![];
+
+    for (index, field) in clustering_fields.iter().enumerate() {
+        let prefix = &clustering_fields[..=index];
+        let variant = create_variant(&field.ident);
+        let types = prefix.iter().map(|f| &f.borrow_ty);
+        let idents = prefix.iter().map(|f| &f.ident).collect::<Vec<_>>();
+        let names = idents.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+        let doc = format!("Restricts the columns: {}", names.join(", "));
+
+        prefix_variants.push(quote! {
+            #[doc = #doc]
+            #variant(#(&'a #types),*)
+        });
+        prefix_columns.push(quote! {
+            #clustering_prefix::#variant(..) => &[#(#names),*]
+        });
+        prefix_add_values.push(quote! {
+            #clustering_prefix::#variant(#(#idents),*) => {
+                #(values.add_value(#idents)?;)*
+            }
+        });
+    }
+
+    let select_partition_constant = select_partition_constant();
+    let select_partition_desc_constant = select_partition_desc_constant();
+    let select_partition_fn_name = select_partition_fn_name();
+    let select_partition_range_fn_name = select_partition_range_fn_name();
+    let select_partition_query = format!(
+        "select {} from {} {}",
+        column_names, table_name, partition_where_clause
+    );
+    let select_partition_desc_query = format!("{}{}", select_partition_query, order_by_desc);
+
+    tokens_constants.extend(quote! {
+        /// The query to select all rows in a partition
+        pub const #select_partition_constant: &str = #select_partition_query;
+        /// The query to select all rows in a partition, ordered descending by the first clustering column
+        pub const #select_partition_desc_constant: &str = #select_partition_desc_query;
+    });
+
+    tokens_type.extend(quote! {
+        /// A prefix of the clustering columns, used as a bound of a range within a partition
+        /// The variant is named after the last column of the prefix
+        #[derive(Copy, Clone, Debug)]
+        pub enum #clustering_prefix<'a> {
+            #(#prefix_variants),*
+        }
+
+        impl catalytic::clustering_range::ClusteringColumns for #clustering_prefix<'_> {
+            fn columns(&self) -> &'static [&'static str] {
+                match self {
+                    #(#prefix_columns),*
+                }
+            }
+
+            fn add_values(&self, values: &mut SerializedValues) -> Result<(), SerializeValuesError> {
+                match self {
+                    #(#prefix_add_values)*
+                }
+
+                Ok(())
+            }
+        }
+
+        impl #partition_key_struct_ref<'_> {
+            /// Returns a struct that can perform a selection of all rows in the partition
+            /// If descending is true, the rows are ordered descending by the first clustering column
+            pub fn #select_partition_fn_name(&self, descending: bool) -> Result<#select_multiple<#struct_ident>, Error> {
+                #log_library::debug!("Selecting partition of table {} with values {:#?}", #table_name, self);
+
+                #serialize
+
+                Ok(#select_multiple::new(Qv {
+                    query: if descending {
+                        #select_partition_desc_constant
+                    } else {
+                        #select_partition_constant
+                    },
+                    values: serialized_values
+                }))
+            }
+
+            /// Returns a struct that can perform a selection of the rows in the partition within a range of the clustering columns
+            /// Both bounds can restrict a different prefix of the clustering columns, e.g.
+            /// ClusteringPrefix::A(&a)..=ClusteringPrefix::B(&a, &b)
+            /// If descending is true, the rows are ordered descending by the first clustering column
+            pub fn #select_partition_range_fn_name<'b>(
+                &self,
+                range: impl std::ops::RangeBounds<#clustering_prefix<'b>>,
+                descending: bool,
+            ) -> Result<#select_multiple<#struct_ident, String>, Error> {
+                #log_library::debug!("Selecting range of partition of table {} with values {:#?}", #table_name, self);
+
+                #serialize
+
+                let mut query = catalytic::clustering_range::append_range(#select_partition_constant, &range, &mut serialized_values)?;
+
+                if descending {
+                    query.push_str(#order_by_desc);
+                }
+
+                Ok(#select_multiple::new(Qv {
+                    query,
+                    values: serialized_values
+                }))
+            }
+        }
+    });
+
+    // Rows can not be deleted from a materialized view
+    if entity_writer.table.materialized_view.is_none() {
+        let delete_partition_constant = delete_partition_constant();
+        let delete_partition_fn_name = delete_partition_fn_name();
+        let delete_range_fn_name = delete_range_fn_name();
+        let delete_multiple = entity_writer.delete_multiple();
+        let delete_partition_query =
+            format!("delete from {} {}", table_name, partition_where_clause);
+
+        tokens_constants.extend(quote! {
+            /// The query to delete all rows in a partition
+            pub const #delete_partition_constant: &str = #delete_partition_query;
+        });
+
+        tokens_type.extend(quote! {
+            impl #partition_key_struct_ref<'_> {
+                /// Returns a struct that can perform a deletion of all rows in the partition
+                pub fn #delete_partition_fn_name(&self) -> Result<#delete_multiple, Error> {
+                    #log_library::debug!("Deleting partition of table {} with values {:#?}", #table_name, self);
+
+                    #serialize
+
+                    Ok(#delete_multiple::new(Qv {
+                        query: #delete_partition_constant,
+                        values: serialized_values
+                    }))
+                }
+
+                /// Returns a struct that can perform a deletion of the rows in the partition within a range of the clustering columns
+                pub fn #delete_range_fn_name<'b>(
+                    &self,
+                    range: impl std::ops::RangeBounds<#clustering_prefix<'b>>,
+                ) -> Result<#delete_multiple<String>, Error> {
+                    #log_library::debug!("Deleting range of partition of table {} with values {:#?}", #table_name, self);
+
+                    #serialize
+
+                    let query = catalytic::clustering_range::append_range(#delete_partition_constant, &range, &mut serialized_values)?;
+
+                    Ok(#delete_multiple::new(Qv {
+                        query,
+                        values: serialized_values
+                    }))
+                }
+            }
+        });
+    }
+
+    (tokens_constants, tokens_type)
+}
+
+/// Writes the partition key structs, which are distinct from the primary key structs
+/// because the table has clustering columns
+fn write_partition_key<T: Transformer>(entity_writer: &'_ EntityWriter<T>) -> TokenStream {
+    let struct_ident = entity_writer.struct_ident();
+    let partition_key_struct = partition_key_struct();
+    let partition_key_struct_ref = partition_key_struct_ref();
+    let partition_key_struct_parameter = partition_key_struct_parameter();
+    let primary_key_struct = primary_key_struct();
+    let primary_key_struct_ref = primary_key_struct_ref();
+    let to_ref = to_ref();
+    let partition_key_metadata = entity_writer
+        .transformer
+        .partition_struct_metadata(entity_writer.struct_table())
+        .into_tokenstream();
+    let partition_key_ref_metadata = entity_writer
+        .transformer
+        .partition_struct_ref_metadata(entity_writer.struct_table())
+        .into_tokenstream();
+    let partition_key_fields = entity_writer.primary_key_fields_of_kind(ColumnKind::PartitionKey);
+    let idents = partition_key_fields
+        .iter()
+        .map(|f| &f.ident)
+        .collect::<Vec<_>>();
+    let fields = partition_key_fields.iter().map(|f| {
+        let ident = &f.ident;
+        let ty = &f.ty;
+
+        quote! { pub #ident: #ty, }
+    });
+    let ref_fields = partition_key_fields.iter().map(|f| {
+        let ident = &f.ident;
+        let ty = &f.borrow_ty;
+
+        quote! { pub #ident: &'a #ty, }
+    });
+    let from_ref = partition_key_fields.iter().map(|f| {
+        let ident = &f.ident;
+        let from_ref = &f.from_borrow_to_owned;
+
+        quote! { #ident: f.#ident.#from_ref, }
+    });
+
+    quote! {
+        /// The owned partition key struct
+        /// A partition contains all the rows with the same partition key
+        #partition_key_metadata
+        pub struct #partition_key_struct {
+            #(#fields)*
+        }
+
+        /// The borrowed partition key struct
+        /// This struct can be used to perform reads and deletes of a whole partition or of a range within the partition
+        #partition_key_ref_metadata
+        pub struct #partition_key_struct_ref<'a> {
+            #(#ref_fields)*
+        }
+
+        /// Conversation method to go from a borrowed partition key to an owned partition key
+        impl #partition_key_struct_ref<'_> {
+            pub fn into_owned(self) -> #partition_key_struct {
+                self.into()
+            }
+        }
+
+        /// Conversation method to go from an owned partition key to a borrowed partition key
+        impl #partition_key_struct {
+            pub fn #to_ref(&self) -> #partition_key_struct_ref<'_> {
+                #partition_key_struct_ref {
+                    #(#idents: &self.#idents,)*
+                }
+            }
+        }
+
+        /// Conversation method to go from a borrowed partition key to an owned partition key
+        impl From<#partition_key_struct_ref<'_>> for #partition_key_struct {
+            fn from(f: #partition_key_struct_ref<'_>) -> #partition_key_struct {
+                #partition_key_struct {
+                    #(#from_ref)*
+                }
+            }
+        }
+
+        impl #struct_ident {
+            /// Create a borrowed partition key from the struct values
+            pub fn #partition_key_struct_parameter(&self) -> #partition_key_struct_ref {
+                #partition_key_struct_ref {
+                    #(#idents: &self.#idents,)*
+                }
+            }
+        }
+
+        impl #primary_key_struct {
+            /// Create a borrowed partition key from the primary key
+            pub fn #partition_key_struct_parameter(&self) -> #partition_key_struct_ref {
+                #partition_key_struct_ref {
+                    #(#idents: &self.#idents,)*
+                }
+            }
+        }
+
+        impl<'a> #primary_key_struct_ref<'a> {
+            /// Create a borrowed partition key from the primary key
+            pub fn #partition_key_struct_parameter(&self) -> #partition_key_struct_ref<'a> {
+                #partition_key_struct_ref {
+                    #(#idents: self.#idents,)*
+                }
+            }
+        }
+    }
+}
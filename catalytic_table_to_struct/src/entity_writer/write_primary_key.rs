@@ -1,10 +1,14 @@
-use crate::entity_writer::EntityWriter;
+use crate::entity_writer::collection_operation::collection_operations;
+use crate::entity_writer::{write_counter, EntityWriter};
 use crate::query_ident::create_variant;
 use crate::query_ident::{
-    base_table, base_table_query, delete_constant, delete_fn_name, primary_key_struct,
+    base_table, base_table_query, delete_constant, delete_fn_name, delete_if_exists_constant,
+    delete_if_exists_fn_name, delete_with_constant, delete_with_fn_name, primary_key_struct,
     primary_key_struct_ref, qv, select_unique_constant, select_unique_expect_fn_name,
-    select_unique_fn_name, to_ref, updatable_column_ref, update_dyn, update_dyn_multiple,
-    update_field,
+    select_unique_fn_name, select_unique_with_writetime_constant,
+    select_unique_with_writetime_expect_fn_name, select_unique_with_writetime_fn_name, to_ref,
+    updatable_column_ref, update_dyn, update_dyn_multiple, update_field, update_field_if,
+    update_field_with, with_writetime,
 };
 use crate::transformer::Transformer;
 use proc_macro2::{Ident, TokenStream};
@@ -145,6 +149,53 @@ pub(crate) fn write<T: Transformer>(
         &select_unique_constant,
     ));
 
+    let writetime_fields = entity_writer.writetime_fields();
+
+    if !writetime_fields.is_empty() {
+        let select_unique_with_writetime_constant = select_unique_with_writetime_constant();
+        let with_writetime = with_writetime();
+        let writetime_columns = writetime_fields
+            .iter()
+            .map(|f| format!("writetime({})", f.ident))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let select_unique_with_writetime_query = format!(
+            "select {}, {} from {} {}",
+            column_names, writetime_columns, table_name, where_clause
+        );
+        let writetimes = writetime_fields
+            .iter()
+            .map(|_| quote! { Option<TimestampType>, })
+            .collect::<Vec<_>>();
+        let doc = format!(
+            "The row together with the writetime of the columns: {}",
+            writetime_fields
+                .iter()
+                .map(|f| f.ident.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        tokens_constants.extend(quote! {
+            /// The query to retrieve a unique row in this table, including the writetime of the columns
+            pub const #select_unique_with_writetime_constant: &str = #select_unique_with_writetime_query;
+
+            #[doc = #doc]
+            pub type #with_writetime = WithMetadata<#struct_ident, (#(#writetimes)*)>;
+        });
+
+        tokens_type.extend(create_select_unique(
+            entity_writer,
+            &log_library,
+            &serialize,
+            &primary_key_struct_ref,
+            &select_unique_with_writetime_fn_name(),
+            &select_unique_with_writetime_expect_fn_name(),
+            &with_writetime,
+            &select_unique_with_writetime_constant,
+        ));
+    }
+
     match &entity_writer.table.materialized_view {
         Some(mv) => {
             if mv.same_columns {
@@ -174,12 +225,19 @@ pub(crate) fn write<T: Transformer>(
             }
         }
         None => {
-            if !entity_writer
+            if entity_writer.is_counter_table() {
+                let (counter_constants, counter_type) =
+                    write_counter::write(entity_writer, &add_to_serialized_values);
+
+                tokens_constants.extend(counter_constants);
+                tokens_type.extend(counter_type);
+            } else if !entity_writer
                 .struct_field_metadata
                 .non_primary_key_fields
                 .is_empty()
             {
                 let update = entity_writer.update();
+                let conditional_mutation = entity_writer.conditional_mutation();
 
                 // Write the update methods
                 for field in &entity_writer.struct_field_metadata.non_primary_key_fields {
@@ -207,7 +265,7 @@ pub(crate) fn write<T: Transformer>(
                     tokens_type.extend(quote! {
                     impl #primary_key_struct_ref<'_> {
                         #[doc = #message_return]
-                        pub fn #method_name_qv(&self, val: &#ty) -> Result<Update, SerializeValuesError> {
+                        pub fn #method_name_qv(&self, val: &#ty) -> Result<Update, Error> {
                             let mut serialized_values = SerializedValues::with_capacity(#single_update_len);
 
                             serialized_values.add_value(&val)?;
@@ -233,13 +291,201 @@ pub(crate) fn write<T: Transformer>(
                         }
                     }
                 });
+
+                    // Write the compare-and-set method
+                    let (method_name, constant) = update_field_if(&field.ident);
+                    let method_name_qv = qv(&method_name);
+                    let update_if_query = format!(
+                        "update {} set {c} = ? {} if {c} = ?",
+                        table_name,
+                        where_clause,
+                        c = field.ident
+                    );
+                    let update_if_len = primary_key_len + 2;
+                    let message_return = format!(
+                        "Returns a struct that can perform an update operation for column {}, which is only applied if the column has the expected value",
+                        field.ident
+                    );
+                    let message_perform = format!(
+                        "Performs an update operation for column {} if the column has the expected value, else the existing columns are returned",
+                        field.ident
+                    );
+                    let message_query = format!(
+                        "The query to update column {} if it has the expected value",
+                        field.ident
+                    );
+
+                    tokens_constants.extend(quote! {
+                        #[doc = #message_query]
+                        pub const #constant: &str = #update_if_query;
+                    });
+
+                    tokens_type.extend(quote! {
+                        impl #primary_key_struct_ref<'_> {
+                            #[doc = #message_return]
+                            pub fn #method_name_qv(&self, val: &#ty, expected: &#ty) -> Result<#conditional_mutation, Error> {
+                                let mut serialized_values = SerializedValues::with_capacity(#update_if_len);
+
+                                serialized_values.add_value(&val)?;
+
+                                #(#add_to_serialized_values)*;
+
+                                serialized_values.add_value(&expected)?;
+
+                                Ok(#conditional_mutation::new(Qv {
+                                    query: #constant,
+                                    values: serialized_values
+                                }))
+                            }
+
+                            #[doc = #message_perform]
+                            pub async fn #method_name(
+                                &self,
+                                session: &CachingSession,
+                                val: &#ty,
+                                expected: &#ty,
+                            ) -> Result<QueryResultConditional, Error> {
+                                #log_library::debug!("Updating table {} with val {:#?} if the value is {:#?} for row {:#?}", #table_name, val, expected, self);
+
+                                self.#method_name_qv(val, expected)?.execute(session).await
+                            }
+                        }
+                    });
+
+                    // Write the method with a write timestamp
+                    let (method_name, constant) = update_field_with(&field.ident);
+                    let method_name_qv = qv(&method_name);
+                    let update_with_query = format!(
+                        "update {} using timestamp ? set {} = ? {}",
+                        table_name, field.ident, where_clause
+                    );
+                    let update_with_len = primary_key_len + 2;
+                    let message_return = format!(
+                        "Returns a struct that can perform an update operation for column {} with a write timestamp",
+                        field.ident
+                    );
+                    let message_perform = format!(
+                        "Performs an update operation for column {} with a write timestamp",
+                        field.ident
+                    );
+                    let message_query = format!(
+                        "The query to update column {} with a write timestamp",
+                        field.ident
+                    );
+
+                    tokens_constants.extend(quote! {
+                        #[doc = #message_query]
+                        pub const #constant: &str = #update_with_query;
+                    });
+
+                    tokens_type.extend(quote! {
+                        impl #primary_key_struct_ref<'_> {
+                            #[doc = #message_return]
+                            pub fn #method_name_qv(&self, val: &#ty, timestamp: TimestampType) -> Result<Update, Error> {
+                                let mut serialized_values = SerializedValues::with_capacity(#update_with_len);
+
+                                serialized_values.add_value(&timestamp)?;
+                                serialized_values.add_value(&val)?;
+
+                                #(#add_to_serialized_values)*;
+
+                                Ok(#update::new(Qv {
+                                    query: #constant,
+                                    values: serialized_values
+                                }))
+                            }
+
+                            #[doc = #message_perform]
+                            pub async fn #method_name(
+                                &self,
+                                session: &CachingSession,
+                                val: &#ty,
+                                timestamp: TimestampType,
+                            ) -> ScyllaQueryResult {
+                                #log_library::debug!("Updating table {} with val {:#?} and timestamp {} for row {:#?}", #table_name, val, timestamp, self);
+
+                                self.#method_name_qv(val, timestamp)?.update(session).await
+                            }
+                        }
+                    });
+
+                    // Write the methods that update a part of a collection
+                    for operation in collection_operations(field) {
+                        let method_name = operation.fn_name();
+                        let method_name_qv = qv(&method_name);
+                        let constant = operation.constant();
+                        let update_query = format!(
+                            "update {} set {} {}",
+                            table_name,
+                            operation.set_clause(),
+                            where_clause
+                        );
+                        let parameters = operation.parameters();
+                        let values_len = primary_key_len + parameters.len();
+                        let idents = parameters.iter().map(|p| &p.ident).collect::<Vec<_>>();
+                        let borrow_tys = parameters.iter().map(|p| &p.borrow_ty);
+                        let arguments = quote! {
+                            #(#idents: &#borrow_tys),*
+                        };
+                        let serialize_parameters = operation.serialize();
+                        let debug_value = if idents.len() == 1 {
+                            quote! { #(#idents)* }
+                        } else {
+                            quote! { (#(#idents),*) }
+                        };
+                        let message_query = format!(
+                            "The query to {} column {}",
+                            operation.kind.description(),
+                            field.ident
+                        );
+                        let message_return = format!(
+                            "Returns a struct that can perform an update operation to {} column {}",
+                            operation.kind.description(),
+                            field.ident
+                        );
+                        let message_perform = format!(
+                            "Performs an update operation to {} column {}",
+                            operation.kind.description(),
+                            field.ident
+                        );
+
+                        tokens_constants.extend(quote! {
+                            #[doc = #message_query]
+                            pub const #constant: &str = #update_query;
+                        });
+
+                        tokens_type.extend(quote! {
+                            impl #primary_key_struct_ref<'_> {
+                                #[doc = #message_return]
+                                pub fn #method_name_qv(&self, #arguments) -> Result<Update, Error> {
+                                    let mut serialized_values = SerializedValues::with_capacity(#values_len);
+
+                                    #serialize_parameters
+
+                                    #(#add_to_serialized_values)*;
+
+                                    Ok(#update::new(Qv {
+                                        query: #constant,
+                                        values: serialized_values
+                                    }))
+                                }
+
+                                #[doc = #message_perform]
+                                pub async fn #method_name(&self, session: &CachingSession, #arguments) -> ScyllaQueryResult {
+                                    #log_library::debug!("Updating table {} with {:#?} for row {:#?}", #table_name, #debug_value, self);
+
+                                    self.#method_name_qv(#(#idents),*)?.update(session).await
+                                }
+                            }
+                        });
+                    }
                 }
 
                 let update_dyn = update_dyn();
                 let update_dyn_qv = qv(&update_dyn);
                 let updatable_column_ref = updatable_column_ref();
-                let mut variants = vec![];
-                let mut method_names_qv = vec![];
+                let mut update_dyn_arms = vec![];
+                let mut update_dyn_multiple_arms = vec![];
 
                 for f in entity_writer
                     .struct_field_metadata
@@ -248,19 +494,48 @@ pub(crate) fn write<T: Transformer>(
                 {
                     let v = create_variant(&f.ident);
                     let (method_name, _) = update_field(&f.ident);
+                    let method_name_qv = qv(&method_name);
+                    let column = &f.ident;
 
-                    variants.push(quote! {
-                        #v
+                    update_dyn_arms.push(quote! {
+                        #updatable_column_ref::#v(val) => self.#method_name_qv(val)
                     });
-                    method_names_qv.push(qv(&method_name));
+                    update_dyn_multiple_arms.push(quote! {
+                        #updatable_column_ref::#v(v) => {
+                            query.push(concat!(stringify!(#column), " = ?"));
+                            serialized_values.add_value(v)?;
+                        }
+                    });
+
+                    for operation in collection_operations(f) {
+                        let v = operation.variant();
+                        let method_name_qv = qv(&operation.fn_name());
+                        let set_clause = operation.set_clause();
+                        let serialize_parameters = operation.serialize();
+                        let idents = operation
+                            .parameters()
+                            .into_iter()
+                            .map(|p| p.ident)
+                            .collect::<Vec<_>>();
+
+                        update_dyn_arms.push(quote! {
+                            #updatable_column_ref::#v(#(#idents),*) => self.#method_name_qv(#(#idents),*)
+                        });
+                        update_dyn_multiple_arms.push(quote! {
+                            #updatable_column_ref::#v(#(#idents),*) => {
+                                query.push(#set_clause);
+                                #serialize_parameters
+                            }
+                        });
+                    }
                 }
 
                 tokens_type.extend(quote! {
                 impl #primary_key_struct_ref<'_> {
                     /// Returns a struct that can perform an update on a dynamic updatable column
-                    pub fn #update_dyn_qv(&self, val: #updatable_column_ref<'_>) -> Result<Update, SerializeValuesError> {
+                    pub fn #update_dyn_qv(&self, val: #updatable_column_ref<'_>) -> Result<Update, Error> {
                         match val {
-                            #(#updatable_column_ref::#variants(val) => self.#method_names_qv(val)),*
+                            #(#update_dyn_arms),*
                         }
                     }
 
@@ -273,17 +548,10 @@ pub(crate) fn write<T: Transformer>(
 
                 let update_dyn_multiple = update_dyn_multiple();
                 let update_dyn_multiple_qv = qv(&update_dyn_multiple);
-                let update_column = entity_writer
-                    .struct_field_metadata
-                    .non_primary_key_fields
-                    .iter()
-                    .map(|f| &f.ident)
-                    .collect::<Vec<_>>();
-
                 tokens_type.extend(quote! {
                 impl #primary_key_struct_ref<'_> {
                     /// Returns a struct that can perform a dynamic amount of column updates
-                    pub fn #update_dyn_multiple_qv(&self, val: &[#updatable_column_ref<'_>]) -> Result<Update<String, SerializedValues>, SerializeValuesError> {
+                    pub fn #update_dyn_multiple_qv(&self, val: &[#updatable_column_ref<'_>]) -> Result<Update<String, SerializedValues>, Error> {
                          if val.is_empty() {
                             panic!("Empty update array")
                         }
@@ -293,10 +561,7 @@ pub(crate) fn write<T: Transformer>(
 
                         for v in val {
                             match v {
-                                #(#updatable_column_ref::#variants(v) => {
-                                    query.push(concat!(stringify!(#update_column), " = ?"));
-                                    serialized_values.add_value(v)?;
-                                }),*
+                                #(#update_dyn_multiple_arms),*
                             }
                         }
 
@@ -339,7 +604,7 @@ pub(crate) fn write<T: Transformer>(
             tokens_type.extend(quote! {
                 impl #primary_key_struct_ref<'_> {
                     /// Returns a struct that can perform a single row deletion
-                    pub fn #delete_fn_name_qv(&self) -> Result<DeleteUnique, SerializeValuesError> {
+                    pub fn #delete_fn_name_qv(&self) -> Result<DeleteUnique, Error> {
                         #serialize
 
                             Ok(#delete_unique::new(
@@ -357,7 +622,85 @@ pub(crate) fn write<T: Transformer>(
                         self.#delete_fn_name_qv()?.delete_unique(session).await
                     }
                 }
-            })
+            });
+
+            // Lightweight transactions and write timestamps are not supported on counter tables
+            if !entity_writer.is_counter_table() {
+                let delete_if_exists_fn_name = delete_if_exists_fn_name();
+                let delete_if_exists_fn_name_qv = qv(&delete_if_exists_fn_name);
+                let delete_if_exists_constant = delete_if_exists_constant();
+                let delete_if_exists_query = format!("{} if exists", delete_query);
+                let conditional_mutation = entity_writer.conditional_mutation();
+
+                tokens_constants.extend(quote! {
+                    /// The query to delete a unique row in the table if it exists
+                    pub const #delete_if_exists_constant: &str = #delete_if_exists_query;
+                });
+
+                tokens_type.extend(quote! {
+                    impl #primary_key_struct_ref<'_> {
+                        /// Returns a struct that can perform a single row deletion, which is only applied if the row exists
+                        pub fn #delete_if_exists_fn_name_qv(&self) -> Result<#conditional_mutation, Error> {
+                            #serialize
+
+                            Ok(#conditional_mutation::new(
+                                Qv {
+                                    query: #delete_if_exists_constant,
+                                    values: serialized_values
+                                }
+                            ))
+                        }
+
+                        /// Performs a single row deletion if the row exists, the result tells if the row was deleted
+                        pub async fn #delete_if_exists_fn_name(&self, session: &CachingSession) -> Result<QueryResultConditional, Error> {
+                            #log_library::debug!("Deleting a row if it exists from table {} with values {:#?}", #table_name, self);
+
+                            self.#delete_if_exists_fn_name_qv()?.execute(session).await
+                        }
+                    }
+                });
+
+                let delete_with_fn_name = delete_with_fn_name();
+                let delete_with_fn_name_qv = qv(&delete_with_fn_name);
+                let delete_with_constant = delete_with_constant();
+                let delete_with_query = format!(
+                    "delete from {} using timestamp ? {}",
+                    table_name, where_clause
+                );
+                let delete_with_len = primary_key_len + 1;
+
+                tokens_constants.extend(quote! {
+                    /// The query to delete a unique row in the table with a write timestamp
+                    pub const #delete_with_constant: &str = #delete_with_query;
+                });
+
+                tokens_type.extend(quote! {
+                    impl #primary_key_struct_ref<'_> {
+                        /// Returns a struct that can perform a single row deletion with a write timestamp
+                        pub fn #delete_with_fn_name_qv(&self, timestamp: TimestampType) -> Result<DeleteUnique, Error> {
+                            let mut serialized_values = SerializedValues::with_capacity(#delete_with_len);
+
+                            serialized_values.add_value(&timestamp)?;
+
+                            #(#add_to_serialized_values)*;
+
+                            Ok(#delete_unique::new(
+                                Qv {
+                                    query: #delete_with_constant,
+                                    values: serialized_values
+                                }
+                            ))
+                        }
+
+                        /// Performs a single row deletion with a write timestamp, data written with a later timestamp is kept
+                        pub async fn #delete_with_fn_name(&self, session: &CachingSession, timestamp: TimestampType) -> ScyllaQueryResult {
+                            #log_library::debug!("Deleting a row with timestamp {} from table {} with values {:#?}", timestamp, #table_name, self);
+
+                            self.#delete_with_fn_name_qv(timestamp)?.delete_unique(session).await
+                        }
+                    }
+                });
+            }
         }
     }
 
@@ -383,7 +726,7 @@ fn create_select_unique<T: Transformer>(
         quote! {
             impl #primary_key_struct<'_> {
                 /// Returns a struct that can perform a unique row selection
-                pub fn #fn_name_qv(&self) -> Result<#transformer<#struct_ident>, SerializeValuesError> {
+                pub fn #fn_name_qv(&self) -> Result<#transformer<#struct_ident>, Error> {
                     #serialize
 
                     Ok(#transformer::new(
@@ -394,7 +737,7 @@ fn create_select_unique<T: Transformer>(
                 }
 
                 /// Performs the unique row selection
-                pub async fn #fn_name(&self, session: &CachingSession) -> Result<#return_type<#struct_ident>, SingleSelectQueryErrorTransform> {
+                pub async fn #fn_name(&self, session: &CachingSession) -> Result<#return_type<#struct_ident>, Error> {
                     #log_library::debug!("Selecting unique row for table {} with values: {:#?}", #table, self);
 
                     self.#fn_name_qv()?.select(session).await
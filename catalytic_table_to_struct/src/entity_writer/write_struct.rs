@@ -1,13 +1,16 @@
+use crate::entity_writer::collection_operation::collection_operations;
 use crate::entity_writer::EntityWriter;
 use crate::query_ident::{
     all_in_memory, base_table, base_table_query, create_variant, delete_fn_name, in_memory_update,
-    in_memory_updates, insert_constant, insert_fn_name, insert_or_delete_fn_name,
-    insert_ttl_constant, insert_ttl_fn_name, primary_key_owned, primary_key_struct,
-    primary_key_struct_parameter, primary_key_struct_ref, qv, select_all_constant,
-    select_all_count_constant, select_all_count_fn_name, select_all_fn_name, struct_ref, to_ref,
-    truncate_constant, truncate_fn_name, updatable_column,
+    in_memory_updates, insert_constant, insert_fn_name, insert_if_not_exists_constant,
+    insert_if_not_exists_fn_name, insert_or_delete_fn_name, insert_ttl_constant,
+    insert_ttl_fn_name, insert_with_constant, insert_with_fn_name, primary_key_owned,
+    primary_key_struct, primary_key_struct_parameter, primary_key_struct_ref, qv,
+    select_all_constant, select_all_count_constant, select_all_count_fn_name, select_all_fn_name,
+    select_by_field, struct_ref, to_ref, truncate_constant, truncate_fn_name, updatable_column,
 };
 use crate::transformer::Transformer;
+use catalytic::secondary_index::indexed_columns;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
@@ -96,7 +99,7 @@ pub(crate) fn write<T: Transformer>(
         }
 
         /// Performs the count query
-        pub async fn #select_all_count_fn_name(session: &CachingSession) -> Result<QueryResultUniqueRowExpect<CountType>, SingleSelectQueryErrorTransform> {
+        pub async fn #select_all_count_fn_name(session: &CachingSession) -> Result<QueryResultUniqueRowExpect<CountType>, Error> {
             #select_all_count_fn_name_qv().select_count(session).await
         }
 
@@ -171,6 +174,14 @@ pub(crate) fn write<T: Transformer>(
 
     match &entity_writer.table.materialized_view {
         None => {
+            let (select_by_constants, select_by_type) =
+                create_select_by_indexed_columns(entity_writer, &struct_name_ident, &column_names);
+
+            tokens_constants.extend(select_by_constants);
+            tokens_type.extend(select_by_type);
+
+            // Rows of counter tables are created by updating the counters, they can not be inserted
+            let is_counter_table = entity_writer.is_counter_table();
             let question_marks =
                 entity_writer.comma_separated_question_marks(entity_writer.columns.len());
             let insert_query_const_name = insert_constant();
@@ -179,18 +190,36 @@ pub(crate) fn write<T: Transformer>(
                 table_name, column_names, question_marks
             );
 
-            tokens_constants.extend(quote! {
-                /// The query to insert a unique row in the table
-                pub const #insert_query_const_name: &str = #insert_query;
-            });
+            if !is_counter_table {
+                tokens_constants.extend(quote! {
+                    /// The query to insert a unique row in the table
+                    pub const #insert_query_const_name: &str = #insert_query;
+                });
 
-            let insert_ttl_query_const_name = insert_ttl_constant();
-            let insert_ttl_query = format!("{} using ttl ?", insert_query);
+                let insert_ttl_query_const_name = insert_ttl_constant();
+                let insert_ttl_query = format!("{} using ttl ?", insert_query);
 
-            tokens_constants.extend(quote! {
-                /// The query to insert a unique row in the table with a TTL
-                pub const #insert_ttl_query_const_name: &str = #insert_ttl_query;
-            });
+                tokens_constants.extend(quote! {
+                    /// The query to insert a unique row in the table with a TTL
+                    pub const #insert_ttl_query_const_name: &str = #insert_ttl_query;
+                });
+
+                let insert_if_not_exists_query_const_name = insert_if_not_exists_constant();
+                let insert_if_not_exists_query = format!("{} if not exists", insert_query);
+
+                tokens_constants.extend(quote! {
+                    /// The query to insert a unique row in the table if it doesn't exist yet
+                    pub const #insert_if_not_exists_query_const_name: &str = #insert_if_not_exists_query;
+                });
+
+                let insert_with_query_const_name = insert_with_constant();
+                let insert_with_query = format!("{} using timestamp ?", insert_query);
+
+                tokens_constants.extend(quote! {
+                    /// The query to insert a unique row in the table with a write timestamp
+                    pub const #insert_with_query_const_name: &str = #insert_with_query;
+                });
+            }
 
             let truncate_query_const_name = truncate_constant();
             let truncate_query = format!("truncate {}", table_name);
@@ -216,6 +245,13 @@ pub(crate) fn write<T: Transformer>(
             let truncate_qv = qv(&truncate_fn_name);
             let insert_qv = qv(&insert_fn_name);
             let insert_ttl_qv = qv(&insert_ttl_fn_name);
+            let insert_if_not_exists_fn_name = insert_if_not_exists_fn_name();
+            let insert_if_not_exists_qv = qv(&insert_if_not_exists_fn_name);
+            let insert_if_not_exists_constant = insert_if_not_exists_constant();
+            let insert_with_fn_name = insert_with_fn_name();
+            let insert_with_qv = qv(&insert_with_fn_name);
+            let insert_with_constant = insert_with_constant();
+            let lightweight_transaction = entity_writer.lightweight_transaction();
 
             tokens_type.extend(quote! {
                 /// Returns a struct that can perform a truncate operation
@@ -231,10 +267,16 @@ pub(crate) fn write<T: Transformer>(
                 pub async fn #truncate_fn_name(session: &CachingSession) -> ScyllaQueryResult {
                     #truncate_qv().truncate(session).await
                 }
+            });
+
+            if is_counter_table {
+                return (tokens_constants, tokens_type);
+            }
 
+            tokens_type.extend(quote! {
                 impl <'a> #struct_name_ref_ident<'a> {
                     /// Returns a struct that can perform an insert operation
-                    pub fn #insert_qv(&self) -> Result<#insert, SerializeValuesError> {
+                    pub fn #insert_qv(&self) -> Result<#insert, Error> {
                         let mut serialized = SerializedValues::with_capacity(#field_count);
 
                         #(serialized.add_value(&self.#idents)?);*;
@@ -254,7 +296,7 @@ pub(crate) fn write<T: Transformer>(
                     }
 
                     /// Returns a struct that can perform an insert operation with a TTL
-                    pub fn #insert_ttl_qv(&self, ttl: TtlType) -> Result<#insert, SerializeValuesError> {
+                    pub fn #insert_ttl_qv(&self, ttl: TtlType) -> Result<#insert, Error> {
                         let mut serialized = SerializedValues::with_capacity(#insert_with_ttl_values_len);
 
                         #(serialized.add_value(&self.#idents)?);*;
@@ -274,6 +316,46 @@ pub(crate) fn write<T: Transformer>(
                         self.#insert_ttl_qv(ttl)?.insert(session).await
                     }
 
+                    /// Returns a struct that can perform an insert operation, which is only applied if the row doesn't exist
+                    pub fn #insert_if_not_exists_qv(&self) -> Result<#lightweight_transaction<#struct_name_ident>, Error> {
+                        let mut serialized = SerializedValues::with_capacity(#field_count);
+
+                        #(serialized.add_value(&self.#idents)?);*;
+
+                        Ok(#lightweight_transaction::new(Qv {
+                            query: #insert_if_not_exists_constant,
+                            values: serialized,
+                        }))
+                    }
+
+                    /// Performs an insert if the row doesn't exist, else the existing row is returned
+                    pub async fn #insert_if_not_exists_fn_name(&self, session: &CachingSession) -> Result<QueryResultLwt<#struct_name_ident>, Error> {
+                        #log_library::debug!("Inserting if not exists: {:#?}", self);
+
+                        self.#insert_if_not_exists_qv()?.execute(session).await
+                    }
+
+                    /// Returns a struct that can perform an insert operation with a write timestamp
+                    pub fn #insert_with_qv(&self, timestamp: TimestampType) -> Result<#insert, Error> {
+                        let mut serialized = SerializedValues::with_capacity(#insert_with_ttl_values_len);
+
+                        #(serialized.add_value(&self.#idents)?);*;
+
+                        serialized.add_value(&timestamp)?;
+
+                        Ok(#insert::new(Qv {
+                            query: #insert_with_constant,
+                            values: serialized,
+                        }))
+                    }
+
+                    /// Performs an insert with a write timestamp, replaying it with the same timestamp has no additional effect
+                    pub async fn #insert_with_fn_name(&self, session: &CachingSession, timestamp: TimestampType) -> ScyllaQueryResult {
+                        #log_library::debug!("Insert with timestamp {}, {:#?}", timestamp, self);
+
+                        self.#insert_with_qv(timestamp)?.insert(session).await
+                    }
+
                     /// Performs either an insertion or deletion, depending on the insert parameter
                     pub async fn #insert_or_delete(&self, session: &CachingSession, insert: bool) -> ScyllaQueryResult {
                         if insert {
@@ -288,27 +370,38 @@ pub(crate) fn write<T: Transformer>(
             let in_memory_updates = in_memory_updates();
             let in_memory_update = in_memory_update();
             let updatable_column = updatable_column();
-            let mut variants = vec![];
-            let fields = entity_writer
-                .struct_field_metadata
-                .non_primary_key_fields
-                .iter()
-                .map(|f| &f.ident)
-                .collect::<Vec<_>>();
-
-            for field in &fields {
-                variants.push(create_variant(field));
+            let mut arms = vec![];
+
+            for field in &entity_writer.struct_field_metadata.non_primary_key_fields {
+                let ident = &field.ident;
+                let variant = create_variant(ident);
+
+                arms.push(quote! {
+                    #updatable_column::#variant(val) => {
+                        self.#ident = val;
+                    }
+                });
+
+                for operation in collection_operations(field) {
+                    let variant = operation.variant();
+                    let idents = operation.parameters().into_iter().map(|p| p.ident);
+                    let in_memory = operation.in_memory();
+
+                    arms.push(quote! {
+                        #updatable_column::#variant(#(#idents),*) => {
+                            #in_memory
+                        }
+                    });
+                }
             }
 
-            if !fields.is_empty() {
+            if !arms.is_empty() {
                 tokens_type.extend(quote! {
                     impl #struct_name_ident {
                         /// Performs an update on the current struct based on the update parameter
                         pub fn #in_memory_update(&mut self, update: #updatable_column) {
                             match update {
-                                #(#updatable_column::#variants(val) => {
-                                self.#fields = val;
-                                }),*
+                                #(#arms),*
                             }
                         }
 
@@ -374,14 +467,94 @@ fn create_select_all_query(
 
         /// Returns a struct that can perform a selection of all rows in the database
         /// with a specified page size
-        pub async fn #fn_name(session: &CachingSession, page_size: Option<i32>) -> Result<TypedRowIterator<#row_type>, QueryError> {
+        pub async fn #fn_name(session: &CachingSession, page_size: Option<i32>) -> Result<TypedRowIterator<#row_type>, Error> {
             #select_multiple_qv().select(session, page_size).await
         }
 
         /// Returns a struct that can perform a selection of all rows in the database
         /// It will accumulate all rows in memory by sending paged queries
-        pub async fn #select_multiple_all_in_memory(session: &CachingSession, page_size: i32) -> Result<QueryEntityVec<#row_type>, MultipleSelectQueryErrorTransform> {
+        pub async fn #select_multiple_all_in_memory(session: &CachingSession, page_size: i32) -> Result<QueryEntityVec<#row_type>, Error> {
             #select_multiple_qv().select_all_in_memory(session, page_size).await
         }
     }
 }
+
+/// Creates the select queries for the columns with a secondary index
+fn create_select_by_indexed_columns<T: Transformer>(
+    entity_writer: &EntityWriter<T>,
+    struct_name_ident: &Ident,
+    column_names: &str,
+) -> (TokenStream, TokenStream) {
+    let table_name = &entity_writer.table.table_name;
+    let select_multiple = entity_writer.select_multiple();
+    let mut tokens_constants = TokenStream::new();
+    let mut tokens_type = TokenStream::new();
+
+    for column in indexed_columns(entity_writer.schema, table_name) {
+        let field = match entity_writer
+            .struct_field_metadata
+            .fields
+            .iter()
+            .find(|f| f.ident == column)
+        {
+            Some(field) => field,
+            None => continue,
+        };
+        let ident = &field.ident;
+        let ty = &field.borrow_ty;
+        let (fn_name, constant) = select_by_field(ident);
+        let fn_name_qv = qv(&fn_name);
+        let fn_name_in_memory = all_in_memory(&fn_name);
+        let query = format!(
+            "select {} from {} where {} = ?",
+            column_names, table_name, column
+        );
+        let message_query = format!(
+            "The query to select the rows by the indexed column {}",
+            column
+        );
+        let message_return = format!(
+            "Returns a struct that can perform a selection of the rows by the indexed column {}",
+            column
+        );
+        let message_perform = format!(
+            "Selects the rows by the indexed column {} with a specified page size",
+            column
+        );
+        let message_in_memory = format!(
+            "Selects the rows by the indexed column {} and accumulates them in memory",
+            column
+        );
+
+        tokens_constants.extend(quote! {
+            #[doc = #message_query]
+            pub const #constant: &str = #query;
+        });
+
+        tokens_type.extend(quote! {
+            #[doc = #message_return]
+            pub fn #fn_name_qv(#ident: &#ty) -> Result<#select_multiple<#struct_name_ident, &'static str, SerializedValues>, Error> {
+                let mut serialized_values = SerializedValues::with_capacity(1);
+
+                serialized_values.add_value(&#ident)?;
+
+                Ok(#select_multiple::new(Qv {
+                    query: #constant,
+                    values: serialized_values
+                }))
+            }
+
+            #[doc = #message_perform]
+            pub async fn #fn_name(session: &CachingSession, #ident: &#ty, page_size: Option<i32>) -> Result<TypedRowIterator<#struct_name_ident>, Error> {
+                #fn_name_qv(#ident)?.select(session, page_size).await
+            }
+
+            #[doc = #message_in_memory]
+            pub async fn #fn_name_in_memory(session: &CachingSession, #ident: &#ty, page_size: i32) -> Result<QueryEntityVec<#struct_name_ident>, Error> {
+                #fn_name_qv(#ident)?.select_all_in_memory(session, page_size).await
+            }
+        });
+    }
+
+    (tokens_constants, tokens_type)
+}
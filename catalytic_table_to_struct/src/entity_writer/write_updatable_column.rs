@@ -2,12 +2,14 @@ use heck::CamelCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
+use crate::entity_writer::collection_operation::collection_operations;
 use crate::entity_writer::EntityWriter;
 use crate::query_ident::{get_updatable_column_field, updatable_column, updatable_column_ref};
 use crate::transformer::Transformer;
 
 pub(crate) fn write<T: Transformer>(entity_writer: &'_ EntityWriter<T>) -> TokenStream {
     if entity_writer.table.materialized_view.is_some()
+        || entity_writer.is_counter_table()
         || entity_writer
             .struct_field_metadata
             .non_primary_key_fields
@@ -61,6 +63,32 @@ pub(crate) fn write<T: Transformer>(entity_writer: &'_ EntityWriter<T>) -> Token
                 #updatable_column_ref::#variant_name(&self.#ident)
             }
         });
+
+        // Variants which update a part of a collection
+        for operation in collection_operations(field) {
+            let variant_name = operation.variant();
+            let parameters = operation.parameters();
+            let idents = parameters.iter().map(|p| &p.ident).collect::<Vec<_>>();
+            let tys = parameters.iter().map(|p| &p.ty);
+            let borrow_tys = parameters.iter().map(|p| &p.borrow_ty);
+            let to_owned = parameters.iter().map(|p| &p.from_borrow_to_owned);
+
+            updatable_column_variants.push(quote! {
+                #variant_name(#(#tys),*)
+            });
+
+            updatable_column_ref_variants.push(quote! {
+                #variant_name(#(&'a #borrow_tys),*)
+            });
+
+            updatable_column_to_ref.push(quote! {
+                #updatable_column::#variant_name(#(#idents),*) => #updatable_column_ref::#variant_name(#(#idents),*)
+            });
+
+            updatable_column_from_ref.push(quote! {
+                #updatable_column_ref::#variant_name(#(#idents),*) => #updatable_column::#variant_name(#(#idents.#to_owned),*)
+            });
+        }
     }
 
     let updatable_column_metadata = entity_writer
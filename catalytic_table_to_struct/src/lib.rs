@@ -4,13 +4,9 @@ use crate::transformer::{StructTable, Transformer};
 
 use crate::query_ident::struct_ref;
 use catalytic::capitalizing::table_name_to_struct_name;
-use catalytic::env_property_reader::keyspace;
-use catalytic::materialized_view::{
-    query_materialized_view, query_materialized_views, MaterializedView,
-};
-use catalytic::query_metadata::query_columns;
-use catalytic::runtime::query_collect_to_vec;
-use catalytic::table_metadata::TableName;
+use catalytic::materialized_view::{materialized_view, MaterializedView};
+use catalytic::schema_provider::{DatabaseSchema, SchemaProvider};
+use catalytic::user_defined_type::USER_DEFINED_TYPES_MODULE;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
@@ -21,6 +17,7 @@ mod column_mapper;
 mod entity_writer;
 pub mod query_ident;
 pub mod transformer;
+mod user_defined_type_writer;
 
 pub const GENERATED: &str = "generated";
 
@@ -36,8 +33,19 @@ pub struct Table {
 ///     On Windows, the path should be something like C:\\users\\myself\\project\\src\\generated\\
 ///
 /// transformer: trait in which customization can take place.
+///
+/// The tables are read from the database, use `generate_from_schema` to read them from somewhere else
 pub fn generate(base_dir: &Path, transformer: impl Transformer) {
-    let keyspace = keyspace();
+    generate_from_schema(base_dir, transformer, &DatabaseSchema)
+}
+
+/// Same as `generate`, but the tables, columns and materialized views are read from the given schema.
+/// To generate without a running database, pass in a `catalytic::schema_provider::CqlFileSchema`
+pub fn generate_from_schema(
+    base_dir: &Path,
+    transformer: impl Transformer,
+    schema: &dyn SchemaProvider,
+) {
     let mut current_dir = std::env::current_dir().unwrap();
 
     comp_pb(base_dir, &current_dir);
@@ -47,13 +55,8 @@ pub fn generate(base_dir: &Path, transformer: impl Transformer) {
     comp_pb(base_dir, &current_dir);
 
     // Query all the tables
-    let query = format!(
-        "select table_name from system_schema.tables where keyspace_name = '{}'",
-        keyspace
-    );
-
-    let non_materialized_views: Vec<TableName> = query_collect_to_vec(query, &[]);
-    let materialized_views = query_materialized_views();
+    let non_materialized_views = schema.table_names();
+    let materialized_views = schema.materialized_views();
     let mut tables = vec![];
 
     for t in non_materialized_views {
@@ -65,7 +68,7 @@ pub fn generate(base_dir: &Path, transformer: impl Transformer) {
 
     for t in materialized_views {
         tables.push(Table {
-            materialized_view: query_materialized_view(&t.table_name),
+            materialized_view: materialized_view(schema, &t.table_name),
             table_name: t.table_name,
         });
     }
@@ -82,6 +85,37 @@ pub fn generate(base_dir: &Path, transformer: impl Transformer) {
 
     add_generated_header(&mut mod_file);
 
+    // The structs of the user defined types are placed in a single file, the tables refer to it
+    let user_defined_types = schema.user_defined_types();
+
+    if !user_defined_types.is_empty() {
+        writeln!(
+            mod_file,
+            "pub mod {m};\npub use {m}::{{{}}};",
+            user_defined_types
+                .iter()
+                .map(|u| u.struct_name())
+                .collect::<Vec<_>>()
+                .join(", "),
+            m = USER_DEFINED_TYPES_MODULE
+        )
+        .unwrap();
+
+        let path_to_udt_file = format!("{}.rs", USER_DEFINED_TYPES_MODULE);
+        let mut file = File::create(base_dir.join(&path_to_udt_file)).unwrap();
+
+        add_generated_header(&mut file);
+
+        write!(
+            file,
+            "{}",
+            user_defined_type_writer::write(&user_defined_types)
+        )
+        .unwrap();
+
+        assert!(format(&path_to_udt_file, base_dir));
+    }
+
     for table in tables {
         println!("Processing table: {}", table.table_name);
 
@@ -98,7 +132,7 @@ pub fn generate(base_dir: &Path, transformer: impl Transformer) {
         .unwrap();
 
         // Query all the columns for this table
-        let columns = query_columns(&table.table_name);
+        let columns = schema.columns(&table.table_name);
 
         // Create the file to place the generated rust code in
         let path_to_struct_file = format!("{}.rs", table.table_name);
@@ -122,6 +156,7 @@ pub fn generate(base_dir: &Path, transformer: impl Transformer) {
             struct_field_metadata,
             transformer: &transformer,
             columns: &columns,
+            schema,
         };
 
         let tokens = entity_write.create_tokens();
@@ -139,7 +174,7 @@ pub fn generate(base_dir: &Path, transformer: impl Transformer) {
     // Format the output
     // This may fail sometimes with weird NULL bytes, in cause of failure, recursion
     if !format("mod.rs", base_dir) {
-        generate(base_dir, transformer);
+        generate_from_schema(base_dir, transformer, schema);
     }
 }
 
@@ -14,6 +14,18 @@ pub fn primary_key_struct_ref() -> Ident {
     struct_ref(&primary_key_struct())
 }
 
+pub fn partition_key_struct() -> Ident {
+    format_ident!("PartitionKey")
+}
+
+pub fn partition_key_struct_ref() -> Ident {
+    struct_ref(&partition_key_struct())
+}
+
+pub fn partition_key_struct_parameter() -> Ident {
+    format_ident!("partition_key")
+}
+
 pub fn struct_ref(struct_ref: &Ident) -> Ident {
     format_ident!("{}Ref", struct_ref)
 }
@@ -26,6 +38,10 @@ pub fn primary_key_struct_parameter() -> Ident {
     format_ident!("primary_key")
 }
 
+pub fn with_writetime() -> Ident {
+    format_ident!("WithWritetime")
+}
+
 pub fn updatable_column() -> Ident {
     format_ident!("UpdatableColumn")
 }
@@ -42,6 +58,10 @@ pub fn create_variant(ident: &Ident) -> Ident {
     format_ident!("{}", ident.to_string().to_camel_case())
 }
 
+pub fn clustering_prefix() -> Ident {
+    format_ident!("ClusteringPrefix")
+}
+
 pub fn update_dyn() -> Ident {
     format_ident!("update_dyn")
 }
@@ -76,6 +96,12 @@ macro_rules! write_query {
 write_query!(insert_or_delete_fn_name, "insert_or_delete");
 write_query!(insert_fn_name, "insert", insert_constant);
 write_query!(insert_ttl_fn_name, "insert_ttl", insert_ttl_constant);
+write_query!(
+    insert_if_not_exists_fn_name,
+    "insert_if_not_exists",
+    insert_if_not_exists_constant
+);
+write_query!(insert_with_fn_name, "insert_with", insert_with_constant);
 write_query!(truncate_fn_name, "truncate", truncate_constant);
 write_query!(
     select_unique_fn_name,
@@ -83,6 +109,15 @@ write_query!(
     select_unique_constant
 );
 write_query!(select_unique_expect_fn_name, "select_unique_expect");
+write_query!(
+    select_unique_with_writetime_fn_name,
+    "select_unique_with_writetime",
+    select_unique_with_writetime_constant
+);
+write_query!(
+    select_unique_with_writetime_expect_fn_name,
+    "select_unique_with_writetime_expect"
+);
 write_query!(select_all_fn_name, "select_all", select_all_constant);
 write_query!(
     select_all_count_fn_name,
@@ -90,6 +125,40 @@ write_query!(
     select_all_count_constant
 );
 write_query!(delete_fn_name, "delete", delete_constant);
+write_query!(
+    delete_if_exists_fn_name,
+    "delete_if_exists",
+    delete_if_exists_constant
+);
+write_query!(delete_with_fn_name, "delete_with", delete_with_constant);
+write_query!(
+    select_partition_fn_name,
+    "select_partition",
+    select_partition_constant
+);
+write_query!(select_partition_range_fn_name, "select_partition_range");
+write_query!(
+    delete_partition_fn_name,
+    "delete_partition",
+    delete_partition_constant
+);
+write_query!(delete_range_fn_name, "delete_range");
+write_query!(token_fn_name, "token", token_constant);
+write_query!(
+    select_by_token_range_fn_name,
+    "select_by_token_range",
+    select_by_token_range_constant
+);
+write_query!(scan_all_parallel_fn_name, "scan_all_parallel");
+write_query!(
+    update_counters_fn_name,
+    "update_counters",
+    update_counters_constant
+);
+
+pub fn select_partition_desc_constant() -> Ident {
+    format_ident!("SELECT_PARTITION_DESC_QUERY")
+}
 
 pub fn base_table(ident: &Ident) -> Ident {
     format_ident!("{}_base_table", ident)
@@ -115,3 +184,48 @@ pub fn update_field(ident: &Ident) -> (Ident, Ident) {
         format_ident!("{}", constant),
     )
 }
+
+pub fn update_field_if(ident: &Ident) -> (Ident, Ident) {
+    let update_string = format!("update_{}_if", ident);
+    let constant = update_string.to_uppercase() + "_QUERY";
+
+    (
+        format_ident!("{}", update_string),
+        format_ident!("{}", constant),
+    )
+}
+
+pub fn update_field_with(ident: &Ident) -> (Ident, Ident) {
+    let update_string = format!("update_{}_with", ident);
+    let constant = update_string.to_uppercase() + "_QUERY";
+
+    (
+        format_ident!("{}", update_string),
+        format_ident!("{}", constant),
+    )
+}
+
+pub fn select_by_field(ident: &Ident) -> (Ident, Ident) {
+    let select_string = format!("select_by_{}", ident);
+    let constant = select_string.to_uppercase() + "_QUERY";
+
+    (
+        format_ident!("{}", select_string),
+        format_ident!("{}", constant),
+    )
+}
+
+pub fn increment_field(ident: &Ident) -> (Ident, Ident) {
+    counter_field("increment", ident)
+}
+
+pub fn decrement_field(ident: &Ident) -> (Ident, Ident) {
+    counter_field("decrement", ident)
+}
+
+fn counter_field(prefix: &str, ident: &Ident) -> (Ident, Ident) {
+    let fn_name = format!("{}_{}", prefix, ident);
+    let constant = fn_name.to_uppercase() + "_QUERY";
+
+    (format_ident!("{}", fn_name), format_ident!("{}", constant))
+}
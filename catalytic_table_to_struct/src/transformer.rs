@@ -110,6 +110,16 @@ pub trait Transformer {
         TypeMetadata::with_default_values(&["catalytic_macro::PrimaryKey", "Copy"])
     }
 
+    /// Add custom derives to the partition key struct
+    fn partition_struct_metadata(&self, _struct_table: StructTable) -> TypeMetadata {
+        TypeMetadata::with_default_values::<&str>(&[])
+    }
+
+    /// Add custom derives to the partition key ref struct
+    fn partition_struct_ref_metadata(&self, _struct_table: StructTable) -> TypeMetadata {
+        TypeMetadata::with_default_values(&["Copy"])
+    }
+
     /// Add custom derives to the updatable column enum
     fn updatable_column_metadata(&self, _struct_table: StructTable) -> TypeMetadata {
         TypeMetadata::with_default_values::<&str>(&[])
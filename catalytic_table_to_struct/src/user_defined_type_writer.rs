@@ -0,0 +1,60 @@
+use catalytic::table_metadata::ColumnType;
+use catalytic::user_defined_type::UserDefinedType;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Writes a struct for every user defined type
+/// The struct implements FromCqlVal and Value, so it can be used as column type and in collections
+/// CqlTypeOf is implemented so the struct can be used as argument in query!
+pub(crate) fn write(user_defined_types: &[UserDefinedType]) -> TokenStream {
+    let mut tokens = TokenStream::new();
+
+    for user_defined_type in user_defined_types {
+        tokens.extend(write_user_defined_type(user_defined_type));
+    }
+
+    tokens
+}
+
+fn write_user_defined_type(user_defined_type: &UserDefinedType) -> TokenStream {
+    let struct_name = format_ident!("{}", user_defined_type.struct_name());
+    let doc = format!("The user defined type '{}'", user_defined_type.type_name);
+    let (idents, types): (Vec<_>, Vec<_>) = user_defined_type
+        .fields()
+        .map(|(field_name, field_type)| {
+            let ty: TokenStream = ColumnType::new(field_type).to_ty().parse().unwrap();
+
+            (format_ident!("{}", field_name), ty)
+        })
+        .unzip();
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct #struct_name {
+            #(pub #idents: #types),*
+        }
+
+        impl scylla::cql_to_rust::FromCqlVal<scylla::frame::response::result::CqlValue> for #struct_name {
+            fn from_cql(cql_val: scylla::frame::response::result::CqlValue) -> Result<Self, scylla::cql_to_rust::FromCqlValError> {
+                let mut fields = catalytic::user_defined_type::user_defined_type_fields(cql_val)?;
+
+                Ok(#struct_name {
+                    #(#idents: scylla::cql_to_rust::FromCqlVal::from_cql(fields.next().flatten())?),*
+                })
+            }
+        }
+
+        impl scylla::frame::value::Value for #struct_name {
+            fn serialize(&self, buf: &mut Vec<u8>) -> Result<(), scylla::frame::value::ValueTooBig> {
+                catalytic::user_defined_type::serialize_user_defined_type(buf, |buf| {
+                    #(scylla::frame::value::Value::serialize(&self.#idents, buf)?;)*
+
+                    Ok(())
+                })
+            }
+        }
+
+        impl catalytic::cql_type::CqlTypeOf<catalytic::cql_type::UserDefinedType<#struct_name>> for #struct_name {}
+    }
+}